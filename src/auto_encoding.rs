@@ -0,0 +1,410 @@
+//! Choose the encodings to search for by sampling the input (`--auto`).
+//!
+//! Every candidate encoding decodes the sample. Strings that look like
+//! natural language, see `plausibility::score()`, mark the input bytes they
+//! were decoded from. The candidates are then chosen greedily: first the
+//! one marking the most bytes, then the one marking the most bytes not
+//! marked by a chosen one, and so on.
+//!
+//! ASCII decodes the same in all ASCII compatible encodings. Single-byte
+//! encodings, however, also decode the binary data around ASCII strings and
+//! thus mark more bytes than `UTF-8`. Therefore, ASCII bytes count for
+//! `UTF-8` and for encodings that are not ASCII compatible only. For the
+//! others, only the words with non-ASCII characters are rated.
+
+use crate::input::input_is_stdin;
+use crate::mission::MissionDef;
+use crate::options::{ARGS, ASCII_ENC_LABEL, ENCODING_DEFAULT};
+use crate::plausibility;
+use encoding_rs::{DecoderResult, Encoding, UTF_8};
+use lazy_static::lazy_static;
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::str;
+use unicode_normalization::char::is_combining_mark;
+
+/// Encodings `--auto` chooses from, in order of preference.
+pub const AUTO_CANDIDATES: [&str; 18] = [
+    "UTF-8",
+    "UTF-16LE",
+    "UTF-16BE",
+    "windows-1252",
+    "windows-1250",
+    "windows-1251",
+    "KOI8-R",
+    "windows-1253",
+    "windows-1254",
+    "windows-1255",
+    "windows-1256",
+    "windows-1257",
+    "windows-874",
+    "Shift_JIS",
+    "EUC-JP",
+    "GBK",
+    "Big5",
+    "EUC-KR",
+];
+
+/// Maximum number of encodings `--auto` chooses.
+pub const AUTO_ENCODINGS_MAX: usize = 4;
+
+/// An encoding is chosen only, when it finds at least this many bytes of
+/// text not found by the encodings chosen before.
+pub const AUTO_GAIN_MIN: usize = 64;
+
+/// Decoding binary data produces some text by chance. Therefore, an
+/// encoding is chosen only, when it finds text in at least
+/// `1/AUTO_GAIN_SAMPLE_RATIO` of the sample.
+pub const AUTO_GAIN_SAMPLE_RATIO: usize = 256;
+
+/// Strings shorter than this do not count.
+const AUTO_CHARS_MIN: usize = 8;
+
+/// Minimum `plausibility::score()` of a string to count. Higher than
+/// usually needed for `--min-score`, as machine code decoded with
+/// single-byte encodings scores up to about 60.
+const AUTO_SCORE_MIN: u8 = 70;
+
+/// Longest period `is_periodic()` looks for.
+const AUTO_PERIOD_MAX: usize = 8;
+
+/// Number of bytes sampled from `stdin`.
+const AUTO_STDIN_SAMPLE_LEN: u64 = 0x10000;
+
+/// Number of windows sampled from the input files.
+const AUTO_WINDOW_NB: usize = 16;
+
+/// Length of a window sampled from an input file.
+const AUTO_WINDOW_LEN: u64 = 0x1000;
+
+lazy_static! {
+    /// The beginning of `stdin`. As it can not be read twice, `input::Slicer`
+    /// scans it before the rest of `stdin`.
+    pub static ref STDIN_SAMPLE: Vec<u8> = {
+        let mut sample = Vec::new();
+        if let Err(e) = io::stdin().take(AUTO_STDIN_SAMPLE_LEN).read_to_end(&mut sample) {
+            eprintln!("Error: can not read stdin: {}", e);
+        }
+        sample
+    };
+
+    /// The encodings chosen by `--auto`.
+    pub static ref AUTO_ENCODINGS: AutoEncodings = AutoEncodings::from_windows(&sample());
+}
+
+/// The encodings chosen from a sample of the input.
+#[derive(Debug, Default)]
+pub struct AutoEncodings {
+    /// Number of sampled bytes.
+    pub sample_len: usize,
+    /// Chosen encodings and the number of bytes of text each adds.
+    pub v: Vec<(&'static str, usize)>,
+}
+
+impl AutoEncodings {
+    /// Chooses the encodings for the sampled `windows`.
+    pub fn from_windows(windows: &[Vec<u8>]) -> Self {
+        // For every candidate: if ASCII bytes count and the text ranges in
+        // every window.
+        let candidates: Vec<(bool, Vec<Vec<Range<usize>>>)> = AUTO_CANDIDATES
+            .iter()
+            .map(|name| {
+                // All candidates are known to `encoding_rs`.
+                let encoding = Encoding::for_label(name.as_bytes()).unwrap();
+                let ascii_counts = encoding == UTF_8 || !encoding.is_ascii_compatible();
+                (
+                    ascii_counts,
+                    windows
+                        .iter()
+                        .map(|w| text_ranges(encoding, w, ascii_counts))
+                        .collect(),
+                )
+            })
+            .collect();
+
+        let sample_len = windows.iter().map(|w| w.len()).sum();
+        let gain_min = AUTO_GAIN_MIN.max(sample_len / AUTO_GAIN_SAMPLE_RATIO);
+        let mut covered: Vec<Vec<bool>> = windows.iter().map(|w| vec![false; w.len()]).collect();
+        let mut v = Vec::new();
+        let mut chosen = vec![false; candidates.len()];
+        while v.len() < AUTO_ENCODINGS_MAX {
+            // The first candidate wins a tie.
+            let best = candidates
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !chosen[*i])
+                .map(|(i, (ascii_counts, ranges))| {
+                    (i, gain(ranges, windows, &covered, *ascii_counts))
+                })
+                .rev()
+                .max_by_key(|(_, gain)| *gain);
+            let (i, gain) = match best {
+                Some((i, gain)) if gain >= gain_min => (i, gain),
+                _ => break,
+            };
+            for (ranges, covered) in candidates[i].1.iter().zip(covered.iter_mut()) {
+                for r in ranges {
+                    covered[r.clone()].iter_mut().for_each(|b| *b = true);
+                }
+            }
+            chosen[i] = true;
+            v.push((AUTO_CANDIDATES[i], gain));
+        }
+
+        Self { sample_len, v }
+    }
+
+    /// Appends the chosen encodings to the mission definitions
+    /// `mission_defs`, unless they are searched for already.
+    pub fn extend_encoding(&self, mission_defs: &mut Vec<MissionDef>) {
+        let resolve = |name: &str| {
+            if name.is_empty() {
+                Encoding::for_label(ENCODING_DEFAULT.as_bytes())
+            } else if name == ASCII_ENC_LABEL {
+                None
+            } else {
+                Encoding::for_label(name.as_bytes())
+            }
+        };
+        let searched: Vec<&'static Encoding> = mission_defs
+            .iter()
+            .filter_map(|mission_def| resolve(mission_def.enc_name()))
+            .collect();
+        for (name, _) in &self.v {
+            if !searched.contains(&resolve(name).unwrap()) {
+                mission_defs.push(MissionDef::EncOpt(name.to_string()));
+            }
+        }
+    }
+}
+
+/// Reads the sample: the beginning of `stdin` or `AUTO_WINDOW_NB` windows
+/// evenly spread over the input files. Files that can not be read are
+/// skipped, `input::Slicer` reports them later.
+fn sample() -> Vec<Vec<u8>> {
+    if input_is_stdin() {
+        return vec![STDIN_SAMPLE.clone()];
+    }
+    let window_nb = (AUTO_WINDOW_NB / ARGS.inputs.len()).max(1) as u64;
+    let mut windows = Vec::new();
+    for path in &ARGS.inputs {
+        let mut file = match File::open(path) {
+            Ok(f) => f,
+            Err(_) => continue,
+        };
+        let len = file.metadata().map(|m| m.len()).unwrap_or(0);
+        // Small files are read as a whole.
+        let (offsets, window_len) = if len <= window_nb * AUTO_WINDOW_LEN {
+            (vec![0], len)
+        } else {
+            let step = (len - AUTO_WINDOW_LEN) / (window_nb - 1).max(1);
+            // Even offsets keep UTF-16 aligned.
+            let offsets = (0..window_nb).map(|i| (i * step) & !1).collect();
+            (offsets, AUTO_WINDOW_LEN)
+        };
+        for offset in offsets {
+            let mut window = Vec::new();
+            if file.seek(SeekFrom::Start(offset)).is_ok()
+                && (&mut file)
+                    .take(window_len)
+                    .read_to_end(&mut window)
+                    .is_ok()
+            {
+                windows.push(window);
+            }
+        }
+    }
+    windows
+}
+
+/// Number of bytes of `windows` in `ranges` not yet `covered`. Without
+/// `ascii_counts` only non-ASCII bytes are counted.
+fn gain(
+    ranges: &[Vec<Range<usize>>],
+    windows: &[Vec<u8>],
+    covered: &[Vec<bool>],
+    ascii_counts: bool,
+) -> usize {
+    ranges
+        .iter()
+        .zip(windows)
+        .zip(covered)
+        .map(|((ranges, window), covered)| {
+            ranges
+                .iter()
+                .map(|r| {
+                    window[r.clone()]
+                        .iter()
+                        .zip(&covered[r.clone()])
+                        .filter(|(byte, covered)| !**covered && (ascii_counts || !byte.is_ascii()))
+                        .count()
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+/// Number of letters in `s` with a letter next to them.
+fn word_letter_nb(s: &str) -> usize {
+    let mut nb = 0;
+    let mut run = 0;
+    for c in s.chars().chain(std::iter::once(' ')) {
+        if c.is_alphabetic() {
+            run += 1;
+        } else {
+            if run > 1 {
+                nb += run;
+            }
+            run = 0;
+        }
+    }
+    nb
+}
+
+/// True, when for a period up to `AUTO_PERIOD_MAX` a third of the
+/// characters of `s` repeat the character one period before. Tables in
+/// binary data look like this, text does not.
+fn is_periodic(s: &str) -> bool {
+    let chars: Vec<char> = s.chars().collect();
+    (1..=AUTO_PERIOD_MAX).any(|period| {
+        let repeated_nb = chars
+            .iter()
+            .zip(chars.iter().skip(period))
+            .filter(|(a, b)| a == b)
+            .count();
+        3 * repeated_nb >= chars.len()
+    })
+}
+
+/// The words of `s` with non-ASCII characters, separated by spaces.
+fn non_ascii_words(s: &str) -> String {
+    s.split(|c: char| !c.is_alphanumeric() && !is_combining_mark(c))
+        .filter(|w| !w.is_ascii())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Decodes `input` with `encoding` and returns the byte ranges of strings
+/// looking like text. Strings end at invalid bytes and control
+/// characters. Without `ascii_counts` only the words with non-ASCII
+/// characters are rated.
+fn text_ranges(encoding: &'static Encoding, input: &[u8], ascii_counts: bool) -> Vec<Range<usize>> {
+    let mut decoder = encoding.new_decoder_without_bom_handling();
+    let mut ranges = Vec::new();
+    let mut s = String::new();
+    let mut s_start = 0;
+    // The first byte of the character being decoded.
+    let mut char_start = 0;
+    let mut buffer = [0u8; 16];
+
+    // Single letters between symbols, as they appear when decoding binary
+    // data, score well, as word boundaries are frequent. Text is mostly
+    // made of words: two thirds of the characters at least.
+    let mut end_str = |s: &mut String, s_start: usize, end: usize| {
+        let char_nb = s.chars().count();
+        if char_nb >= AUTO_CHARS_MIN
+            && 3 * word_letter_nb(s) >= 2 * char_nb
+            && !is_periodic(s)
+            && if ascii_counts {
+                plausibility::score(s)
+            } else {
+                plausibility::score(&non_ascii_words(s))
+            }
+            .is_some_and(|score| score >= AUTO_SCORE_MIN)
+        {
+            ranges.push(s_start..end);
+        }
+        s.clear();
+    };
+
+    // We feed the decoder byte by byte to learn where each character
+    // starts.
+    for i in 0..input.len() {
+        let (result, _, written) =
+            decoder.decode_to_utf8_without_replacement(&input[i..i + 1], &mut buffer, false);
+        if let DecoderResult::Malformed(_, _) = result {
+            end_str(&mut s, s_start, char_start);
+            char_start = i + 1;
+            continue;
+        }
+        if written == 0 {
+            continue;
+        }
+        // The decoder returns valid UTF-8 only.
+        for c in str::from_utf8(&buffer[..written]).unwrap().chars() {
+            if c.is_control() {
+                end_str(&mut s, s_start, char_start);
+            } else {
+                if s.is_empty() {
+                    s_start = char_start;
+                }
+                s.push(c);
+            }
+        }
+        char_start = i + 1;
+    }
+    end_str(&mut s, s_start, char_start);
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::MissionConfig;
+
+    #[test]
+    fn test_from_windows() {
+        let text = "The file could not be opened. Die Datei wurde nicht gefunden.";
+        let utf16le: Vec<u8> = text.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+        let russian = "Не удалось открыть файл. Попробуйте ещё раз позже.";
+        let (cp1251, _, _) = encoding_rs::WINDOWS_1251.encode(russian);
+        let binary: Vec<u8> = (0..200u32).map(|i| (i * 97 % 251) as u8).collect();
+
+        // ASCII is found by UTF-8, the others find nothing new.
+        let windows = vec![
+            [text.as_bytes(), &[0], text.as_bytes()].concat(),
+            binary.clone(),
+        ];
+        let auto = AutoEncodings::from_windows(&windows);
+        assert_eq!(auto.v, vec![("UTF-8", 2 * text.len())]);
+        assert_eq!(auto.sample_len, 2 * text.len() + 1 + binary.len());
+
+        let windows = vec![
+            [&utf16le[..], &binary].concat(),
+            [
+                &binary,
+                &[0][..],
+                &cp1251,
+                &[0],
+                &cp1251,
+                &[0],
+                text.as_bytes(),
+            ]
+            .concat(),
+            [&binary, &[0][..], text.as_bytes()].concat(),
+        ];
+        let auto = AutoEncodings::from_windows(&windows);
+        let names: Vec<&str> = auto.v.iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, vec!["UTF-16LE", "UTF-8", "windows-1251"]);
+
+        // Nothing to find in binary data.
+        assert!(AutoEncodings::from_windows(&[binary]).v.is_empty());
+
+        // `utf-16le` is searched for already.
+        let mc = MissionConfig {
+            encoding: Some("ascii".to_string()),
+            ..Default::default()
+        };
+        let mut mission_defs = vec![
+            MissionDef::EncOpt("utf-16le,10".to_string()),
+            MissionDef::Config("m", &mc),
+        ];
+        auto.extend_encoding(&mut mission_defs);
+        let enc_names: Vec<&str> = mission_defs.iter().map(MissionDef::enc_name).collect();
+        assert_eq!(
+            enc_names,
+            vec!["utf-16le", "ascii", "UTF-8", "windows-1251"]
+        );
+    }
+}
@@ -0,0 +1,286 @@
+//! Character-range expressions for `--unicode-block-filter`.
+//!
+//! An expression like `U+0400-U+04FF,U+0020-U+007E,!U+2028` lists code point
+//! ranges to include, ranges to exclude (prefixed with `!`), hexadecimal
+//! Unicode-block-filter masks and the alias names of
+//! `UNICODE_BLOCK_FILTER_ALIASSE` and `FILTER_ALIASES`. It is compiled into the masks
+//! `Utf8Filter::af` and `Utf8Filter::ubf`, which stay the fast path. Only
+//! characters whose leading byte is partly covered by the expression, are
+//! looked up in a sorted range list.
+
+use crate::config::FILTER_ALIASES;
+use crate::mission::{find_builtin_filter_alias, find_filter_alias, UNICODE_BLOCK_FILTER_ALIASSE};
+use anyhow::{anyhow, Context, Result};
+use std::fmt;
+
+/// Separates the items of a character-range expression.
+pub const CHAR_RANGES_SEPARATOR: char = ',';
+
+/// Items with this prefix are excluded.
+pub const CHAR_RANGES_EXCLUDE: char = '!';
+
+/// The highest Unicode code point.
+const CODE_POINT_MAX: u32 = 0x10FFFF;
+
+/// A set of Unicode code points compiled from a character-range expression.
+#[derive(Clone, Eq, PartialEq)]
+pub struct CharRanges {
+    /// `Some()` when the expression includes ASCII code points. Then, the
+    /// ASCII part of the set, expressed as `Utf8Filter::af` mask.
+    pub af: Option<u128>,
+    /// Leading bytes of all multi-byte characters in the set, expressed as
+    /// `Utf8Filter::ubf` mask.
+    pub ubf: u64,
+    /// Leading bytes, whose characters are all in the set. Characters
+    /// starting with these do not need to be looked up in `ranges`.
+    ubf_full: u64,
+    /// The set as sorted, disjoint and non-adjacent inclusive ranges.
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharRanges {
+    /// True when `s` is meant as character-range expression and not as
+    /// single alias name or hexadecimal mask.
+    pub fn is_expression(s: &str) -> bool {
+        let s = s.trim();
+        s.contains(CHAR_RANGES_SEPARATOR)
+            || s.starts_with(CHAR_RANGES_EXCLUDE)
+            || s.to_ascii_uppercase().contains("U+")
+    }
+
+    /// Compiles the character-range expression `s`.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut include = Vec::new();
+        let mut exclude = Vec::new();
+        for item in s.split(CHAR_RANGES_SEPARATOR).map(str::trim) {
+            match item.strip_prefix(CHAR_RANGES_EXCLUDE) {
+                Some(item) => exclude.extend(parse_item(item.trim())?),
+                None => include.extend(parse_item(item)?),
+            }
+        }
+        if include.is_empty() {
+            return Err(anyhow!("no characters to include in `{}`", s));
+        }
+        let includes_ascii = include.iter().any(|(start, _)| *start < 0x80);
+        let ranges = subtract(&normalize(include), &normalize(exclude));
+
+        let mut af = 0u128;
+        let mut ubf = 0u64;
+        let mut ubf_full = 0u64;
+        for (start, end) in ranges.iter().filter(|(start, _)| *start < 0x80) {
+            for c in *start..=(*end).min(0x7F) {
+                af |= 1 << c;
+            }
+        }
+        for b in 0xC0..=0xFFu8 {
+            if let Some((start, end)) = leading_byte_range(b) {
+                // Number of code points with this leading byte in the set.
+                let covered: u32 = ranges
+                    .iter()
+                    .filter(|(s, e)| *s <= end && *e >= start)
+                    .map(|(s, e)| (*e).min(end) - (*s).max(start) + 1)
+                    .sum();
+                if covered > 0 {
+                    ubf |= 1 << (b & 0x3f);
+                }
+                if covered == end - start + 1 {
+                    ubf_full |= 1 << (b & 0x3f);
+                }
+            }
+        }
+
+        Ok(Self {
+            af: if includes_ascii { Some(af) } else { None },
+            ubf,
+            ubf_full,
+            ranges,
+        })
+    }
+
+    /// True when the range list must be consulted for characters with this
+    /// leading byte, because only some of them are in the set.
+    #[inline]
+    pub fn is_partial(&self, leading_byte: u8) -> bool {
+        1 << (leading_byte & 0x3f) & self.ubf_full == 0
+    }
+
+    /// True when `c` is in the set.
+    #[inline]
+    pub fn pass(&self, c: char) -> bool {
+        let c = c as u32;
+        // The index of the first range starting after `c`.
+        let i = self.ranges.partition_point(|(start, _)| *start <= c);
+        i > 0 && self.ranges[i - 1].1 >= c
+    }
+}
+
+/// Parses one item of a character-range expression into code point ranges.
+fn parse_item(item: &str) -> Result<Vec<(u32, u32)>> {
+    if item.is_empty() {
+        return Err(anyhow!("empty item"));
+    }
+    if item.to_ascii_uppercase().starts_with("U+") {
+        let (start, end) = match item.split_once('-') {
+            Some((start, end)) => (parse_code_point(start)?, parse_code_point(end)?),
+            None => {
+                let c = parse_code_point(item)?;
+                (c, c)
+            }
+        };
+        if start > end {
+            return Err(anyhow!("empty range `{}`", item));
+        }
+        return Ok(vec![(start, end)]);
+    }
+    let ubf = if let Some(hex) = item.strip_prefix("0x") {
+        u64::from_str_radix(hex, 16)
+            .with_context(|| format!("failed to parse hexadecimal number: `{}`", item))?
+    } else {
+        find_builtin_filter_alias(item, &UNICODE_BLOCK_FILTER_ALIASSE)
+            .or_else(|| {
+                find_filter_alias(
+                    item,
+                    &UNICODE_BLOCK_FILTER_ALIASSE,
+                    &FILTER_ALIASES.unicode_block,
+                )
+            })
+            .ok_or_else(|| {
+                anyhow!(
+                    "filter name `{}` is not valid, try `--list-encodings`",
+                    item
+                )
+            })?
+    };
+    Ok((0xC0..=0xFFu8)
+        .filter(|b| 1 << (b & 0x3f) & ubf != 0)
+        .filter_map(leading_byte_range)
+        .collect())
+}
+
+/// Parses `U+XXXX`. The `U+` is optional.
+fn parse_code_point(s: &str) -> Result<u32> {
+    let s = s.trim();
+    let hex = if s.to_ascii_uppercase().starts_with("U+") {
+        &s[2..]
+    } else {
+        s
+    };
+    let c = u32::from_str_radix(hex, 16)
+        .with_context(|| format!("failed to parse code point: `{}`", s))?;
+    if c > CODE_POINT_MAX {
+        return Err(anyhow!("code point `{}` is out of range", s));
+    }
+    Ok(c)
+}
+
+/// The code points of all UTF-8 characters starting with the leading byte
+/// `b`. `None` for bytes that never start a valid multi-byte character.
+fn leading_byte_range(b: u8) -> Option<(u32, u32)> {
+    let (start, len) = match b {
+        0xC2..=0xDF => (((b & 0x1F) as u32) << 6, 0x40),
+        0xE0..=0xEF => (((b & 0x0F) as u32) << 12, 0x1000),
+        0xF0..=0xF4 => (((b & 0x07) as u32) << 18, 0x40000),
+        _ => return None,
+    };
+    // `0xE0` and `0xF0` can not encode the lower code points (overlong).
+    let start = start.max(match b {
+        0xE0 => 0x800,
+        0xF0 => 0x10000,
+        _ => 0,
+    });
+    let end = (start | (len - 1)).min(CODE_POINT_MAX);
+    Some((start, end))
+}
+
+/// Sorts `v` and merges overlapping and adjacent ranges.
+fn normalize(mut v: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    v.sort_unstable();
+    let mut out: Vec<(u32, u32)> = Vec::with_capacity(v.len());
+    for (start, end) in v {
+        match out.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => out.push((start, end)),
+        }
+    }
+    out
+}
+
+/// Removes the normalized ranges `b` from the normalized ranges `a`.
+fn subtract(a: &[(u32, u32)], b: &[(u32, u32)]) -> Vec<(u32, u32)> {
+    let mut out = Vec::with_capacity(a.len());
+    for (a_start, end) in a {
+        let mut start = *a_start;
+        for (b_start, b_end) in b.iter().filter(|(s, e)| *s <= *end && *e >= *a_start) {
+            if *b_start > start {
+                out.push((start, b_start - 1));
+            }
+            start = b_end.saturating_add(1);
+        }
+        if start <= *end {
+            out.push((start, *end));
+        }
+    }
+    out
+}
+
+/// Prints the set in the syntax of the expression.
+impl fmt::Debug for CharRanges {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|(start, end)| {
+                if start == end {
+                    format!("U+{:04X}", start)
+                } else {
+                    format!("U+{:04X}-U+{:04X}", start, end)
+                }
+            })
+            .collect();
+        write!(f, "{}", ranges.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert!(CharRanges::is_expression("U+0400-U+04FF"));
+        assert!(CharRanges::is_expression("Cyrillic,Greek"));
+        assert!(!CharRanges::is_expression("Cyrillic"));
+        assert!(!CharRanges::is_expression("0xffff"));
+
+        let cr = CharRanges::parse("U+0400-U+04FF, U+0020-U+007E, !U+2028, !U+0410").unwrap();
+        assert_eq!(
+            format!("{:?}", cr),
+            "U+0020-U+007E,U+0400-U+040F,U+0411-U+04FF"
+        );
+        assert_eq!(cr.af, Some(((1u128 << 0x7F) - 1) & !((1 << 0x20) - 1)));
+        // Leading bytes 0xD0..=0xD3.
+        assert_eq!(cr.ubf, 0xf << 0x10);
+        assert!(cr.is_partial(0xD0));
+        assert!(!cr.is_partial(0xD1));
+        assert!(cr.pass('Я'));
+        assert!(!cr.pass('А'));
+        assert!(!cr.pass('Ա'));
+
+        // Aliases and masks are converted to ranges.
+        let cr = CharRanges::parse("Cyrillic,U+2028,!U+0400-U+0401").unwrap();
+        assert_eq!(cr.af, None);
+        assert!(cr.pass('\u{2028}'));
+        assert!(!cr.pass('\u{2029}'));
+        assert!(!cr.pass('\u{400}'));
+        assert!(cr.pass('\u{402}'));
+        let cr = CharRanges::parse("0x10000,U+1F600-U+1F64F").unwrap();
+        assert_eq!(format!("{:?}", cr), "U+0400-U+043F,U+1F600-U+1F64F");
+        assert!(cr.is_partial(0xF0));
+
+        assert!(CharRanges::parse("!U+0400").is_err());
+        assert!(CharRanges::parse("U+0500-U+0400").is_err());
+        assert!(CharRanges::parse("U+110000").is_err());
+        assert!(CharRanges::parse("Klingon,U+0400").is_err());
+        assert!(CharRanges::parse("U+0400,").is_err());
+    }
+}
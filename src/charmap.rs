@@ -0,0 +1,230 @@
+//! Custom single-byte encodings loaded from mapping files.
+//!
+//! A mapping file assigns to every byte `0x00..=0xFF` a Unicode code point.
+//! The expected syntax is the one of the mapping tables published by
+//! unicode.org (e.g. `MAPPINGS/VENDORS/MICSFT/WINDOWS/CP1252.TXT`):
+//!
+//! ```text
+//! # Comment
+//! 0x41    0x0041    # LATIN CAPITAL LETTER A
+//! 0x81        #UNDEFINED
+//! ```
+//!
+//! Bytes that are not listed, or listed without code point, are undefined. The
+//! decoder treats them like invalid input.
+
+use anyhow::{anyhow, Context, Result};
+use encoding_rs::DecoderResult;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+/// A table mapping every byte to a Unicode scalar value.
+#[derive(Clone)]
+pub struct CharMap {
+    /// Label used when printing the encoding name, usually the path of the
+    /// mapping file.
+    pub name: String,
+    /// The byte `b` decodes to `table[b]`. `None` marks undefined bytes.
+    pub table: [Option<char>; 256],
+}
+
+/// We do not print all 256 entries, just a summary.
+impl fmt::Debug for CharMap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "CharMap {{ name: {:?}, defined bytes: {} }}",
+            self.name,
+            self.table.iter().filter(|c| c.is_some()).count()
+        )
+    }
+}
+
+impl CharMap {
+    /// Reads and parses the mapping file `path`.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let content = fs::read_to_string(Path::new(path))
+            .with_context(|| format!("can not read mapping file `{}`", path))?;
+        Self::parse(path, &content).with_context(|| format!("invalid mapping file `{}`", path))
+    }
+
+    /// Parses the content of a mapping file. See module documentation for
+    /// the syntax.
+    pub fn parse(name: &str, content: &str) -> Result<Self> {
+        let mut table = [None; 256];
+        for (line_nb, line) in content.lines().enumerate() {
+            // Strip comments.
+            let line = match line.find('#') {
+                Some(i) => &line[..i],
+                None => line,
+            };
+            let mut fields = line.split_whitespace();
+            let byte = match fields.next() {
+                Some(b) => b,
+                None => continue,
+            };
+            let byte = parse_hex(byte).ok().filter(|b| *b <= 0xFF).ok_or_else(|| {
+                anyhow!(
+                    "line {}: `{}` is not a single byte in hexadecimal notation",
+                    line_nb + 1,
+                    byte
+                )
+            })?;
+            let code_point = match fields.next() {
+                Some(c) => c,
+                // Undefined byte.
+                None => continue,
+            };
+            let c = parse_hex(code_point)
+                .ok()
+                .and_then(char::from_u32)
+                .ok_or_else(|| {
+                    anyhow!(
+                        "line {}: `{}` is not a valid Unicode code point",
+                        line_nb + 1,
+                        code_point
+                    )
+                })?;
+            if fields.next().is_some() {
+                return Err(anyhow!(
+                    "line {}: too many items, expected `<byte> <code point>`",
+                    line_nb + 1
+                ));
+            }
+            table[byte as usize] = Some(c);
+        }
+
+        if table.iter().all(|c| c.is_none()) {
+            return Err(anyhow!("no byte mapping found"));
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            table,
+        })
+    }
+}
+
+/// Parses a hexadecimal number with `0x` prefix.
+fn parse_hex(s: &str) -> Result<u32> {
+    let digits = s
+        .strip_prefix("0x")
+        .or_else(|| s.strip_prefix("0X"))
+        .ok_or_else(|| anyhow!("missing prefix `0x`"))?;
+    Ok(u32::from_str_radix(digits, 16)?)
+}
+
+/// A decoder for `CharMap` encodings. It mimics the interface of
+/// `encoding_rs::Decoder`. As every character is encoded in exactly one byte,
+/// the decoder has no inner state.
+pub struct CharMapDecoder {
+    char_map: &'static CharMap,
+}
+
+impl CharMapDecoder {
+    /// Constructor.
+    pub fn new(char_map: &'static CharMap) -> Self {
+        Self { char_map }
+    }
+
+    /// Decodes `src` into `dst`, stopping after the first undefined byte.
+    /// Returns the same tuple as
+    /// `encoding_rs::Decoder::decode_to_str_without_replacement()`: the reason
+    /// for returning, the number of bytes read and the number of bytes
+    /// written.
+    pub fn decode_to_str_without_replacement(
+        &mut self,
+        src: &[u8],
+        dst: &mut str,
+        _last: bool,
+    ) -> (DecoderResult, usize, usize) {
+        // This is safe, as long as we only write complete UTF-8 sequences and
+        // repair the bytes after the written ones (see below).
+        let dst = unsafe { dst.as_bytes_mut() };
+        let mut read = 0;
+        let mut written = 0;
+        let mut result = DecoderResult::InputEmpty;
+        for b in src {
+            let c = match self.char_map.table[*b as usize] {
+                Some(c) => c,
+                None => {
+                    read += 1;
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+            };
+            let char_len = c.len_utf8();
+            if written + char_len > dst.len() {
+                result = DecoderResult::OutputFull;
+                break;
+            }
+            c.encode_utf8(&mut dst[written..written + char_len]);
+            written += char_len;
+            read += 1;
+        }
+        // We may have overwritten the first bytes of a multibyte character.
+        // Its orphan continuation bytes must go, so that `dst` stays valid
+        // UTF-8.
+        let mut i = written;
+        while i < dst.len() && dst[i] & 0b1100_0000 == 0b1000_0000 {
+            dst[i] = 0;
+            i += 1;
+        }
+        (result, read, written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lazy_static::lazy_static;
+
+    lazy_static! {
+        static ref CHAR_MAP: CharMap = CharMap::parse(
+            "test",
+            "# A test table\n\
+             0x41\t0x0041\t# LATIN CAPITAL LETTER A\n\
+             0x42\t0x0411\t# CYRILLIC CAPITAL LETTER BE\n\
+             0x43\t0x20AC\t# EURO SIGN\n\
+             0x44\t\t#UNDEFINED\n"
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(CHAR_MAP.table[0x41], Some('A'));
+        assert_eq!(CHAR_MAP.table[0x42], Some('Б'));
+        assert_eq!(CHAR_MAP.table[0x43], Some('€'));
+        assert_eq!(CHAR_MAP.table[0x44], None);
+        assert_eq!(CHAR_MAP.table[0x45], None);
+
+        assert!(CharMap::parse("t", "0x100\t0x0041").is_err());
+        assert!(CharMap::parse("t", "0x41\t0x110000").is_err());
+        assert!(CharMap::parse("t", "0x41\t0x0041\t0x0042").is_err());
+        assert!(CharMap::parse("t", "41\t0x0041").is_err());
+        assert!(CharMap::parse("t", "# nothing").is_err());
+    }
+
+    #[test]
+    fn test_decode() {
+        let mut d = CharMapDecoder::new(&CHAR_MAP);
+        let mut buf = [0u8; 16];
+        let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+
+        let (res, read, written) = d.decode_to_str_without_replacement(b"ABCDA", dst, false);
+        assert_eq!(res, DecoderResult::Malformed(1, 0));
+        assert_eq!(read, 4);
+        assert_eq!(written, 6);
+        assert_eq!(&dst[..written], "AБ€");
+
+        // Not enough space for `€`.
+        let mut buf = [0u8; 4];
+        let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+        let (res, read, written) = d.decode_to_str_without_replacement(b"ABC", dst, true);
+        assert_eq!(res, DecoderResult::OutputFull);
+        assert_eq!(read, 2);
+        assert_eq!(written, 3);
+    }
+}
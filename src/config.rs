@@ -0,0 +1,447 @@
+//! Named missions and profiles defined in configuration files.
+//!
+//! A configuration file is written in TOML:
+//!
+//! ```toml
+//! [missions.cyrillic-utf16]
+//! encoding = "UTF-16LE"
+//! chars_min = 6
+//! ascii_filter = "All-Ctrl"
+//! unicode_block_filter = "Cyrillic"
+//! grep_pattern = "пароль|password"
+//!
+//! [profiles.russian-forensics]
+//! missions = ["cyrillic-utf16", "cyrillic-utf8"]
+//! output_line_len = 80
+//! ```
+//!
+//! `--profile` adds the missions of a profile to the `--encoding`
+//! definitions.
+//!
+//! Filter aliases extend `ASCII_FILTER_ALIASSE` and
+//! `UNICODE_BLOCK_FILTER_ALIASSE`:
+//!
+//! ```toml
+//! [ascii_filter_aliases]
+//! Hex-Only = "[0-9a-fA-F]"
+//! Digits = "0x3ff000000000000"
+//!
+//! [unicode_block_filter_aliases]
+//! Slavic = "Cyrillic,Latin"
+//! ```
+//!
+//! The system wide configuration file is read first, then the
+//! user's and finally the one given with `--config`. Later definitions
+//! replace earlier ones with the same name.
+
+use crate::mission::{
+    find_filter_alias, MissionDef, ASCII_FILTER_ALIASSE, UNICODE_BLOCK_FILTER_ALIASSE,
+};
+#[cfg(not(test))]
+use crate::options::ARGS;
+use anyhow::{anyhow, Context, Result};
+use lazy_static::lazy_static;
+use serde_derive::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+#[cfg(not(test))]
+use std::process;
+
+/// The system wide configuration file.
+pub const CONFIG_FILE_SYSTEM: &str = "/etc/stringsext/stringsext.toml";
+
+/// The user's configuration file, relative to `$XDG_CONFIG_HOME` or
+/// `$HOME/.config`.
+pub const CONFIG_FILE_USER: &str = "stringsext/stringsext.toml";
+
+#[cfg(not(test))]
+lazy_static! {
+    /// The content of all configuration files.
+    pub static ref CONFIG: Config = Config::load(ARGS.config.as_ref()).unwrap_or_else(|error| {
+        eprintln!("Error while reading configuration files: {:?}", error);
+        process::exit(1);
+    });
+
+    /// The filter aliases of `CONFIG`. Unless the user asked for
+    /// configuration files with `--config` or `--profile`, an invalid file
+    /// only disables the user-defined aliases.
+    pub static ref FILTER_ALIASES: FilterAliases =
+        if ARGS.config.is_some() || !ARGS.profile.is_empty() {
+            CONFIG.filter_aliases().unwrap_or_else(|error| {
+                eprintln!("Error while reading configuration files: {:?}", error);
+                process::exit(1);
+            })
+        } else {
+            Config::load(None)
+                .and_then(|config| config.filter_aliases())
+                .unwrap_or_else(|error| {
+                    eprintln!(
+                        "Warning: ignoring user-defined filter aliases: {:#}",
+                        error
+                    );
+                    FilterAliases::default()
+                })
+        };
+}
+
+#[cfg(test)]
+lazy_static! {
+    pub static ref CONFIG: Config = Config::default();
+    pub static ref FILTER_ALIASES: FilterAliases = FilterAliases::default();
+}
+
+/// User-defined filter aliases: name, filter and definition.
+#[derive(Debug, Default)]
+pub struct FilterAliases {
+    pub ascii: Vec<(String, u128, String)>,
+    pub unicode_block: Vec<(String, u64, String)>,
+}
+
+/// A mission, with the items of an `--encoding` definition
+/// `ENC_NAME,MIN,AF,UBF,GREP,PATTERNS,offset=…,same-block=…,len=…,label=…`.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MissionConfig {
+    pub encoding: Option<String>,
+    pub chars_min: Option<u8>,
+    pub ascii_filter: Option<String>,
+    pub unicode_block_filter: Option<String>,
+    pub grep_char: Option<String>,
+    pub grep_pattern: Option<String>,
+    pub counter_offset: Option<u64>,
+    pub same_unicode_block: Option<bool>,
+    pub output_line_len: Option<usize>,
+    pub label: Option<String>,
+}
+
+/// A named set of missions.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    /// Names of missions in `Config::missions`.
+    pub missions: Vec<String>,
+    /// Used when `--output-line-len` is not given.
+    pub output_line_len: Option<usize>,
+}
+
+/// The content of all configuration files.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub missions: BTreeMap<String, MissionConfig>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, ProfileConfig>,
+    /// Definitions of ASCII-filter aliases: a hexadecimal mask, the name of
+    /// a built-in alias or a set of ASCII characters, e.g. `[0-9a-f]`.
+    #[serde(default)]
+    pub ascii_filter_aliases: BTreeMap<String, String>,
+    /// Definitions of Unicode-block-filter aliases: a `,`-separated list of
+    /// hexadecimal masks and names of built-in aliases.
+    #[serde(default)]
+    pub unicode_block_filter_aliases: BTreeMap<String, String>,
+}
+
+impl Config {
+    /// Reads the system wide and the user's configuration file, if they
+    /// exist, and the file `path`, which must exist.
+    pub fn load(path: Option<&PathBuf>) -> Result<Self> {
+        let user_dir = env::var_os("XDG_CONFIG_HOME")
+            .filter(|d| !d.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|h| Path::new(&h).join(".config")));
+        let mut config = Self::default();
+        for p in [
+            Some(PathBuf::from(CONFIG_FILE_SYSTEM)),
+            user_dir.map(|d| d.join(CONFIG_FILE_USER)),
+        ]
+        .iter()
+        .flatten()
+        {
+            if p.is_file() {
+                config.merge(Self::from_file(p)?);
+            }
+        }
+        if let Some(p) = path {
+            config.merge(Self::from_file(p)?);
+        }
+        Ok(config)
+    }
+
+    /// Reads and parses the configuration file `path`.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("can not read configuration file `{}`", path.display()))?;
+        Self::parse(&content)
+            .with_context(|| format!("invalid configuration file `{}`", path.display()))
+    }
+
+    /// Parses the content of a configuration file.
+    pub fn parse(content: &str) -> Result<Self> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// Adds the definitions of `other`, replacing those with the same name.
+    pub fn merge(&mut self, other: Self) {
+        self.missions.extend(other.missions);
+        self.profiles.extend(other.profiles);
+        self.ascii_filter_aliases.extend(other.ascii_filter_aliases);
+        self.unicode_block_filter_aliases
+            .extend(other.unicode_block_filter_aliases);
+    }
+
+    /// Compiles the filter alias definitions. Definitions refer to built-in
+    /// aliases only. Names of built-in aliases can not be redefined.
+    pub fn filter_aliases(&self) -> Result<FilterAliases> {
+        fn check_name<T>(name: &str, list: &[([u8; 12], T, [u8; 25])]) -> Result<()> {
+            if name.is_empty() || name.contains(',') || name.starts_with("0x") {
+                Err(anyhow!("invalid filter alias name `{}`.", name))
+            } else if list
+                .iter()
+                .any(|(alias, _, _)| name.as_bytes() == alias.trim_ascii_end())
+            {
+                Err(anyhow!(
+                    "filter alias `{}` is already a built-in alias.",
+                    name
+                ))
+            } else {
+                Ok(())
+            }
+        }
+        let mut aliases = FilterAliases::default();
+        for (name, def) in &self.ascii_filter_aliases {
+            check_name(name, &ASCII_FILTER_ALIASSE)?;
+            let af = parse_af_alias(def.trim())
+                .with_context(|| format!("invalid ASCII-filter alias `{}`.", name))?;
+            aliases.ascii.push((name.clone(), af, def.clone()));
+        }
+        for (name, def) in &self.unicode_block_filter_aliases {
+            check_name(name, &UNICODE_BLOCK_FILTER_ALIASSE)?;
+            let mut ubf = 0;
+            for item in def.split(',').map(str::trim) {
+                let item_ubf = match parse_mask(item, u64::from_str_radix) {
+                    Some(mask) => mask,
+                    None => find_filter_alias(item, &UNICODE_BLOCK_FILTER_ALIASSE, &[])
+                        .ok_or_else(|| anyhow!("unknown filter `{}`", item)),
+                };
+                ubf |= item_ubf
+                    .with_context(|| format!("invalid Unicode-block-filter alias `{}`.", name))?;
+            }
+            aliases.unicode_block.push((name.clone(), ubf, def.clone()));
+        }
+        Ok(aliases)
+    }
+
+    /// Appends the missions of the `profiles` to `mission_defs`. Returns
+    /// the `output_line_len` of the last profile defining one.
+    pub fn expand_profiles<'a>(
+        &'a self,
+        profiles: &[String],
+        mission_defs: &mut Vec<MissionDef<'a>>,
+    ) -> Result<Option<String>> {
+        let mut output_line_len = None;
+        for name in profiles {
+            let profile = self.profiles.get(name).ok_or_else(|| {
+                anyhow!(
+                    "profile `{}` is not defined, available profiles: `{}`.",
+                    name,
+                    self.profiles
+                        .keys()
+                        .cloned()
+                        .collect::<Vec<_>>()
+                        .join("`, `")
+                )
+            })?;
+            for m in &profile.missions {
+                let (m, mission) = self.missions.get_key_value(m).ok_or_else(|| {
+                    anyhow!("profile `{}`: mission `{}` is not defined.", name, m)
+                })?;
+                mission_defs.push(MissionDef::Config(m, mission));
+            }
+            if let Some(n) = profile.output_line_len {
+                output_line_len = Some(n.to_string());
+            }
+        }
+        Ok(output_line_len)
+    }
+}
+
+/// Parses `0x...`, `None` otherwise.
+fn parse_mask<T>(
+    s: &str,
+    from_str_radix: fn(&str, u32) -> Result<T, std::num::ParseIntError>,
+) -> Option<Result<T>> {
+    s.strip_prefix("0x").map(|hex| {
+        from_str_radix(hex, 16)
+            .with_context(|| format!("failed to parse hexadecimal number: `{}`", s))
+    })
+}
+
+/// Parses the definition of an ASCII-filter alias.
+fn parse_af_alias(def: &str) -> Result<u128> {
+    if let Some(mask) = parse_mask(def, u128::from_str_radix) {
+        return mask;
+    }
+    if let Some(set) = def.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+        let chars: Vec<char> = set.chars().collect();
+        if chars.iter().any(|c| !c.is_ascii()) {
+            return Err(anyhow!("`{}` contains non-ASCII characters", def));
+        }
+        let mut af = 0u128;
+        let mut i = 0;
+        while i < chars.len() {
+            // A `-` between two characters denotes a range.
+            if i + 2 < chars.len() && chars[i + 1] == '-' {
+                if chars[i] > chars[i + 2] {
+                    return Err(anyhow!("empty range in `{}`", def));
+                }
+                for c in chars[i]..=chars[i + 2] {
+                    af |= 1 << c as u32;
+                }
+                i += 3;
+            } else {
+                af |= 1 << chars[i] as u32;
+                i += 1;
+            }
+        }
+        return Ok(af);
+    }
+    find_filter_alias(def, &ASCII_FILTER_ALIASSE, &[])
+        .ok_or_else(|| anyhow!("unknown filter `{}`", def))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_profiles() {
+        let mut config = Config::parse(
+            r#"
+            [missions.cyrillic-utf16]
+            encoding = "UTF-16LE"
+            chars_min = 6
+            unicode_block_filter = "Cyrillic"
+            grep_pattern = "пароль|password"
+
+            [missions.ascii]
+            encoding = "ascii"
+
+            [profiles.russian]
+            missions = ["cyrillic-utf16", "ascii"]
+            output_line_len = 80
+            "#,
+        )
+        .unwrap();
+
+        let mut mission_defs = vec![MissionDef::EncOpt("UTF-8".to_string())];
+        let output_line_len = config
+            .expand_profiles(&["russian".to_string()], &mut mission_defs)
+            .unwrap();
+        assert_eq!(
+            mission_defs,
+            vec![
+                MissionDef::EncOpt("UTF-8".to_string()),
+                MissionDef::Config("cyrillic-utf16", &config.missions["cyrillic-utf16"]),
+                MissionDef::Config("ascii", &config.missions["ascii"]),
+            ]
+        );
+        assert_eq!(mission_defs[1].enc_name(), "UTF-16LE");
+        assert_eq!(output_line_len, Some("80".to_string()));
+
+        // Later definitions replace earlier ones.
+        config.merge(
+            Config::parse(
+                r#"
+                [missions.ascii]
+                encoding = "ascii"
+                chars_min = 10
+                "#,
+            )
+            .unwrap(),
+        );
+        assert_eq!(config.missions["ascii"].chars_min, Some(10));
+
+        assert!(config
+            .expand_profiles(&["nope".to_string()], &mut Vec::new())
+            .is_err());
+        assert!(Config::parse("[missions.x]\nencodin = \"ascii\"").is_err());
+        assert!(Config::parse("[profiles.p]\nmissions = [\"x\"]")
+            .unwrap()
+            .expand_profiles(&["p".to_string()], &mut Vec::new())
+            .is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let path = env::temp_dir().join(format!("stringsext-test-{}.toml", std::process::id()));
+        fs::write(&path, "[missions.m]\nencoding = \"UTF-8\"\n").unwrap();
+        let config = Config::load(Some(&path));
+        fs::write(&path, "[missions.m]\nencodings = \"UTF-8\"\n").unwrap();
+        let invalid = Config::load(Some(&path));
+        fs::remove_file(&path).unwrap();
+
+        assert!(config.unwrap().missions.contains_key("m"));
+        assert!(invalid.is_err());
+        assert!(Config::load(Some(&path)).is_err());
+    }
+
+    #[test]
+    fn test_filter_aliases() {
+        let config = Config::parse(
+            r#"
+            [ascii_filter_aliases]
+            Hex-Only = "[0-9a-fA-F]"
+            Digits = "0x3ff000000000000"
+            Printable = "All-Ctrl"
+
+            [unicode_block_filter_aliases]
+            Slavic = "Cyrillic, 0x4"
+            "#,
+        )
+        .unwrap();
+        let aliases = config.filter_aliases().unwrap();
+
+        let af = |name| find_filter_alias(name, &ASCII_FILTER_ALIASSE, &aliases.ascii);
+        let hex = af("Hex-Only").unwrap();
+        assert!(hex & 1 << b'7' != 0 && hex & 1 << b'F' != 0 && hex & 1 << b'f' != 0);
+        assert_eq!(hex & 1 << b'g', 0);
+        assert_eq!(af("Digits"), Some(0x3ff000000000000));
+        assert_eq!(af("Printable"), af("All-Ctrl"));
+        // Abbreviations prefer built-in aliases.
+        assert_eq!(af("D"), af("Default"));
+        assert_eq!(af("Di"), Some(0x3ff000000000000));
+        assert_eq!(af("Nope"), None);
+
+        let (name, ubf, _) = &aliases.unicode_block[0];
+        assert_eq!(name, "Slavic");
+        assert_eq!(
+            *ubf,
+            find_filter_alias("Cyrillic", &UNICODE_BLOCK_FILTER_ALIASSE, &[]).unwrap() | 0x4
+        );
+
+        assert!(Config::parse("[ascii_filter_aliases]\nX = \"[ä]\"")
+            .unwrap()
+            .filter_aliases()
+            .is_err());
+        // Built-in aliases can not be redefined.
+        assert!(Config::parse("[ascii_filter_aliases]\nAll = \"0x1\"")
+            .unwrap()
+            .filter_aliases()
+            .is_err());
+        assert!(
+            Config::parse("[unicode_block_filter_aliases]\nAfrican = \"0x1\"")
+                .unwrap()
+                .filter_aliases()
+                .is_err()
+        );
+        assert!(
+            Config::parse("[unicode_block_filter_aliases]\nX = \"Klingon\"")
+                .unwrap()
+                .filter_aliases()
+                .is_err()
+        );
+    }
+}
@@ -2,11 +2,14 @@
 
 extern crate encoding_rs;
 
+use crate::format::FindingFields;
+use crate::format::DELIMITED;
 use crate::input::ByteCounter;
 use crate::mission::Mission;
+use crate::mission::MISSIONS;
+use crate::options::OutputFormat;
 use crate::options::Radix;
 use crate::options::ARGS;
-use crate::options::ASCII_ENC_LABEL;
 use std::io::Write;
 use std::str;
 
@@ -28,7 +31,7 @@ pub const OUTPUT_BUF_LEN: usize = 0x40;
 /// prints  a `Finding`.
 pub const OUTPUT_LINE_METADATA_LEN: usize = 40;
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
 /// Used to express the precision of `Finding::position` when the algorithm can
 /// not determine its exact position.
 pub enum Precision {
@@ -64,6 +67,13 @@ pub struct Finding<'a> {
     /// that the position is not exactly known, we indicate if the finding is
     /// somewhere before or after `position`.
     pub position_precision: Precision,
+    /// The exact position of the first input byte `s` was decoded from. Unlike
+    /// `position`, this is never an approximation. Only known with
+    /// `Mission::record_char_spans`, `position` otherwise.
+    pub start: ByteCounter,
+    /// The number of input bytes `s` was decoded from. Only known with
+    /// `Mission::record_char_spans`, `0` otherwise.
+    pub byte_len: usize,
     /// Whatever the original encoding was, the result string `s` is always stored as
     /// UTF-8. `s` is a `&str` pointing into `FindingCollection::output_buffer`.
     pub s: &'a str,
@@ -71,6 +81,22 @@ pub struct Finding<'a> {
     /// from the previous `scanner::scan()` run. This can happen when a finding from
     /// the previous run has hit the`input_buffer`-boundary.
     pub s_completes_previous_s: bool,
+    /// The grep pattern found in the string starting with `s`, if
+    /// `Mission::grep_patterns` is set. Chunks completing a previous `s` do
+    /// not repeat it.
+    pub matched_pattern: Option<&'static str>,
+    /// The plausibility score of the string starting with `s`, if
+    /// `Mission::min_score` is set and the string has characters to rate.
+    /// Chunks completing a previous `s` do not repeat it.
+    pub score: Option<u8>,
+    /// The language of the string starting with `s` and the confidence in
+    /// percent, if `Mission::identify_language` is set and the string is
+    /// long enough. Chunks completing a previous `s` do not repeat it.
+    pub language: Option<(&'static str, u8)>,
+    /// When the string starting with `s` contains doubly encoded UTF-8
+    /// (mojibake) and `Mission::detect_mojibake` is set, this holds the whole
+    /// repaired string. Chunks completing a previous `s` do not repeat it.
+    pub mojibake_repaired: Option<String>,
 }
 
 impl Eq for Finding<'_> {}
@@ -80,7 +106,7 @@ impl PartialEq for Finding<'_> {
     fn eq(&self, other: &Self) -> bool {
         (self.position == other.position)
             && (self.position_precision == other.position_precision)
-            && (self.mission.encoding.name() == other.mission.encoding.name())
+            && (self.mission.encoding_name() == other.mission.encoding_name())
             && (self.mission.filter == other.mission.filter)
             && (self.s == other.s)
     }
@@ -109,7 +135,16 @@ impl PartialOrd for Finding<'_> {
 }
 
 impl<'a> Finding<'a> {
-    pub fn print(&self, out: &mut dyn Write) -> Result<(), Box<std::io::Error>> {
+    /// Prints the finding. `also` are other missions having found the same
+    /// string, see `--merge`.
+    pub fn print(&self, out: &mut dyn Write, also: &[&Mission]) -> Result<(), Box<std::io::Error>> {
+        match ARGS.format {
+            OutputFormat::Text => (),
+            OutputFormat::Jsonl => return FindingFields::new(self, also).write_jsonl(out),
+            OutputFormat::Csv | OutputFormat::Tsv => {
+                return DELIMITED.write_row(&FindingFields::new(self, also), out)
+            }
+        }
         out.write_all(b"\n")?;
         if !ARGS.no_metadata {
             if ARGS.inputs.len() > 1 {
@@ -138,19 +173,40 @@ impl<'a> Finding<'a> {
                 };
             }
 
-            if ARGS.encoding.len() > 1 {
-                // map 0 -> 'a', 1 -> 'b', 2 -> 'c' ...
-                out.write_all(&[b'(', self.mission.mission_id + 97_u8, b' '])?;
-                out.write_all(if self.mission.print_encoding_as_ascii {
-                    ASCII_ENC_LABEL.as_bytes()
-                } else {
-                    self.mission.encoding.name().as_bytes()
-                })?;
+            if MISSIONS.len() > 1 {
+                out.write_all(b"(")?;
+                out.write_all(self.mission.label.as_bytes())?;
+                out.write_all(b" ")?;
+                out.write_all(self.mission.encoding_name().as_bytes())?;
+                for m in also {
+                    out.write_all(b", ")?;
+                    out.write_all(m.label.as_bytes())?;
+                    out.write_all(b" ")?;
+                    out.write_all(m.encoding_name().as_bytes())?;
+                }
                 // After ")" send two tabs.
                 out.write_all(b")\t")?;
             };
+
+            if let Some(score) = self.score {
+                out.write_fmt(format_args!("{}%\t", score))?;
+            };
+
+            if let Some((code, confidence)) = self.language {
+                out.write_fmt(format_args!("{}:{}%\t", code, confidence))?;
+            };
+
+            if let Some(p) = self.matched_pattern {
+                out.write_all(b"[")?;
+                out.write_all(p.as_bytes())?;
+                out.write_all(b"]\t")?;
+            };
         };
         out.write_all(self.s.as_bytes())?;
+        if let Some(ref repaired) = self.mojibake_repaired {
+            out.write_all(b"\t=> ")?;
+            out.write_all(repaired.as_bytes())?;
+        };
         Ok(())
     }
 }
@@ -10,8 +10,15 @@ use crate::helper::starts_with_multibyte_char;
 use crate::helper::SplitStr;
 use crate::input::ByteCounter;
 use crate::input::INPUT_BUF_LEN;
+use crate::mission::Mission;
+use crate::mojibake;
+use crate::scanner::HeldChunk;
+use crate::scanner::HeldStr;
+use crate::scanner::ScannerDecoder;
 use crate::scanner::ScannerState;
+use crate::scanner::HELD_STR_LEN_MAX;
 use encoding_rs::DecoderResult;
+use itertools::kmerge;
 use std::io::Write;
 use std::marker::PhantomPinned;
 use std::ops::Deref;
@@ -19,6 +26,44 @@ use std::pin::Pin;
 use std::slice;
 use std::str;
 
+/// Returns where to start decoding, so that the string around the pre-search
+/// hit at `hit` is found from its beginning. We go back window by window as
+/// long as the decoded window contains no string boundary, but not further
+/// than `lower_bound`, e.g. the beginning of `input_buffer`. The returned
+/// position lies a multiple of 4 bytes before `hit`, so that the decoder
+/// starts with the hit's code unit alignment, e.g. in UTF-16.
+fn pre_search_str_start(
+    mission: &'static Mission,
+    input_buffer: &[u8],
+    lower_bound: usize,
+    hit: usize,
+    decoder_input_window: usize,
+) -> usize {
+    let step = (decoder_input_window & !3).max(4);
+    // Some encodings output more than 3 bytes per input byte.
+    let mut output_buffer_bytes = vec![0u8; 8 * step];
+    let mut start = hit;
+    while start >= lower_bound + step {
+        let window_start = start - step;
+        let output_buffer: &mut str =
+            as_mut_str_unchecked_no_borrow_check!(output_buffer_bytes[..]);
+        let (decoder_result, _, written) = ScannerDecoder::new(mission)
+            .decode_to_str_without_replacement(
+                &input_buffer[window_start..start],
+                &mut *output_buffer,
+                false,
+            );
+        if decoder_result != DecoderResult::InputEmpty
+            || !mission.filter.pass_all_chars(&output_buffer[..written])
+        {
+            // The string starts in this window.
+            return window_start;
+        }
+        start = window_start;
+    }
+    hit - ((hit - lower_bound) & !3)
+}
+
 /// `FindingCollection` is a set of ordered `Finding` s.
 /// The box `output_buffer_bytes` and the struct `Finding` are self-referential,
 /// because `Finding.s` points into `output_buffer_bytes`. Therefore, special
@@ -34,6 +79,10 @@ pub struct FindingCollection<'a> {
     /// All concurrent `ScannerState::scan()` start at the same byte. All
     /// `Finding.position` refer to `first_byte_position` as zero.
     pub first_byte_position: ByteCounter,
+    /// The origin of the scanned input, see `Finding::input_file_id`.
+    pub input_file_id: Option<u8>,
+    /// Number of input bytes scanned by `Self::from()`.
+    pub input_len: usize,
     /// A buffer containing the UTF-8 representation of all findings during one
     /// `Self::from()` run. First, the `Decoder` fills in some UTF-8
     /// string. This string is then filtered. The result of this filtering is
@@ -41,6 +90,15 @@ pub struct FindingCollection<'a> {
     /// `Finding`-objects have a `&str`-member called `Finding.s` that is
     /// a substring (slice) of `output_buffer_bytes`.
     output_buffer_bytes: Box<[u8]>,
+    /// The text of the held strings released by `Self::from()`. Like
+    /// `output_buffer_bytes`, it is pointed to by `Finding.s`. The strings
+    /// are never modified and their content does not move with the vector.
+    held_strs: Vec<Box<str>>,
+    /// The position of the first chunk of a string the scanner still holds
+    /// after `Self::from()`, see `ScannerState::held_str`. The chunk will be
+    /// in a later collection: the findings of other missions from this
+    /// position on must wait for it, see `PendingFindings`.
+    pub held_position: Option<ByteCounter>,
     /// If `output_buffer` is too small to receive all findings, this is set
     /// `true` indicating that only the last `Finding` s could be stored. At
     /// least one `Finding` got lost. This incident is reported to the user. If
@@ -56,7 +114,11 @@ impl FindingCollection<'_> {
         FindingCollection {
             v: Vec::new(),
             first_byte_position: byte_offset,
+            input_file_id: None,
+            input_len: 0,
             output_buffer_bytes,
+            held_strs: Vec::new(),
+            held_position: None,
             str_buf_overflow: false,
             _marker: PhantomPinned,
         }
@@ -73,8 +135,10 @@ impl FindingCollection<'_> {
     /// The input parameter `input_file_id` is forwarded and stored in each `Finding`
     /// of the returned `FindingCollection`.\
     /// The function keeps its inner state in
-    /// `ss.decoder`, `ss.last_scan_run_leftover`,
-    /// `ss.last_run_str_was_printed_and_is_maybe_cut_str` and `ss.consumed_bytes`.\
+    /// `ss.decoder`, `ss.last_scan_run_leftover`, `ss.char_spans`,
+    /// `ss.next_char_position`,
+    /// `ss.last_run_str_was_printed_and_is_maybe_cut_str`,
+    /// `ss.last_run_str_is_rejected`, `ss.held_str` and `ss.consumed_bytes`.\
     /// `ss.mission` is not directly used in this function, but some part of it, the
     /// `ss.mission.filter`, is forwarded to the helper function:
     /// `helper::SplitStr::next()`.\
@@ -88,6 +152,8 @@ impl FindingCollection<'_> {
         is_last_input_buffer: bool,
     ) -> Pin<Box<FindingCollection<'a>>> {
         let mut fc = FindingCollection::new(ss.consumed_bytes);
+        fc.input_file_id = input_file_id;
+        fc.input_len = input_buffer.len();
         // We do not clear `output_buffer_bytes`, we just overwrite.
 
         // Initialisation
@@ -114,12 +180,26 @@ impl FindingCollection<'_> {
         }
         let mut last_window_str_was_printed_and_is_maybe_cut_str =
             ss.last_run_str_was_printed_and_is_maybe_cut_str;
+        // Set when the current string did not pass the string filters. Then
+        // all chunks completing it are dropped too, also in the next windows.
+        let mut str_is_rejected = ss.last_run_str_is_rejected;
+        let mut last_window_scripts = ss.last_run_scripts;
 
         // In many encodings (e.g. UTF16), to fill one `output_line` we need more bytes of input.
         // If ever the string gets longer than `output_line_char_nb_max`, `SplitStr` will wrap the line.
         let decoder_input_window = 2 * ss.mission.output_line_char_nb_max;
         let mut is_last_window = false;
 
+        // In pre-search mode, these are the positions of the encoded keyword
+        // in `input_buffer`.
+        let mission = ss.mission;
+        let pre_search_hits = mission.pre_search.as_ref().map(|ps| {
+            let (hits, partial_len) = ps.find_hits(input_buffer, ss.pre_search_partial_len);
+            ss.pre_search_partial_len = partial_len;
+            (hits, ps.needle.len())
+        });
+        let mut next_hit = 0usize;
+
         // iterate over `input_buffer with ``decoder_input_window`-sized slices.
         '_input_window_loop: while decoder_input_start < input_buffer.len() {
             decoder_input_end = match decoder_input_start + decoder_input_window {
@@ -130,17 +210,67 @@ impl FindingCollection<'_> {
                 }
             };
 
+            // In pre-search mode, we only decode windows close to a hit:
+            // the one with the hit and the windows on each side covering the
+            // surrounding string. We never skip the rest of an unfinished
+            // string.
+            if let Some((ref hits, needle_len)) = pre_search_hits {
+                while next_hit < hits.len() && hits[next_hit] + needle_len <= decoder_input_start {
+                    next_hit += 1;
+                }
+                let str_is_open = last_window_str_was_printed_and_is_maybe_cut_str
+                    || last_window_leftover_len > 0;
+                let skip_to = match hits.get(next_hit) {
+                    _ if str_is_open => None,
+                    Some(&h) => match pre_search_str_start(
+                        ss.mission,
+                        input_buffer,
+                        decoder_input_start,
+                        h,
+                        decoder_input_window,
+                    ) {
+                        start if start < decoder_input_end => None,
+                        start => Some(start),
+                    },
+                    None => Some(input_buffer.len()),
+                };
+                if let Some(skip_to) = skip_to {
+                    // The skipped bytes do not continue anything.
+                    ss.decoder
+                        .skip(ss.mission, &input_buffer[decoder_input_start..skip_to]);
+                    decoder_input_start = skip_to;
+                    ss.next_char_position = ss.consumed_bytes + skip_to as ByteCounter;
+                    last_window_leftover_len = 0;
+                    last_window_str_was_printed_and_is_maybe_cut_str = false;
+                    str_is_rejected = false;
+                    continue;
+                }
+            }
+
             // Decode one `input_window`, go as far as you can, then loop again.
             'decoder: loop {
                 let output_buffer_slice: &mut str = as_mut_str_unchecked_no_borrow_check!(
                     &mut fc.output_buffer_bytes[decoder_output_start..]
                 );
+                // Recording the character spans is slow for encodings with
+                // characters of varying width: we do it only when needed.
                 let (decoder_result, decoder_read, decoder_written) =
-                    ss.decoder.decode_to_str_without_replacement(
-                        &input_buffer[decoder_input_start..decoder_input_end],
-                        output_buffer_slice,
-                        extra_round,
-                    );
+                    if ss.mission.record_char_spans {
+                        ss.decoder.decode_with_spans(
+                            &input_buffer[decoder_input_start..decoder_input_end],
+                            output_buffer_slice,
+                            extra_round,
+                            ss.consumed_bytes + decoder_input_start as ByteCounter,
+                            &mut ss.next_char_position,
+                            &mut ss.char_spans[decoder_output_start..],
+                        )
+                    } else {
+                        ss.decoder.decode_to_str_without_replacement(
+                            &input_buffer[decoder_input_start..decoder_input_end],
+                            output_buffer_slice,
+                            extra_round,
+                        )
+                    };
 
                 // If the assumption is wrong we change later.
                 let mut position_precision = Precision::Exact;
@@ -177,8 +307,7 @@ impl FindingCollection<'_> {
                         // The only way to find out from which scan() run the first
                         // bytes came, is to scan again with a new Decoder and compare
                         // the results.
-                        let mut empty_decoder =
-                            ss.decoder.encoding().new_decoder_without_bom_handling();
+                        let mut empty_decoder = ScannerDecoder::new(ss.mission);
                         // A short buffer on the stack will do.
                         let mut buffer_bytes = [0u8; 8];
                         // This is save, because there are only valid 0 in
@@ -243,25 +372,102 @@ impl FindingCollection<'_> {
                 // Now we split `split_str_buffer` into substrings and store them in
                 // vector `fc.v`.
 
-                '_chunk_loop: for chunk in SplitStr::new(
+                let mut split_str = SplitStr::new(
                     split_str_buffer,
                     ss.mission.chars_min_nb,
                     ss.mission.require_same_unicode_block,
+                    ss.mission.max_scripts,
                     continue_str_if_possible,
                     invalid_bytes_after_split_str_buffer,
                     ss.mission.filter,
                     ss.mission.output_line_char_nb_max,
-                ) {
+                );
+                if continue_str_if_possible {
+                    split_str.scripts = last_window_scripts;
+                }
+                '_chunk_loop: for chunk in split_str.by_ref() {
+                    if chunk.s_completes_previous_s {
+                        if str_is_rejected {
+                            // Follow the rejected string into the next
+                            // window, if it goes on.
+                            last_window_str_was_printed_and_is_maybe_cut_str = chunk.s_is_maybe_cut;
+                            continue;
+                        }
+                    } else {
+                        str_is_rejected = false;
+                        // The held string ended before this chunk.
+                        if let Some(held_str) = ss.held_str.take() {
+                            fc.push_held_str(ss.mission, held_str);
+                        }
+                    }
+
                     if !chunk.s_is_to_be_filtered_again {
-                        // We keep it for printing.
-                        fc.v.push(Finding {
-                            input_file_id,
-                            mission: ss.mission,
-                            position: ss.consumed_bytes + decoder_input_start as ByteCounter,
-                            position_precision,
-                            s: chunk.s,
-                            s_completes_previous_s: chunk.s_completes_previous_s,
-                        });
+                        // The input bytes `chunk.s` was decoded from.
+                        let first_char =
+                            chunk.s.as_ptr() as usize - fc.output_buffer_bytes.as_ptr() as usize;
+                        let last_char =
+                            first_char + chunk.s.char_indices().last().map_or(0, |(i, _)| i);
+                        let position = ss.consumed_bytes + decoder_input_start as ByteCounter;
+                        let (start, byte_len) = if ss.mission.record_char_spans {
+                            let start = ss.char_spans[first_char].start;
+                            (start, (ss.char_spans[last_char].end - start) as usize)
+                        } else {
+                            (position, 0)
+                        };
+                        if ss.mission.holds_whole_str() {
+                            // A match or a doubly encoded character may span
+                            // the chunks the string is wrapped to and the
+                            // windows and buffers it is decoded in. We hold
+                            // the chunks until the string ends.
+                            let held_str = ss.held_str.get_or_insert_with(HeldStr::default);
+                            held_str.s.push_str(chunk.s);
+                            held_str.char_nb += chunk.s.chars().count();
+                            held_str.chunks.push(HeldChunk {
+                                input_file_id,
+                                position,
+                                position_precision,
+                                start,
+                                byte_len,
+                                len: chunk.s.len(),
+                                s_completes_previous_s: chunk.s_completes_previous_s,
+                            });
+                            if ss
+                                .mission
+                                .chars_max_nb
+                                .is_some_and(|max| held_str.char_nb > max)
+                            {
+                                // Too long already, whatever follows: we drop
+                                // it and its rest.
+                                ss.held_str = None;
+                                str_is_rejected = true;
+                            } else if !chunk.s_is_maybe_cut {
+                                if let Some(held_str) = ss.held_str.take() {
+                                    fc.push_held_str(ss.mission, held_str);
+                                }
+                            } else if held_str.s.len() - held_str.context_len >= HELD_STR_LEN_MAX {
+                                // We do not hold more: we judge the string
+                                // part by part.
+                                if let Some(held_str) = ss.held_str.take() {
+                                    ss.held_str = Some(fc.push_held_str_part(ss.mission, held_str));
+                                }
+                            }
+                        } else {
+                            // We keep it for printing.
+                            fc.v.push(Finding {
+                                input_file_id,
+                                mission: ss.mission,
+                                position,
+                                position_precision,
+                                start,
+                                byte_len,
+                                s: chunk.s,
+                                s_completes_previous_s: chunk.s_completes_previous_s,
+                                matched_pattern: None,
+                                score: None,
+                                language: None,
+                                mojibake_repaired: None,
+                            });
+                        }
 
                         last_window_leftover_len = 0;
 
@@ -288,6 +494,13 @@ impl FindingCollection<'_> {
                     // since we do not know their exact position.
                     position_precision = Precision::After;
                 }
+                // Nothing in the next window can complete the held string.
+                if !last_window_str_was_printed_and_is_maybe_cut_str {
+                    if let Some(held_str) = ss.held_str.take() {
+                        fc.push_held_str(ss.mission, held_str);
+                    }
+                }
+                last_window_scripts = split_str.scripts;
 
                 decoder_output_start += decoder_written;
 
@@ -313,7 +526,7 @@ impl FindingCollection<'_> {
                             ss.consumed_bytes,
                             ss.consumed_bytes + decoder_input_start as ByteCounter,
                             input_file_id,
-                            char::from(ss.mission.mission_id + 97)
+                            ss.mission.label
                         );
                         decoder_output_start = 0;
                         debug_assert!(
@@ -326,6 +539,12 @@ impl FindingCollection<'_> {
             }
         }
 
+        if is_last_input_buffer {
+            if let Some(held_str) = ss.held_str.take() {
+                fc.push_held_str(ss.mission, held_str);
+            }
+        }
+
         // Store possible leftovers in `ScannerState` for next `scanner::scan()`.
         let last_window_leftover = as_str_unchecked_no_borrow_check!(
             fc.output_buffer_bytes
@@ -333,14 +552,110 @@ impl FindingCollection<'_> {
         );
         // Update inner state for next `scan()` run.
         ss.last_scan_run_leftover = String::from(last_window_leftover);
+        if ss.mission.record_char_spans {
+            ss.char_spans.copy_within(
+                decoder_output_start - last_window_leftover_len..decoder_output_start,
+                0,
+            );
+        }
         ss.last_run_str_was_printed_and_is_maybe_cut_str =
             last_window_str_was_printed_and_is_maybe_cut_str;
+        ss.last_run_str_is_rejected = str_is_rejected;
+        ss.last_run_scripts = last_window_scripts;
         ss.consumed_bytes += decoder_input_start as ByteCounter;
+        fc.held_position = ss
+            .held_str
+            .as_ref()
+            .and_then(|held_str| held_str.chunks.first())
+            .map(|chunk| chunk.position);
 
         // Now we pin the `FindingCollection`.
         Box::pin(fc)
     }
 
+    /// Filters the string `held_str` as a whole with
+    /// `Mission::pass_str_filter()`, unless a previous part of it passed
+    /// already. If it passes, its chunks are stored as `Finding` s. The
+    /// filter results and the repaired string go to the first one. Returns
+    /// `true` if the string passed.
+    fn push_held_str(&mut self, mission: &'static Mission, held_str: HeldStr) -> bool {
+        if held_str.chunks.is_empty() {
+            return held_str.is_accepted;
+        }
+        let (passes, matched_pattern, score, language) = if held_str.is_accepted {
+            (true, None, None, None)
+        } else {
+            mission.pass_str_filter(&held_str.s)
+        };
+        if !passes {
+            return false;
+        }
+        let mut mojibake_repaired = if mission.detect_mojibake {
+            mojibake::repair(&held_str.s[held_str.context_len..])
+        } else {
+            None
+        };
+        let s: Box<str> = held_str.s.into_boxed_str();
+        // This is safe, because `s` is kept in `self.held_strs` and never
+        // modified.
+        let s_unbound = as_str_unchecked_no_borrow_check!(s.as_bytes());
+        self.held_strs.push(s);
+        let mut chunk_start = held_str.context_len;
+        for (i, chunk) in held_str.chunks.into_iter().enumerate() {
+            let is_first = i == 0;
+            self.v.push(Finding {
+                input_file_id: chunk.input_file_id,
+                mission,
+                position: chunk.position,
+                position_precision: chunk.position_precision,
+                start: chunk.start,
+                byte_len: chunk.byte_len,
+                s: &s_unbound[chunk_start..chunk_start + chunk.len],
+                // Nothing is printed before a part that passes first.
+                s_completes_previous_s: chunk.s_completes_previous_s
+                    && (!is_first || held_str.is_accepted),
+                matched_pattern: matched_pattern.filter(|_| is_first),
+                score: score.filter(|_| is_first),
+                language: language.filter(|_| is_first),
+                mojibake_repaired: mojibake_repaired.take(),
+            });
+            chunk_start += chunk.len;
+        }
+        true
+    }
+
+    /// Like `push_held_str()`, but for a part of a string longer than
+    /// `HELD_STR_LEN_MAX`, that goes on. Returns what is held of the string
+    /// for its rest: the last `Mission::output_line_char_nb_max` characters of
+    /// the part as context, so that matches spanning the parts are found.
+    /// Parts are printed, if they or an earlier part pass the filters.
+    fn push_held_str_part(&mut self, mission: &'static Mission, held_str: HeldStr) -> HeldStr {
+        if held_str.context_len == 0 {
+            if let Some(chunk) = held_str.chunks.first() {
+                eprintln!(
+                    "Warning: scanner ({}): the string at {:x} is longer than {} bytes, \
+                     it is filtered in parts.",
+                    mission.label, chunk.position, HELD_STR_LEN_MAX
+                );
+            }
+        }
+        let context_start = held_str
+            .s
+            .char_indices()
+            .rev()
+            .nth(mission.output_line_char_nb_max - 1)
+            .map_or(0, |(i, _)| i);
+        let mut rest = HeldStr {
+            s: held_str.s[context_start..].to_string(),
+            context_len: held_str.s.len() - context_start,
+            char_nb: held_str.char_nb,
+            chunks: Vec::new(),
+            is_accepted: false,
+        };
+        rest.is_accepted = self.push_held_str(mission, held_str);
+        rest
+    }
+
     /// Clears the buffer to make more space after buffer overflow. Tag the
     /// collection as overflowed.
     pub fn clear_and_mark_incomplete(&mut self) {
@@ -361,7 +676,7 @@ impl FindingCollection<'_> {
             );
         }
         for finding in &self.v {
-            finding.print(out)?;
+            finding.print(out, &[])?;
         }
         Ok(())
     }
@@ -414,14 +729,73 @@ impl<'a> Deref for FindingCollection<'a> {
     }
 }
 
+/// The `FindingCollection` s received by the merger, whose findings are not
+/// all printed yet. The findings are printed in order of their position. While
+/// a mission holds a string, see `FindingCollection::held_position`, the
+/// findings of all missions from its position on are kept back, also for
+/// `--merge` and `--unique`.
+#[derive(Default)]
+pub struct PendingFindings<'a> {
+    /// The collections and the number of their findings printed already.
+    v: Vec<(Pin<Box<FindingCollection<'a>>>, usize)>,
+    /// The position of the first string the missions still hold.
+    held_position: Option<ByteCounter>,
+}
+
+impl<'a> PendingFindings<'a> {
+    /// Adds the `FindingCollection` s of all missions for the same input.
+    pub fn push_batch(&mut self, batch: Vec<Pin<Box<FindingCollection<'a>>>>) {
+        self.held_position = batch.iter().filter_map(|fc| fc.held_position).min();
+        self.v.extend(batch.into_iter().map(|fc| (fc, 0)));
+    }
+
+    /// Calls `f` with the findings that can be printed now, sorted by
+    /// position, and forgets them.
+    pub fn release<E>(&mut self, f: impl FnOnce(&[&Finding]) -> Result<(), E>) -> Result<(), E> {
+        let ends: Vec<usize> = self
+            .v
+            .iter()
+            .map(|(fc, _)| match self.held_position {
+                Some(p) => fc.v.partition_point(|f| f.position < p),
+                None => fc.v.len(),
+            })
+            .collect();
+        let findings: Vec<&Finding> = kmerge(
+            self.v
+                .iter()
+                .zip(&ends)
+                .map(|((fc, printed), &end)| &fc.v[*printed..end]),
+        )
+        .collect();
+        f(&findings)?;
+        for ((_, printed), end) in self.v.iter_mut().zip(ends) {
+            *printed = end;
+        }
+        self.v.retain(|(fc, printed)| *printed < fc.v.len());
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::finding::Precision;
     use crate::finding_collection::FindingCollection;
     use crate::mission::Mission;
+    use crate::pre_search::PreSearch;
+    use crate::scanner::tests::with_char_spans;
+    use crate::scanner::tests::MISSION_ALL_UTF8;
     use crate::scanner::tests::MISSION_ALL_X_USER_DEFINED;
     use crate::scanner::tests::MISSION_ASCII;
+    use crate::scanner::tests::MISSION_DOUBLE_UTF8;
+    use crate::scanner::tests::MISSION_GREP_PATTERNS;
+    use crate::scanner::tests::MISSION_ISO_2022_JP;
+    use crate::scanner::tests::MISSION_MAX_SCRIPTS;
+    use crate::scanner::tests::MISSION_PRE_SEARCH;
+    use crate::scanner::tests::MISSION_REGEX;
+    use crate::scanner::tests::MISSION_UTF16LE;
+    use crate::str_stats::StrStatsFilter;
+    use encoding_rs::ISO_2022_JP;
     use std::str;
 
     // To see println!() output in test run, launch
@@ -500,4 +874,595 @@ mod tests {
         assert!(!ss.last_run_str_was_printed_and_is_maybe_cut_str);
         assert_eq!(ss.last_scan_run_leftover, "");
     }
+
+    #[test]
+    fn test_double_utf8() {
+        let m: &'static Mission = with_char_spans(&MISSION_DOUBLE_UTF8);
+
+        let mut ss = ScannerState::new(m);
+
+        // "Café" and "€uro" doubly encoded, "ab€" simply encoded.
+        let input = "CafÃ©\u{0}â‚¬uro\u{0}ab€".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[0].s, "Café");
+        assert_eq!(fc.v[0].position_precision, Precision::Exact);
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_000, 7));
+        assert_eq!(fc.v[0].mojibake_repaired, None);
+        assert_eq!(fc.v[1].s, "€uro");
+        assert_eq!(fc.v[1].position_precision, Precision::After);
+        assert_eq!((fc.v[1].start, fc.v[1].byte_len), (10_008, 10));
+        assert_eq!(fc.v[0].mission.encoding_name(), "double-UTF-8");
+
+        // "Ã" ends the invalid sequence of "é€" and starts "été". The buffer
+        // boundary cuts it.
+        let mut ss = ScannerState::new(m);
+        let input = "\u{0}é€Ã©tÃ©abc\u{0}".as_bytes();
+        let fc = FindingCollection::from(&mut ss, Some(0), &input[..7], false);
+        assert!(fc.v.is_empty());
+        let fc = FindingCollection::from(&mut ss, Some(0), &input[7..], true);
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "étéabc");
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_006, 12));
+    }
+
+    #[test]
+    fn test_mojibake_wrapped() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            detect_mojibake: true,
+            ..MISSION_ALL_UTF8.clone()
+        }));
+
+        let mut ss = ScannerState::new(m);
+
+        // The line is wrapped between "Ã" and "©".
+        let input = "abcdefghiÃ©xyz\u{0}".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[0].s, "abcdefghiÃ");
+        assert_eq!(fc.v[0].mojibake_repaired.as_deref(), Some("abcdefghiéxyz"));
+        assert_eq!(fc.v[1].s, "©xyz");
+        assert_eq!(fc.v[1].mojibake_repaired, None);
+    }
+
+    #[test]
+    fn test_finding_span() {
+        // Without `Mission::record_char_spans`, the exact span is unknown.
+        let mut ss = ScannerState::new(&MISSION_UTF16LE);
+        let input = b"\0\0P\0a\0s\0s\x001\0\0\0";
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_000, 0));
+
+        // The string starts 2 bytes after the window start.
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_UTF16LE));
+        let input = b"\0\0P\0a\0s\0s\x001\0\0\0";
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "Pass1");
+        assert_eq!(fc.v[0].position, 10_000);
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_002, 10));
+
+        // A short string and a multibyte character cut by the buffer
+        // boundary are completed in the next run.
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ALL_UTF8));
+        let fc = FindingCollection::from(&mut ss, Some(0), b"\0ab\xe2", false);
+        assert!(fc.v.is_empty());
+        let fc = FindingCollection::from(&mut ss, Some(0), b"\x82\xaccd\0", true);
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "ab€cd");
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_001, 7));
+
+        // With escape sequences, the first character spans them.
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ISO_2022_JP));
+        let input = b"\0\x1b$B\x30\x21\x30\x21\x30\x21\x1b(Babc\0";
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "亜亜亜abc");
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_001, 15));
+
+        // Single byte encodings: the spans of lines wrapped by `SplitStr`
+        // and of the strings after dropped characters.
+        let input = b"abcdefg\x58\x59\x80\x82h\x83ijk\x89\x90";
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ALL_X_USER_DEFINED));
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_000, 10));
+        assert_eq!((fc.v[1].start, fc.v[1].byte_len), (10_010, 8));
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ASCII));
+        let fc = FindingCollection::from(&mut ss, Some(0), input, false);
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[1].s, "ijk");
+        assert_eq!((fc.v[1].start, fc.v[1].byte_len), (10_013, 3));
+    }
+
+    #[test]
+    fn test_grep_patterns() {
+        let m: &'static Mission = &MISSION_GREP_PATTERNS;
+
+        let mut ss = ScannerState::new(m);
+
+        // The second string is wrapped in the middle of "secret", and cut
+        // again by the decoder window boundary.
+        let input = "no match\u{0}abcdefghisecret\u{0}мой пароль".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 4);
+        assert_eq!(fc.v[0].s, "abcdefghis");
+        assert_eq!(fc.v[0].matched_pattern, Some("secret"));
+        assert_eq!(fc.v[0].position_precision, Precision::After);
+        assert_eq!(fc.v[1].s, "e");
+        assert!(fc.v[1].s_completes_previous_s);
+        assert_eq!(fc.v[1].matched_pattern, None);
+        assert_eq!(fc.v[2].s, "cret");
+        assert!(fc.v[2].s_completes_previous_s);
+        assert_eq!(fc.v[3].s, "мой пароль");
+        assert_eq!(fc.v[3].matched_pattern, Some("пароль"));
+    }
+
+    /// Scans `input` in slices of `INPUT_BUF_LEN` bytes, as `input::Slicer`
+    /// does, and returns the strings found. Chunks completing a string are
+    /// joined.
+    fn scan_slices(m: &'static Mission, input: &[u8]) -> Vec<String> {
+        let mut ss = ScannerState::new(m);
+        let mut v: Vec<String> = Vec::new();
+        let mut slices = input.chunks(INPUT_BUF_LEN).peekable();
+        while let Some(slice) = slices.next() {
+            let fc = FindingCollection::from(&mut ss, None, slice, slices.peek().is_none());
+            for f in fc.v.iter() {
+                match v.last_mut() {
+                    Some(s) if f.s_completes_previous_s => s.push_str(f.s),
+                    _ => v.push(f.s.to_string()),
+                }
+            }
+        }
+        v
+    }
+
+    #[test]
+    fn test_grep_patterns_long_str() {
+        let m: &'static Mission = &MISSION_GREP_PATTERNS;
+
+        // The first string has the pattern at its beginning, the second after
+        // its first 0x100 bytes: both are printed as a whole.
+        let long = "0123456789abcdefghijklmnopqrstuvwxyz".repeat(8);
+        assert!(long.len() > 0x100);
+        let input = format!("secret {long}\u{0}{long}secret\u{0}{long}\u{0}a secret\u{0}");
+
+        assert_eq!(
+            scan_slices(m, input.as_bytes()),
+            vec![
+                format!("secret {long}"),
+                format!("{long}secret"),
+                "a secret".to_string()
+            ]
+        );
+    }
+
+    /// Scans strings held over many input buffers with a second mission.
+    /// The findings of the other mission wait for them. Up to
+    /// `HELD_STR_LEN_MAX` bytes, a string is judged as a whole, a longer one
+    /// part by part.
+    #[test]
+    fn test_held_str_bounded_and_ordered() {
+        let m0: &'static Mission = &MISSION_GREP_PATTERNS;
+        let m1: &'static Mission = Box::leak(Box::new(Mission {
+            mission_id: 1,
+            ..MISSION_ASCII.clone()
+        }));
+        let mut ss0 = ScannerState::new(m0);
+        let mut ss1 = ScannerState::new(m1);
+
+        // The pattern is in the last input buffer of the first long string.
+        let long = "0123456789abcdefghijklmnopqrstuvwxyz".repeat(13);
+        assert!(long.len() > 10 * INPUT_BUF_LEN && long.len() < HELD_STR_LEN_MAX);
+        let too_long = long.repeat(3);
+        assert!(too_long.len() > 2 * HELD_STR_LEN_MAX);
+        let input = format!("a secret\u{0}{long}secret\u{0}{too_long}secret\u{0}a secret\u{0}");
+
+        let mut pending = PendingFindings::default();
+        let mut printed: Vec<(ByteCounter, usize, String, bool)> = Vec::new();
+        let mut slices = input.as_bytes().chunks(INPUT_BUF_LEN).peekable();
+        while let Some(slice) = slices.next() {
+            let last = slices.peek().is_none();
+            let batch = vec![
+                FindingCollection::from(&mut ss0, None, slice, last),
+                FindingCollection::from(&mut ss1, None, slice, last),
+            ];
+            // Memory is bounded: the strings are ASCII.
+            assert!(
+                ss0.held_str.as_ref().map_or(0, |h| h.s.len())
+                    <= HELD_STR_LEN_MAX + 2 * m0.output_line_char_nb_max
+            );
+            pending.push_batch(batch);
+            assert!(pending.v.len() <= 2 * (HELD_STR_LEN_MAX / INPUT_BUF_LEN + 2));
+            pending
+                .release(|findings| {
+                    for f in findings {
+                        printed.push((
+                            f.position,
+                            f.mission.mission_id,
+                            f.s.to_string(),
+                            f.s_completes_previous_s,
+                        ));
+                    }
+                    Ok::<_, ()>(())
+                })
+                .unwrap();
+        }
+        assert!(pending.v.is_empty());
+
+        // The output is ordered.
+        assert!(printed.windows(2).all(|w| w[0].0 <= w[1].0));
+
+        let mut v: Vec<String> = Vec::new();
+        for (_, _, s, s_completes_previous_s) in printed.iter().filter(|p| p.1 == 0) {
+            match v.last_mut() {
+                Some(last) if *s_completes_previous_s => last.push_str(s),
+                _ => v.push(s.to_string()),
+            }
+        }
+        assert_eq!(v.len(), 4);
+        assert_eq!(v[0], "a secret");
+        // The first long string is printed as a whole.
+        assert_eq!(v[1], format!("{long}secret"));
+        // Of the string too long to be held, the parts before the one with
+        // the pattern are dropped.
+        assert!(v[2].len() < too_long.len());
+        assert!(format!("{too_long}secret").ends_with(&v[2]));
+        assert_eq!(v[3], "a secret");
+
+        // The other mission finds it all.
+        assert!(printed
+            .iter()
+            .filter(|p| p.1 == 1)
+            .any(|p| p.2.contains("secret")));
+    }
+
+    #[test]
+    fn test_regex_invert_long_str() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            invert_match: true,
+            ..MISSION_REGEX.clone()
+        }));
+
+        // Both strings span many input buffers. The one with a date in its
+        // last input buffer is dropped as a whole.
+        let long = "abcdefghijklmnopqrstuvwxyz".repeat(16);
+        assert!(long.len() > 10 * INPUT_BUF_LEN && long.len() < HELD_STR_LEN_MAX);
+        let input = format!("{long} 2024-05\u{0}{long}\u{0}");
+
+        assert_eq!(scan_slices(m, input.as_bytes()), vec![long]);
+    }
+
+    #[test]
+    fn test_regex_long_str() {
+        let m: &'static Mission = &MISSION_REGEX;
+
+        // The second date comes after the first 0x100 bytes of its string: it
+        // is matched in the whole string, decoded from several buffers.
+        let long = "abcdefghijklmnopqrstuvwxyz".repeat(10);
+        assert!(long.len() > 0x100);
+        let input = format!("1999-12 {long}\u{0}{long} 2024-05\u{0}{long}\u{0}a 2000-01\u{0}");
+
+        assert_eq!(
+            scan_slices(m, input.as_bytes()),
+            vec![
+                format!("1999-12 {long}"),
+                format!("{long} 2024-05"),
+                "a 2000-01".to_string()
+            ]
+        );
+
+        // Now the inverse.
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            invert_match: true,
+            ..MISSION_REGEX.clone()
+        }));
+
+        assert_eq!(scan_slices(m, input.as_bytes()), vec![long]);
+    }
+
+    #[test]
+    fn test_max_scripts() {
+        let m: &'static Mission = &MISSION_MAX_SCRIPTS;
+
+        // The Greek string ends exactly at the right boundary of the first
+        // decoder input window. Its Scripts are remembered for the next.
+        let input = "Ωμέγαλ Ωμέ abc\u{0}".as_bytes();
+
+        assert_eq!(scan_slices(m, input), vec!["Ωμέγαλ Ωμέ ", "abc"]);
+    }
+
+    #[test]
+    fn test_regex() {
+        let m: &'static Mission = &MISSION_REGEX;
+
+        let mut ss = ScannerState::new(m);
+
+        // The date in the second string is wrapped and cut by the decoder
+        // window boundary.
+        let input = "no date\u{0}date: 2024-05-01\u{0}1999-12".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 4);
+        assert_eq!(fc.v[0].s, "date: 2024");
+        assert_eq!(fc.v[1].s, "-0");
+        assert!(fc.v[1].s_completes_previous_s);
+        assert_eq!(fc.v[2].s, "5-01");
+        assert!(fc.v[2].s_completes_previous_s);
+        assert_eq!(fc.v[3].s, "1999-12");
+
+        // Now the inverse.
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            invert_match: true,
+            ..MISSION_REGEX.clone()
+        }));
+        let mut ss = ScannerState::new(m);
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "no date");
+    }
+
+    #[test]
+    fn test_min_score() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            min_score: Some(50),
+            ..MISSION_REGEX.clone()
+        }));
+
+        let mut ss = ScannerState::new(m);
+
+        // The noise is rejected, the digits have nothing to rate.
+        let input = "File not found\u{0}ꁥ쐢䳷綨뱕霆\u{0}1234".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 3);
+        assert_eq!(fc.v[0].s, "File not f");
+        assert!(fc.v[0].score.unwrap() >= 50);
+        assert_eq!(fc.v[1].s, "ound");
+        assert_eq!(fc.v[1].score, None);
+        assert_eq!(fc.v[2].s, "1234");
+        assert_eq!(fc.v[2].score, None);
+    }
+
+    #[test]
+    fn test_str_stats_filter() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            str_stats_filter: StrStatsFilter {
+                max_run_len: Some(3),
+                min_distinct_chars: Some(3),
+                ..StrStatsFilter::default()
+            },
+            ..MISSION_REGEX.clone()
+        }));
+
+        let mut ss = ScannerState::new(m);
+
+        let input = "x@@@@@@@y\u{0}ABABABAB\u{0}key: 0x1f".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "key: 0x1f");
+
+        // The run of `@` continues in the next window: the string passes so
+        // far, but is held back until its end is seen.
+        let mut ss = ScannerState::new(m);
+
+        let fc = FindingCollection::from(
+            &mut ss,
+            Some(0),
+            "no run\u{0}abcdefghij@@".as_bytes(),
+            false,
+        );
+
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "no run");
+
+        let fc = FindingCollection::from(&mut ss, Some(100), "@@ end\u{0}".as_bytes(), true);
+
+        assert_eq!(fc.v.len(), 0);
+    }
+
+    #[test]
+    fn test_language() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            output_line_char_nb_max: 60,
+            identify_language: true,
+            languages: Some(vec!["de"]),
+            ..MISSION_REGEX.clone()
+        }));
+
+        let mut ss = ScannerState::new(m);
+
+        let input = "Die Datei wurde nicht gefunden\u{0}File was not found\u{0}Datei".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        // The English string is identified as such and dropped, `Datei` is
+        // too short to be identified and passes.
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[0].s, "Die Datei wurde nicht gefunden");
+        assert_eq!(fc.v[0].language.unwrap().0, "de");
+        assert_eq!(fc.v[1].s, "Datei");
+        assert_eq!(fc.v[1].language, None);
+    }
+
+    #[test]
+    fn test_chars_max() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            chars_max_nb: Some(12),
+            ..MISSION_REGEX.clone()
+        }));
+
+        let mut ss = ScannerState::new(m);
+
+        // The second string is wrapped, but counts as a whole.
+        let input = "way too long string\u{0}just right!\u{0}tiny".as_bytes();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), input, true);
+
+        assert_eq!(fc.v.len(), 3);
+        assert_eq!(fc.v[0].s, "just right");
+        assert_eq!(fc.v[1].s, "!");
+        assert!(fc.v[1].s_completes_previous_s);
+        assert_eq!(fc.v[2].s, "tiny");
+
+        // The string continues in the next window: it is held back until its
+        // length is known.
+        let mut ss = ScannerState::new(m);
+
+        let fc =
+            FindingCollection::from(&mut ss, Some(0), "tiny\u{0}abcdefghijk".as_bytes(), false);
+
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "tiny");
+
+        let fc = FindingCollection::from(&mut ss, Some(100), "lmn\u{0}".as_bytes(), true);
+
+        assert_eq!(fc.v.len(), 0);
+    }
+
+    #[test]
+    fn test_chars_max_long_str() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            chars_max_nb: Some(12),
+            ..MISSION_REGEX.clone()
+        }));
+
+        // The rejected string is longer than `INPUT_BUF_LEN`: none of its
+        // windows or buffers may print its rest.
+        let long = "abcdefghijklmnopqrstuvwxyz".repeat(3);
+        assert!(long.len() > INPUT_BUF_LEN);
+        let input = format!("tiny\u{0}{}\u{0}just right!\u{0}", long);
+
+        assert_eq!(
+            scan_slices(m, input.as_bytes()),
+            vec!["tiny", "just right!"]
+        );
+    }
+
+    #[test]
+    fn test_chars_max_counted_across_buffers() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            regex: None,
+            chars_max_nb: Some(100),
+            ..MISSION_REGEX.clone()
+        }));
+
+        // All strings are longer than 0x100 bytes. The CJK string exceeds
+        // the limit, although its first 0x100 bytes are only 85 characters.
+        let ascii = "abcdefghijklmnopqrstuvwxyz".repeat(12);
+        let cjk_ok = "亜".repeat(90);
+        let cjk_long = "亜".repeat(120);
+        assert!(cjk_ok.len() > 0x100);
+        let input = format!("{ascii}\u{0}{cjk_ok}\u{0}{cjk_long}\u{0}tiny\u{0}");
+
+        assert_eq!(
+            scan_slices(m, input.as_bytes()),
+            vec![cjk_ok, "tiny".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_pre_search() {
+        let m: &'static Mission = with_char_spans(&MISSION_PRE_SEARCH);
+
+        let mut ss = ScannerState::new(m);
+
+        let input: Vec<u8> = "no hit here, no hit here\u{0}the key\u{0}\
+                              no hit here, no hit here\u{0}a key again\u{0}"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        // We cut the input in the middle of the second `key`.
+        let cut = input.len() - 17;
+
+        let fc = FindingCollection::from(&mut ss, Some(0), &input[..cut], false);
+
+        assert_eq!(fc.v.len(), 1);
+        assert_eq!(fc.v[0].s, "the key");
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_050, 14));
+        assert_eq!(ss.pre_search_partial_len, 3);
+
+        let fc = FindingCollection::from(&mut ss, Some(0), &input[cut..], true);
+
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[0].s, "a key agai");
+        assert_eq!((fc.v[0].start, fc.v[0].byte_len), (10_116, 20));
+        assert_eq!(fc.v[1].s, "n");
+        assert!(fc.v[1].s_completes_previous_s);
+        assert_eq!((fc.v[1].start, fc.v[1].byte_len), (10_136, 2));
+    }
+
+    #[test]
+    fn test_pre_search_long_str() {
+        let m: &'static Mission = &MISSION_PRE_SEARCH;
+
+        let mut ss = ScannerState::new(m);
+
+        // The hit is in the middle of a string longer than two decoder
+        // input windows.
+        let input: Vec<u8> = "no hit here, no hit here\u{0}\
+                              abcdefghijklmnop key qrstuvwxyz0123456789\u{0}no hit\u{0}"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+
+        let fc = FindingCollection::from(&mut ss, Some(0), &input, true);
+
+        let mut v: Vec<String> = Vec::new();
+        for f in fc.v.iter() {
+            match v.last_mut() {
+                Some(s) if f.s_completes_previous_s => s.push_str(f.s),
+                _ => v.push(f.s.to_string()),
+            }
+        }
+        assert_eq!(v, vec!["abcdefghijklmnop key qrstuvwxyz0123456789"]);
+    }
+
+    #[test]
+    fn test_pre_search_iso_2022_jp() {
+        let m: &'static Mission = Box::leak(Box::new(Mission {
+            pre_search: Some(PreSearch::new("日本", ISO_2022_JP, None, false).unwrap()),
+            ..MISSION_ISO_2022_JP.clone()
+        }));
+
+        // The keyword is in the middle of a run of JIS X 0208 characters:
+        // only the string's beginning switches to them.
+        let (input, _, _) = ISO_2022_JP.encode("\u{0}東京都港区の会社で日本語\u{0}");
+        assert_eq!(input.len(), INPUT_BUF_LEN);
+
+        let mut ss = ScannerState::new(m);
+        let fc = FindingCollection::from(&mut ss, Some(0), &input, true);
+
+        assert_eq!(fc.v.len(), 2);
+        assert_eq!(fc.v[0].s, "東京都港区の会社で日");
+        assert_eq!(fc.v[1].s, "本語");
+        assert!(fc.v[1].s_completes_previous_s);
+
+        // The run starts in the first input buffer, which has no hit and is
+        // skipped. The decoder still knows the character set in use, when
+        // it continues with the hit in the second buffer. As with all
+        // encodings, the string's part in the skipped buffer is lost.
+        let text = "no hit here, no hit here\u{0}東京都港区の会社で日本語の文書\u{0}no hit\u{0}";
+        let (input, _, _) = ISO_2022_JP.encode(text);
+
+        assert_eq!(scan_slices(m, &input), vec!["都港区の会社で日本語の文書"]);
+    }
 }
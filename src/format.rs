@@ -0,0 +1,691 @@
+//! Machine readable output formats (`--format`).
+//!
+//! All formats share one field model: `FindingFields` holds everything
+//! known about a `Finding`, with the field names used in the output. The
+//! `jsonl` format prints these fields as JSON object, the `csv` and `tsv`
+//! formats print the fields chosen with `--columns` as one row of
+//! delimiter separated values. With `--unique` the field model is
+//! `unique::RecordFields` instead.
+
+use crate::finding::Finding;
+use crate::input::{input_name, ByteCounter};
+use crate::mission::Mission;
+use crate::options::{OutputFormat, ARGS};
+use crate::unique::RECORD_COLUMNS;
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use serde::ser::{self, Impossible, SerializeSeq, SerializeStruct, Serializer};
+use serde::Serialize;
+use serde_derive::Serialize;
+use std::io::{self, Write};
+use std::process;
+
+/// The names of the fields of `FindingFields`, in the order they are
+/// declared. These are the default columns of the `csv` and `tsv` formats.
+pub const FINDING_COLUMNS: &[&str] = &[
+    "file",
+    "offset",
+    "precision",
+    "end_offset",
+    "byte_length",
+    "label",
+    "encoding",
+    "also",
+    "ascii_filter",
+    "unicode_block_filter",
+    "continuation",
+    "pattern",
+    "score",
+    "language",
+    "language_confidence",
+    "text",
+    "repaired",
+];
+
+/// Separates the items of list fields, e.g. `also`, within one column.
+const LIST_SEPARATOR: &str = ";";
+
+lazy_static! {
+    /// Settings of the `csv` and `tsv` formats.
+    pub static ref DELIMITED: Delimited = Delimited::new(
+        ARGS.format,
+        ARGS.delimiter.as_deref(),
+        ARGS.columns.as_deref(),
+        if ARGS.unique.is_some() {
+            RECORD_COLUMNS
+        } else {
+            FINDING_COLUMNS
+        },
+    )
+    .unwrap_or_else(|error| {
+        eprintln!("Error while parsing command-line arguments: {:?}", error);
+        process::exit(1);
+    });
+}
+
+/// The fields of a `Finding` in output formats other than `text`.
+#[derive(Debug, Serialize)]
+pub struct FindingFields<'a> {
+    /// Path of the input file, `-` for `stdin`.
+    pub file: String,
+    /// Position of the first input byte `text` was decoded from, see
+    /// `Finding::start`.
+    pub offset: ByteCounter,
+    /// Always `exact`: unlike `Finding::position` in the `text` format,
+    /// `offset` never points `before` or `after` the string.
+    pub precision: &'static str,
+    /// Position after the last input byte `text` was decoded from.
+    pub end_offset: ByteCounter,
+    /// Number of input bytes `text` was decoded from, see
+    /// `Finding::byte_len`.
+    pub byte_length: usize,
+    pub label: &'a str,
+    pub encoding: &'a str,
+    /// Labels of other missions having found the same string, see
+    /// `--merge`.
+    pub also: Vec<&'a str>,
+    pub ascii_filter: String,
+    pub unicode_block_filter: String,
+    /// `text` continues the string of the previous finding of this mission.
+    pub continuation: bool,
+    pub pattern: Option<&'a str>,
+    pub score: Option<u8>,
+    pub language: Option<&'a str>,
+    pub language_confidence: Option<u8>,
+    pub text: &'a str,
+    /// See `--mojibake`.
+    pub repaired: Option<&'a str>,
+}
+
+impl<'a> FindingFields<'a> {
+    pub fn new(f: &'a Finding, also: &[&'a Mission]) -> Self {
+        FindingFields {
+            file: input_name(f.input_file_id),
+            offset: f.start,
+            precision: "exact",
+            end_offset: f.start + f.byte_len as ByteCounter,
+            byte_length: f.byte_len,
+            label: &f.mission.label,
+            encoding: f.mission.encoding_name(),
+            also: also.iter().map(|m| m.label.as_str()).collect(),
+            ascii_filter: format!("{:#x}", f.mission.filter.af),
+            unicode_block_filter: format!("{:#x}", f.mission.filter.ubf),
+            continuation: f.s_completes_previous_s,
+            pattern: f.matched_pattern,
+            score: f.score,
+            language: f.language.map(|(code, _)| code),
+            language_confidence: f.language.map(|(_, confidence)| confidence),
+            text: f.s,
+            repaired: f.mojibake_repaired.as_deref(),
+        }
+    }
+
+    /// Writes one line of JSON Lines.
+    pub fn write_jsonl(&self, out: &mut dyn Write) -> Result<(), Box<io::Error>> {
+        serde_json::to_writer(&mut *out, self).map_err(io::Error::from)?;
+        out.write_all(b"\n")?;
+        Ok(())
+    }
+}
+
+/// Writes rows of delimiter separated values, quoted as defined in RFC 4180:
+/// a value containing the delimiter, `"`, CR or LF is enclosed in `"` and
+/// its `"` are doubled. Rows end with CR LF.
+#[derive(Debug, PartialEq)]
+pub struct Delimited {
+    delimiter: u8,
+    /// Names of the fields to print, in this order.
+    columns: Vec<&'static str>,
+}
+
+impl Delimited {
+    /// `delimiter` defaults to `,` for `csv` and to tab for `tsv`, `columns`
+    /// is a `,`-separated list of names in `known`, all by default.
+    pub fn new(
+        format: OutputFormat,
+        delimiter: Option<&str>,
+        columns: Option<&str>,
+        known: &[&'static str],
+    ) -> Result<Self> {
+        let delimiter = match delimiter {
+            None if format == OutputFormat::Tsv => b'\t',
+            None => b',',
+            Some("tab") | Some("\\t") => b'\t',
+            Some(d) if d.len() == 1 && d.is_ascii() && !matches!(d, "\"" | "\r" | "\n") => {
+                d.as_bytes()[0]
+            }
+            Some(d) => {
+                return Err(anyhow!(
+                    "delimiter `{}` is not one ASCII character other than `\"`, CR or LF",
+                    d
+                ))
+            }
+        };
+        let columns =
+            match columns {
+                None => known.to_vec(),
+                Some(columns) => columns
+                    .split(',')
+                    .map(|c| {
+                        let c = c.trim();
+                        known.iter().find(|k| **k == c).copied().ok_or_else(|| {
+                            anyhow!("unknown column `{}`, try: {}", c, known.join(","))
+                        })
+                    })
+                    .collect::<Result<_>>()?,
+            };
+        Ok(Delimited { delimiter, columns })
+    }
+
+    /// Writes the header row: the names of the columns.
+    pub fn write_header(&self, out: &mut dyn Write) -> Result<(), Box<io::Error>> {
+        self.write_cells(self.columns.iter().map(|c| c.to_string()), out)
+    }
+
+    /// Writes the `columns` of `fields` as one row.
+    pub fn write_row<T: Serialize>(
+        &self,
+        fields: &T,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<io::Error>> {
+        let mut row = Row {
+            columns: &self.columns,
+            cells: vec![String::new(); self.columns.len()],
+        };
+        fields.serialize(&mut row).map_err(io::Error::from)?;
+        self.write_cells(row.cells.into_iter(), out)
+    }
+
+    fn write_cells(
+        &self,
+        cells: impl Iterator<Item = String>,
+        out: &mut dyn Write,
+    ) -> Result<(), Box<io::Error>> {
+        for (i, cell) in cells.enumerate() {
+            if i > 0 {
+                out.write_all(&[self.delimiter])?;
+            }
+            if cell
+                .bytes()
+                .any(|b| b == self.delimiter || b == b'"' || b == b'\r' || b == b'\n')
+            {
+                out.write_all(b"\"")?;
+                out.write_all(cell.replace('"', "\"\"").as_bytes())?;
+                out.write_all(b"\"")?;
+            } else {
+                out.write_all(cell.as_bytes())?;
+            }
+        }
+        out.write_all(b"\r\n")?;
+        Ok(())
+    }
+}
+
+/// Collects the cells of a row: serializes the fields of a struct named in
+/// `columns` with `Cell`, skipping the others.
+struct Row<'a> {
+    columns: &'a [&'static str],
+    /// The text of every column.
+    cells: Vec<String>,
+}
+
+/// The error for values that do not fit in a row or a cell.
+fn unsupported() -> serde_json::Error {
+    ser::Error::custom("only structs of scalars and lists can be written as row")
+}
+
+/// Implements the `Serializer` methods for the unsupported types.
+macro_rules! serialize_unsupported {
+    ($($method:ident($($arg:ty),*)),* $(,)?) => {
+        $(fn $method(self, $(_: $arg),*) -> Result<Self::Ok, Self::Error> {
+            Err(unsupported())
+        })*
+    };
+}
+
+impl<'a> Serializer for &mut Row<'a> {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = Impossible<(), serde_json::Error>;
+    type SerializeTuple = Impossible<(), serde_json::Error>;
+    type SerializeTupleStruct = Impossible<(), serde_json::Error>;
+    type SerializeTupleVariant = Impossible<(), serde_json::Error>;
+    type SerializeMap = Impossible<(), serde_json::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Impossible<(), serde_json::Error>;
+
+    serialize_unsupported!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_none(),
+        serialize_unit(),
+        serialize_unit_struct(&'static str),
+        serialize_unit_variant(&'static str, u32, &'static str),
+    );
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, serde_json::Error> {
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, serde_json::Error> {
+        Err(unsupported())
+    }
+}
+
+impl<'a> SerializeStruct for &mut Row<'a> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        // A column may be chosen more than once.
+        let mut first = None;
+        for (i, column) in self.columns.iter().enumerate() {
+            if *column != key {
+                continue;
+            }
+            match first {
+                None => {
+                    value.serialize(Cell(&mut self.cells[i]))?;
+                    first = Some(i);
+                }
+                Some(j) => self.cells[i] = self.cells[j].clone(),
+            }
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+}
+
+/// Writes the text of a field in a column: empty for `None`, list items are
+/// joined with `LIST_SEPARATOR`.
+struct Cell<'a>(&'a mut String);
+
+/// Implements the `Serializer` methods for types printed with `Display`.
+macro_rules! serialize_display {
+    ($($method:ident($arg:ty)),* $(,)?) => {
+        $(fn $method(self, v: $arg) -> Result<(), serde_json::Error> {
+            self.0.push_str(&v.to_string());
+            Ok(())
+        })*
+    };
+}
+
+impl<'a> Serializer for Cell<'a> {
+    type Ok = ();
+    type Error = serde_json::Error;
+    type SerializeSeq = ListCell<'a>;
+    type SerializeTuple = Impossible<(), serde_json::Error>;
+    type SerializeTupleStruct = Impossible<(), serde_json::Error>;
+    type SerializeTupleVariant = Impossible<(), serde_json::Error>;
+    type SerializeMap = Impossible<(), serde_json::Error>;
+    type SerializeStruct = Impossible<(), serde_json::Error>;
+    type SerializeStructVariant = Impossible<(), serde_json::Error>;
+
+    serialize_display!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    );
+
+    serialize_unsupported!(serialize_bytes(&[u8]));
+
+    fn serialize_none(self) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<(), serde_json::Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, serde_json::Error> {
+        Ok(ListCell {
+            cell: self.0,
+            is_first: true,
+        })
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, serde_json::Error> {
+        Err(unsupported())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, serde_json::Error> {
+        Err(unsupported())
+    }
+}
+
+/// Writes the items of a list field into one cell.
+struct ListCell<'a> {
+    cell: &'a mut String,
+    is_first: bool,
+}
+
+impl SerializeSeq for ListCell<'_> {
+    type Ok = ();
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        if !self.is_first {
+            self.cell.push_str(LIST_SEPARATOR);
+        }
+        self.is_first = false;
+        value.serialize(Cell(self.cell))
+    }
+
+    fn end(self) -> Result<(), serde_json::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding_collection::FindingCollection;
+    use crate::scanner::tests::with_char_spans;
+    use crate::scanner::tests::MISSION_ALL_UTF8;
+    use crate::scanner::tests::MISSION_ISO_2022_JP;
+    use crate::scanner::ScannerState;
+
+    #[test]
+    fn test_finding_fields() {
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ALL_UTF8));
+        let fc = FindingCollection::from(&mut ss, None, "\u{0}\"é\"\u{0}".as_bytes(), true);
+        assert_eq!(fc.v.len(), 1);
+
+        let fields = FindingFields::new(&fc.v[0], &[]);
+        assert_eq!(fields.file, "-");
+        assert_eq!(fields.text, "\"é\"");
+        // The string starts after the `\u{0}`.
+        assert_eq!(fields.offset, 10_001);
+        assert_eq!(fields.precision, "exact");
+        assert_eq!(fields.byte_length, 4);
+        assert_eq!(
+            fields.end_offset,
+            fields.offset + fields.byte_length as ByteCounter
+        );
+        assert_eq!(fields.label, "a");
+        assert_eq!(fields.encoding, "UTF-8");
+
+        let mut out = Vec::new();
+        fields.write_jsonl(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with("}\n"));
+        assert!(out.contains(r#""text":"\"é\"""#));
+        assert!(out.contains(r#""pattern":null"#));
+
+        // The length is what was read, not what re-encoding would give:
+        // that would end with the escape sequence switching back to ASCII.
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ISO_2022_JP));
+        let input = b"abc\x1b$B\x30\x21\x1b(B\0";
+        let fc = FindingCollection::from(&mut ss, None, input, true);
+        assert_eq!(fc.v.len(), 1);
+        let fields = FindingFields::new(&fc.v[0], &[]);
+        assert_eq!(fields.text, "abc亜");
+        assert_eq!(fields.byte_length, 8);
+        assert_eq!(
+            fields.end_offset,
+            fields.offset + fields.byte_length as ByteCounter
+        );
+
+        // Strings not starting at the decoder window start: their offset is
+        // still exact.
+        let mut ss = ScannerState::new(with_char_spans(&MISSION_ALL_UTF8));
+        let fc = FindingCollection::from(&mut ss, None, b"\0\0\0hello world\0", true);
+        let fields: Vec<_> = fc.v.iter().map(|f| FindingFields::new(f, &[])).collect();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(
+            (
+                fields[0].offset,
+                fields[0].end_offset,
+                fields[0].byte_length
+            ),
+            (10_003, 10_013, 10)
+        );
+        assert_eq!(
+            (
+                fields[1].offset,
+                fields[1].end_offset,
+                fields[1].byte_length
+            ),
+            (10_013, 10_014, 1)
+        );
+    }
+
+    #[test]
+    fn test_finding_columns() {
+        let mut ss = ScannerState::new(&MISSION_ALL_UTF8);
+        let fc = FindingCollection::from(&mut ss, None, b"\0abc\0", true);
+        let value = serde_json::to_value(FindingFields::new(&fc.v[0], &[])).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().collect();
+        let mut columns = FINDING_COLUMNS.to_vec();
+        keys.sort();
+        columns.sort();
+        assert_eq!(keys, columns);
+    }
+
+    #[test]
+    fn test_delimited() {
+        let known = &["a", "b", "c"];
+        let d = Delimited::new(OutputFormat::Csv, None, None, known).unwrap();
+        assert_eq!(d.delimiter, b',');
+        assert_eq!(d.columns, vec!["a", "b", "c"]);
+        let d = Delimited::new(OutputFormat::Tsv, None, Some("c, a"), known).unwrap();
+        assert_eq!(d.delimiter, b'\t');
+        assert_eq!(d.columns, vec!["c", "a"]);
+        let d = Delimited::new(OutputFormat::Csv, Some("tab"), None, known).unwrap();
+        assert_eq!(d.delimiter, b'\t');
+        assert!(Delimited::new(OutputFormat::Csv, Some("\""), None, known).is_err());
+        assert!(Delimited::new(OutputFormat::Csv, Some(",,"), None, known).is_err());
+        assert!(Delimited::new(OutputFormat::Csv, None, Some("a,d"), known).is_err());
+
+        #[derive(Serialize)]
+        struct Fields<'a> {
+            a: &'a str,
+            b: Option<u8>,
+            c: Vec<&'a str>,
+        }
+        let d = Delimited::new(OutputFormat::Csv, Some(";"), None, known).unwrap();
+        let mut out = Vec::new();
+        d.write_header(&mut out).unwrap();
+        d.write_row(
+            &Fields {
+                a: "say \"hi\"",
+                b: None,
+                c: vec!["x", "y"],
+            },
+            &mut out,
+        )
+        .unwrap();
+        d.write_row(
+            &Fields {
+                a: "two\nlines",
+                b: Some(7),
+                c: vec![],
+            },
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "a;b;c\r\n\"say \"\"hi\"\"\";;\"x;y\"\r\n\"two\nlines\";7;\r\n"
+        );
+
+        // Only the chosen columns, in their order, maybe more than once.
+        let d = Delimited::new(OutputFormat::Csv, None, Some("c,b,c"), known).unwrap();
+        let mut out = Vec::new();
+        d.write_row(
+            &Fields {
+                a: "not printed",
+                b: Some(7),
+                c: vec!["x", "y"],
+            },
+            &mut out,
+        )
+        .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "x;y,7,x;y\r\n");
+    }
+}
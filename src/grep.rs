@@ -0,0 +1,177 @@
+//! Search for keywords in decoded findings.
+
+use aho_corasick::{AhoCorasick, MatchKind};
+use anyhow::{anyhow, Context, Result};
+use std::borrow::Cow;
+use std::fmt;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Separates the patterns in a pattern list, e.g. `password|admin`.
+pub const GREP_PATTERN_SEPARATOR: char = '|';
+
+/// Defines which variants of a pattern match. Both, the patterns and the
+/// findings, are transformed with `fold()` before being compared.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub struct MatchMode {
+    /// Compare with full Unicode case folding, e.g. `STRASSE` matches
+    /// `Straße`.
+    pub ignore_case: bool,
+    /// Compare under compatibility normalization (NFKC), e.g. `ＡＢＣ`
+    /// matches `ABC`.
+    pub nfkc: bool,
+    /// Ignore combining marks after decomposition, e.g. `Jose` matches
+    /// `José`.
+    pub ignore_diacritics: bool,
+}
+
+impl MatchMode {
+    /// True when strings are compared as they are.
+    #[inline]
+    pub fn is_exact(&self) -> bool {
+        !self.ignore_case && !self.nfkc && !self.ignore_diacritics
+    }
+
+    /// Transforms `s`, so that all variants of a string considered equal
+    /// under this mode are transformed to the same string.
+    pub fn fold<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        if self.is_exact() {
+            return Cow::Borrowed(s);
+        }
+        let mut t: String = if self.nfkc {
+            s.nfkd().collect()
+        } else if self.ignore_diacritics {
+            s.nfd().collect()
+        } else {
+            s.to_string()
+        };
+        if self.ignore_case {
+            t = caseless::default_case_fold_str(&t);
+        }
+        if self.ignore_diacritics {
+            // Case folding may have produced new decomposable characters.
+            t = t.nfd().filter(|c| !is_combining_mark(*c)).collect();
+        }
+        if self.nfkc {
+            t = t.nfkc().collect();
+        }
+        Cow::Owned(t)
+    }
+}
+
+/// A set of patterns searched simultaneously in decoded findings. As all
+/// findings are UTF-8, one pattern list covers all encodings.
+#[derive(Clone)]
+pub struct GrepPatterns {
+    /// The patterns as given by the user.
+    patterns: Vec<String>,
+    /// How the patterns are compared.
+    mode: MatchMode,
+    /// The automaton searching all `patterns` - transformed by `mode` - in
+    /// one pass.
+    ac: AhoCorasick,
+}
+
+impl GrepPatterns {
+    /// Constructor. Fails, when `patterns` is empty or contains an empty
+    /// pattern.
+    pub fn new(patterns: Vec<String>, mode: MatchMode) -> Result<Self> {
+        if patterns.is_empty() || patterns.iter().any(|p| mode.fold(p).is_empty()) {
+            return Err(anyhow!("empty grep pattern in `{:?}`", patterns));
+        }
+        let ac = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostFirst)
+            .build(patterns.iter().map(|p| mode.fold(p).into_owned()))
+            .with_context(|| format!("can not compile grep patterns `{:?}`", patterns))?;
+        Ok(Self { patterns, mode, ac })
+    }
+
+    /// Splits the pattern list `s` at `GREP_PATTERN_SEPARATOR`.
+    pub fn split_pattern_list(s: &str) -> Vec<String> {
+        s.split(GREP_PATTERN_SEPARATOR).map(String::from).collect()
+    }
+
+    /// Returns the pattern of the leftmost match in `s`. If two patterns
+    /// match at the same position, the one given first wins.
+    #[inline]
+    pub fn find(&self, s: &str) -> Option<&str> {
+        self.ac
+            .find(&*self.mode.fold(s))
+            .map(|m| self.patterns[m.pattern().as_usize()].as_str())
+    }
+}
+
+impl fmt::Debug for GrepPatterns {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GrepPatterns {:?} {:?}", self.patterns, self.mode)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let gp = GrepPatterns::new(
+            GrepPatterns::split_pattern_list("password|пароль|pass"),
+            MatchMode::default(),
+        )
+        .unwrap();
+        assert_eq!(gp.find("my password is"), Some("password"));
+        assert_eq!(gp.find("мой пароль"), Some("пароль"));
+        assert_eq!(gp.find("passport"), Some("pass"));
+        assert_eq!(gp.find("Password"), None);
+
+        assert!(GrepPatterns::new(vec![], MatchMode::default()).is_err());
+        assert!(GrepPatterns::new(
+            GrepPatterns::split_pattern_list("a||b"),
+            MatchMode::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_find_match_mode() {
+        let patterns = GrepPatterns::split_pattern_list("straße|ABC|Jose|пароль");
+
+        let mode = MatchMode {
+            ignore_case: true,
+            ..MatchMode::default()
+        };
+        let gp = GrepPatterns::new(patterns.clone(), mode).unwrap();
+        assert_eq!(gp.find("IN DER STRASSE"), Some("straße"));
+        assert_eq!(gp.find("abc"), Some("ABC"));
+        assert_eq!(gp.find("ПАРОЛЬ"), Some("пароль"));
+        assert_eq!(gp.find("ＡＢＣ"), None);
+        assert_eq!(gp.find("José"), None);
+
+        let mode = MatchMode {
+            nfkc: true,
+            ..MatchMode::default()
+        };
+        let gp = GrepPatterns::new(patterns.clone(), mode).unwrap();
+        assert_eq!(gp.find("ＡＢＣ"), Some("ABC"));
+        assert_eq!(gp.find("abc"), None);
+
+        let mode = MatchMode {
+            ignore_diacritics: true,
+            ..MatchMode::default()
+        };
+        let gp = GrepPatterns::new(patterns.clone(), mode).unwrap();
+        assert_eq!(gp.find("José"), Some("Jose"));
+        // Decomposed form.
+        assert_eq!(gp.find("Jose\u{301}"), Some("Jose"));
+        assert_eq!(gp.find("JOSÉ"), None);
+
+        let mode = MatchMode {
+            ignore_case: true,
+            nfkc: true,
+            ignore_diacritics: true,
+        };
+        let gp = GrepPatterns::new(patterns, mode).unwrap();
+        assert_eq!(gp.find("JOSÉ"), Some("Jose"));
+        assert_eq!(gp.find("ａｂｃ"), Some("ABC"));
+        assert_eq!(gp.find("Strasse"), Some("straße"));
+    }
+}
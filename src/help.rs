@@ -1,10 +1,15 @@
 //! Help the user with command-line-arguments.
 
+use crate::auto_encoding::AUTO_ENCODINGS;
+use crate::config::FILTER_ALIASES;
+use crate::language;
 use crate::mission::ASCII_FILTER_ALIASSE;
 use crate::mission::UNICODE_BLOCK_FILTER_ALIASSE;
 use crate::mission::{Missions, MISSIONS};
 use crate::options::ARGS;
 use crate::options::ASCII_ENC_LABEL;
+use crate::options::CHAR_MAP_ENC_PREFIX;
+use crate::options::DOUBLE_UTF8_ENC_LABEL;
 use crate::AUTHOR;
 use crate::VERSION;
 use std::process;
@@ -27,19 +32,30 @@ pub fn help() {
         }
 
         println!("\nEncoding and filter definitions\n-------------------------------");
-        for (n, name) in ARGS.encoding.iter().enumerate() {
-            println!("{} = {}", char::from((n + 97) as u8), name);
+        // The first mission definitions stem from `--encoding`.
+        for (def_id, name) in ARGS.encoding.iter().enumerate() {
+            match MISSIONS.v.iter().find(|m| m.def_id == def_id) {
+                Some(m) => println!("{} = {}", m.label, name),
+                None => println!("- = {} (skipped)", name),
+            }
+        }
+        for name in ARGS.profile.iter() {
+            println!("+ profile {}", name);
+        }
+        if ARGS.auto {
+            for (name, len) in AUTO_ENCODINGS.v.iter() {
+                println!(
+                    "+ auto {} ({} of {} sampled bytes are text)",
+                    name, len, AUTO_ENCODINGS.sample_len
+                );
+            }
         }
 
         println!("\n\nPARSED COMMANDLINE-ARGUMENTS\n");
 
         let ms: &'static Missions = &MISSIONS;
-        for (i, m) in ms.v.iter().enumerate() {
-            println!(
-                "Scanner ({})\n-----------\n{:#?}\n",
-                char::from((i + 97) as u8),
-                m
-            );
+        for m in ms.v.iter() {
+            println!("Scanner ({})\n-----------\n{:#?}\n", m.label, m);
         }
         process::exit(0);
     };
@@ -49,11 +65,14 @@ pub fn help() {
         // This list is taken from the `Encoding` source file (2019-12-11)
         // and may  not be up to date.
         println!("LIST OF AVAILABLE ENCODINGS AND PREDEFINED FILTERS\n");
-        println!("Format: --encoding=[ENC_NAME],[MIN],[AF,UBF],[GREP]\n\n");
+        println!(
+            "Format: --encoding=[ENC_NAME],[MIN],[AF,UBF],[GREP],[PATTERNS],[KEY=VALUE],...\n\n"
+        );
         println!("ENC_NAME (Encoding)=");
-        let list: [&'static str; 41] = [
+        let list: [&'static str; 42] = [
             ASCII_ENC_LABEL,
             "Big5",
+            DOUBLE_UTF8_ENC_LABEL,
             "EUC-JP",
             "EUC-KR",
             "GBK",
@@ -99,6 +118,10 @@ pub fn help() {
         for e in list.iter() {
             println!("\t{}", e);
         }
+        println!(
+            "\t{}<path> (custom single-byte encoding defined in a mapping file)",
+            CHAR_MAP_ENC_PREFIX
+        );
         println!("\tWarning: this list may be outdated.");
         println!(
             "\tPlease consult the library `encoding_rs` documentation \
@@ -118,6 +141,10 @@ pub fn help() {
                 str::from_utf8(c).unwrap().trim()
             );
         }
+        for (e, b, c) in &FILTER_ALIASES.ascii {
+            let b = format!("{:#x}", b);
+            println!("\t{:<12} = {:>35} (user-defined: {})", e, b, c);
+        }
         println!(
             "\tUse predefined filter names above or your own filter starting with `0x...`.\n\n"
         );
@@ -132,14 +159,40 @@ pub fn help() {
                 str::from_utf8(c).unwrap().trim()
             );
         }
-        println!(
-            "\tUse predefined filter names above or your own filter starting with `0x...`.\n\n"
-        );
+        for (e, b, c) in &FILTER_ALIASES.unicode_block {
+            let b = format!("{:#x}", b);
+            println!("\t{:<12} = {:>18} (user-defined: {})", e, b, c);
+        }
+        println!("\tUse predefined filter names above or your own filter starting with `0x...`.");
+        println!("\tWith `--unicode-block-filter` also a character-range expression,");
+        println!("\te.g. `U+0400-U+04FF,U+0020-U+007E,!U+2028`, see man-page.\n\n");
 
-        println!("GREP = <ASCII code>");
-        println!("\tPrint only lines having at least one character with <ASCII-code>.");
-        println!("\tUseful values are `47` (/) or `92` (\\) for path search.");
-        println!("\t<ASCII code> can be decimal or hexadecimal and must be < 128.");
+        println!("GREP = <CHAR>");
+        println!("\tPrint only lines having at least one character <CHAR>.");
+        println!("\tUseful values are `/` or `\\` for path search.");
+        println!("\t<CHAR> is the character itself, `U+XXXX` or a decimal or");
+        println!("\thexadecimal code, e.g. `€`, `U+20AC`, `8364` or `0x20ac`.\n\n");
+
+        println!("PATTERNS = <PATTERN>|<PATTERN>|...");
+        println!("\tPrint only strings containing at least one of the patterns.");
+        println!("\tThe patterns are searched in the decoded strings, `password|пароль`");
+        println!("\tmatches in all encodings. PATTERNS must not contain `,`.\n\n");
+
+        println!("KEY=VALUE");
+        println!("\tAfter the items above, or instead of them, items of the form");
+        println!("\t`KEY=VALUE` set the following for one encoding only:");
+        println!("\t  min=<MIN>  af=<AF>  ubf=<UBF>  grep=<GREP>  patterns=<PATTERNS>");
+        println!("\t  offset=<NUM>      as `--counter-offset`");
+        println!("\t  same-block=yes|no as `--same-unicode-block`");
+        println!("\t  len=<NUM>         as `--output-line-len`");
+        println!("\t  label=<NAME>      name of the scanner in the output");
+        println!("\te.g. `--encoding=utf-16le,min=8,same-block=yes,len=40`.\n\n");
+
+        println!("LANGUAGES (`--language`) = <ISO 639 code>,<ISO 639 code>,...");
+        let codes: Vec<&str> = language::language_codes().collect();
+        for line in codes.chunks(16) {
+            println!("\t{}", line.join(" "));
+        }
 
         process::exit(0);
     }
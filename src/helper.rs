@@ -1,14 +1,21 @@
 //! Small functions of general use, mainly used in module `scanner`.
 
+#[cfg(test)]
+use crate::char_ranges::CharRanges;
 use crate::mission::Utf8Filter;
 #[cfg(test)]
 use crate::mission::AF_ALL;
 #[cfg(test)]
+use crate::mission::UBF_ALL;
+#[cfg(test)]
 use crate::mission::UBF_GREEK;
 #[cfg(test)]
 use crate::mission::UBF_LATIN;
 #[cfg(test)]
 use crate::mission::UBF_NONE;
+#[cfg(test)]
+use crate::script_filter::ScriptFilter;
+use crate::script_filter::ScriptSet;
 use std::slice;
 use std::str;
 
@@ -94,6 +101,17 @@ pub struct SplitStr<'a> {
     /// same leading byte.
     require_same_unicode_block: bool,
 
+    /// If set, an additional filter criteria is imposed:
+    /// A finding can only have characters of at most `max_scripts` different
+    /// Unicode Scripts. ASCII letters are `Latin`, `Common` and `Inherited`
+    /// characters are not counted.
+    max_scripts: Option<u8>,
+
+    /// The Scripts of the string in progress, when `max_scripts` is set. The
+    /// set is kept when a long string is wrapped. To continue a string of the
+    /// previous run, the caller restores the set here after `new()`.
+    pub scripts: ScriptSet,
+
     /// The caller informs the iterator, that the last string of the previous run
     /// was maybe cut. When the first substring of this run touches the left
     /// boundary of `inp`, we will tag it `s_completes_previous_s` when
@@ -168,10 +186,12 @@ pub struct SplitStrResult<'a> {
 }
 impl<'a> SplitStr<'a> {
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         inp: &str,
         chars_min_nb: u8,
         require_same_unicode_block: bool,
+        max_scripts: Option<u8>,
         last_s_was_maybe_cut: bool,
         invalid_bytes_after_inp: bool,
         utf8f: Utf8Filter,
@@ -189,6 +209,8 @@ impl<'a> SplitStr<'a> {
                 p: inp.as_ptr(),
                 chars_min_nb,
                 require_same_unicode_block,
+                max_scripts,
+                scripts: ScriptSet::default(),
                 last_s_was_maybe_cut,
                 invalid_bytes_after_inp,
                 // We will set this to false later, if `utf8f.grep_char` requires some
@@ -249,7 +271,7 @@ impl<'a> Iterator for SplitStr<'a> {
                         // We can safely `unwrap()` here, because `grep_char_ok`
                         // can only be `false` when `self.utf8f.grep_char` is
                         // `Some()`.
-                        if !grep_char_ok && self.utf8f.grep_char.unwrap() == c {
+                        if !grep_char_ok && self.utf8f.grep_char.unwrap() == c as char {
                             grep_char_ok = true;
                         };
                         // This check is done here for performance reasons. As
@@ -273,20 +295,45 @@ impl<'a> Iterator for SplitStr<'a> {
             // the filter to `leading_byte`.
 
             let (char_is_ok, goto_next_char) = if char_len == 1 {
-                (self.utf8f.pass_af_filter(leading_byte), true)
+                if !self.utf8f.pass_af_filter(leading_byte) {
+                    (false, true)
+                } else if self.max_scripts.map_or(true, |n| {
+                    // Only letters have a Script other than `Common`.
+                    !leading_byte.is_ascii_alphabetic()
+                        || self.scripts.insert(leading_byte as char, n)
+                }) {
+                    (true, true)
+                } else {
+                    // One Script too many: this char will be scanned again.
+                    (false, false)
+                }
             } else {
                 // char_len > 1
-                if self.utf8f.pass_ubf_filter(leading_byte) {
-                    #[allow(clippy::branches_sharing_code)]
-                    if !self.require_same_unicode_block
+                if self.utf8f.pass_ubf_filter(leading_byte)
+                    && (!self.utf8f.needs_char(leading_byte) || {
+                        let c = unsafe {
+                            str::from_utf8_unchecked(slice::from_raw_parts(self.p, char_len))
+                        };
+                        // `c` holds exactly one character.
+                        self.utf8f.pass_char_filter(c.chars().next().unwrap())
+                    })
+                {
+                    let char_continues_s = (!self.require_same_unicode_block
                         || leading_byte == last_multi_char_leading_byte
-                        || last_multi_char_leading_byte == 0
-                    {
-                        last_multi_char_leading_byte = leading_byte;
+                        || last_multi_char_leading_byte == 0)
+                        && self.max_scripts.map_or(true, |n| {
+                            let c = unsafe {
+                                str::from_utf8_unchecked(slice::from_raw_parts(self.p, char_len))
+                            };
+                            // `c` holds exactly one character.
+                            self.scripts.insert(c.chars().next().unwrap(), n)
+                        });
+                    last_multi_char_leading_byte = leading_byte;
+                    if char_continues_s {
                         (true, true)
                     } else {
-                        // char is ok, but has different leading byte
-                        last_multi_char_leading_byte = leading_byte;
+                        // char is ok, but has different leading byte or
+                        // one Script too many.
                         // second false means: this char will be scanned again.
                         (false, false)
                     }
@@ -298,6 +345,14 @@ impl<'a> Iterator for SplitStr<'a> {
             };
 
             if char_is_ok {
+                // Non-ASCII `grep_char`s are checked here, only when they
+                // are part of the string.
+                if !grep_char_ok && char_len > 1 {
+                    let c = unsafe {
+                        str::from_utf8_unchecked(slice::from_raw_parts(self.p, char_len))
+                    };
+                    grep_char_ok = c.starts_with(self.utf8f.grep_char.unwrap());
+                }
                 // This char is good. We keep on going.
                 ok_s_len += char_len;
                 ok_char_nb += 1;
@@ -305,6 +360,7 @@ impl<'a> Iterator for SplitStr<'a> {
                 self.p = unsafe { self.p.add(char_len) };
             } else {
                 // This char did not please the filter.
+                self.scripts.clear();
 
                 // We set the pointer to the next char.
                 if goto_next_char {
@@ -327,6 +383,10 @@ impl<'a> Iterator for SplitStr<'a> {
                 ok_s_len = 0;
                 ok_char_nb = 0;
                 ok_s_p = self.p;
+                // The previous string ended here. When this char is scanned
+                // again, e.g. after a wrapped line, the next string must not
+                // complete it.
+                self.last_s_was_maybe_cut = false;
                 grep_char_ok = self.utf8f.grep_char.is_none();
             }
         }
@@ -482,11 +542,13 @@ mod tests {
             af: AF_ALL,
             ubf: UBF_LATIN,
             grep_char: None,
+            script_filter: None,
+            char_ranges: None,
         };
 
         let b = "€abc€defg€hijk€lm€opq";
 
-        let mut iter = SplitStr::new(b, 3, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "abc");
         assert!(!r.s_completes_previous_s);
@@ -500,7 +562,7 @@ mod tests {
 
         let b = "ab€€defg€hijk€lm€opq";
 
-        let mut iter = SplitStr::new(b, 3, false, true, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, true, false, utf8f, b.len());
         // Corner case: input=true + first string too short, but touches left boundary
         // -> Printed although too short, because it completes string from last run.
         let r = iter.next().unwrap();
@@ -521,7 +583,7 @@ mod tests {
 
         let b = "ab€€defg€hijk€lm€op";
 
-        let mut iter = SplitStr::new(b, 3, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "defg");
         assert!(!r.s_completes_previous_s);
@@ -536,7 +598,7 @@ mod tests {
 
         let b = "€abc€defg€hijk€lm";
 
-        let mut iter = SplitStr::new(b, 4, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "defg");
         let r = iter.next().unwrap();
@@ -551,7 +613,7 @@ mod tests {
 
         let b = "€abc€defg€hijk€lmno€";
 
-        let mut iter = SplitStr::new(b, 4, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "defg");
         let r = iter.next().unwrap();
@@ -567,7 +629,7 @@ mod tests {
         // > 7 bytes
         let b = "abc€defghiÜjklmnpqrs€";
 
-        let mut iter = SplitStr::new(b, 4, false, false, false, utf8f, 7);
+        let mut iter = SplitStr::new(b, 4, false, None, false, false, utf8f, 7);
         let r = iter.next().unwrap();
         // Note, this is longer than 7 bytes.
         assert_eq!(r.s, "defghiÜ");
@@ -594,7 +656,7 @@ mod tests {
 
         let b = "abcdefghijklm";
 
-        let mut iter = SplitStr::new(b, 4, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "abcdefghijklm");
         assert!(!r.s_completes_previous_s);
@@ -605,7 +667,7 @@ mod tests {
 
         let b = "abcdefghijklm€";
 
-        let mut iter = SplitStr::new(b, 4, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "abcdefghijklm");
         assert!(!r.s_completes_previous_s);
@@ -616,7 +678,7 @@ mod tests {
 
         let b = "öö€€ääää€üü€éééé€";
 
-        let mut iter = SplitStr::new(b, 4, false, true, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, true, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "öö");
         let r = iter.next().unwrap();
@@ -632,11 +694,13 @@ mod tests {
             af: AF_ALL,
             ubf: UBF_NONE,
             grep_char: None,
+            script_filter: None,
+            char_ranges: None,
         };
 
         let b = "öö€€ääää€üü€éééé€";
 
-        let mut iter = SplitStr::new(b, 4, false, true, false, utf8f_ascii, b.len());
+        let mut iter = SplitStr::new(b, 4, false, None, true, false, utf8f_ascii, b.len());
         assert_eq!(iter.next(), None);
     }
 
@@ -647,12 +711,14 @@ mod tests {
             af: AF_ALL,
             ubf: UBF_LATIN | UBF_GREEK,
             grep_char: None,
+            script_filter: None,
+            char_ranges: None,
         };
 
         // Additional filter is off.
         let b = "0α1βγöäü€α2βγöäüöαβγαg34αäβüäöüαβγöäü";
 
-        let mut iter = SplitStr::new(b, 3, false, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "0α1βγöäü");
         let r = iter.next().unwrap();
@@ -662,7 +728,7 @@ mod tests {
         // Additional filter is on.
         let b = "0α1βγöäü€α2βγöäüöαβγαg34αäβüäöü";
 
-        let mut iter = SplitStr::new(b, 4, true, false, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 4, true, None, false, false, utf8f, b.len());
         let r = iter.next().unwrap();
         assert_eq!(r.s, "0α1βγ");
         let r = iter.next().unwrap();
@@ -676,6 +742,64 @@ mod tests {
         assert_eq!(iter.next(), None);
     }
 
+    #[test]
+    fn test_split_s_char_ranges() {
+        let cr = CharRanges::parse("U+0400-U+04FF,!U+0401,!U+2028").unwrap();
+        let utf8f = Utf8Filter {
+            af: AF_ALL & !1,
+            ubf: cr.ubf,
+            grep_char: None,
+            script_filter: None,
+            char_ranges: Some(Box::leak(Box::new(cr))),
+        };
+        let b = "ЁжикΩАБВ\u{2028}где";
+
+        let mut iter = SplitStr::new(b, 3, false, None, false, true, utf8f, b.len());
+        assert_eq!(iter.next().unwrap().s, "жик");
+        assert_eq!(iter.next().unwrap().s, "АБВ");
+        assert_eq!(iter.next().unwrap().s, "где");
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_split_s_max_scripts() {
+        let utf8f = Utf8Filter {
+            af: AF_ALL & !1,
+            ubf: UBF_ALL,
+            grep_char: None,
+            script_filter: None,
+            char_ranges: None,
+        };
+        // Punctuation from other blocks, digits and ASCII do not break
+        // the Cyrillic run.
+        let b = "Привет, «мир» 2024! Ωμέγαλ café дом";
+
+        let mut iter = SplitStr::new(b, 3, false, Some(1), false, true, utf8f, b.len());
+        assert_eq!(iter.next().unwrap().s, "Привет, «мир» 2024! ");
+        // ASCII letters are Latin.
+        assert_eq!(iter.next().unwrap().s, "Ωμέγαλ ");
+        assert_eq!(iter.next().unwrap().s, "café ");
+        assert_eq!(iter.next().unwrap().s, "дом");
+        assert_eq!(iter.next(), None);
+
+        let mut iter = SplitStr::new(b, 3, false, Some(2), false, true, utf8f, b.len());
+        assert_eq!(iter.next().unwrap().s, "Привет, «мир» 2024! Ωμέγαλ ");
+        assert_eq!(iter.next().unwrap().s, "café дом");
+        assert_eq!(iter.next(), None);
+
+        // The Scripts are counted over the whole string, also when it is
+        // wrapped.
+        let b = "Ωμέγαbcdef";
+        let mut iter = SplitStr::new(b, 3, false, Some(1), false, true, utf8f, 5);
+        let r = iter.next().unwrap();
+        assert_eq!(r.s, "Ωμέγα");
+        assert!(r.s_is_maybe_cut);
+        let r = iter.next().unwrap();
+        assert_eq!(r.s, "bcdef");
+        assert!(!r.s_completes_previous_s);
+        assert_eq!(iter.next(), None);
+    }
+
     #[test]
     fn test_split_s_grep_char() {
         // We filter Latin + ASCII.
@@ -683,11 +807,13 @@ mod tests {
             af: AF_ALL,
             ubf: UBF_LATIN,
             grep_char: None,
+            script_filter: None,
+            char_ranges: None,
         };
 
         let b = "ac€€xefg€xijk€xm€xp";
 
-        let mut iter = SplitStr::new(b, 3, false, true, false, utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, true, false, utf8f, b.len());
         // Corner case: input=true + first string too short, but touches left boundary
         // -> Printed although too short, because it completes string from last run.
         let r = iter.next().unwrap();
@@ -712,10 +838,12 @@ mod tests {
         let my_utf8f = Utf8Filter {
             af: AF_ALL,
             ubf: UBF_LATIN,
-            grep_char: Some(b'b'),
+            grep_char: Some('b'),
+            script_filter: None,
+            char_ranges: None,
         };
 
-        let mut iter = SplitStr::new(b, 2, false, true, false, my_utf8f, 3);
+        let mut iter = SplitStr::new(b, 2, false, None, true, false, my_utf8f, 3);
         // Corner case: input=true + first string too short, but touches left boundary
         // -> Printed although too short, because it completes string from last run.
         // Only this have the compulsory "b".
@@ -732,10 +860,12 @@ mod tests {
         let my_utf8f = Utf8Filter {
             af: AF_ALL,
             ubf: UBF_LATIN,
-            grep_char: Some(b'x'),
+            grep_char: Some('x'),
+            script_filter: None,
+            char_ranges: None,
         };
 
-        let mut iter = SplitStr::new(b, 2, false, true, false, my_utf8f, 3);
+        let mut iter = SplitStr::new(b, 2, false, None, true, false, my_utf8f, 3);
         // Corner case: input=true + first string too short, but touches left boundary
         // -> Printed although too short, because it completes string from last run.
         // The first passes, because we told there should be no
@@ -792,10 +922,12 @@ mod tests {
         let my_utf8f = Utf8Filter {
             af: AF_ALL,
             ubf: UBF_LATIN,
-            grep_char: Some(b'y'),
+            grep_char: Some('y'),
+            script_filter: None,
+            char_ranges: None,
         };
 
-        let mut iter = SplitStr::new(b, 3, false, false, false, my_utf8f, b.len());
+        let mut iter = SplitStr::new(b, 3, false, None, false, false, my_utf8f, b.len());
         // Corner case: input=false + first string too short, but touches left boundary
         // -> Not printed, because it does not complete the string from last run.
         // No others have the compulsory "y", so they are not printed, except the last,
@@ -806,6 +938,44 @@ mod tests {
         assert!(r.s_is_to_be_filtered_again);
         assert!(r.s_is_maybe_cut);
         assert_eq!(iter.next(), None);
+
+        // Next test, non-ASCII `grep_char`.
+
+        let b = "öäü€€aöü€x\u{0}aaa";
+
+        let my_utf8f = Utf8Filter {
+            af: AF_ALL & !1,
+            ubf: UBF_ALL,
+            grep_char: Some('€'),
+            script_filter: None,
+            char_ranges: None,
+        };
+
+        let mut iter = SplitStr::new(b, 3, false, None, false, true, my_utf8f, b.len());
+        let r = iter.next().unwrap();
+        assert_eq!(r.s, "öäü€€aöü€x");
+        assert!(r.s_satisfies_grep_char_rule);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_split_s_script_filter() {
+        // Hangul and Katakana pass the `ubf`, but not the script filter.
+        let b = "漢字한국어ひらがなカタカナ 𠀀𠀁";
+        let utf8f = Utf8Filter {
+            af: AF_ALL & !1,
+            ubf: UBF_ALL,
+            grep_char: None,
+            script_filter: Some(ScriptFilter::parse("Han,Hiragana").unwrap()),
+            char_ranges: None,
+        };
+
+        let mut iter = SplitStr::new(b, 2, false, None, false, true, utf8f, b.len());
+        assert_eq!(iter.next().unwrap().s, "漢字");
+        assert_eq!(iter.next().unwrap().s, "ひらがな");
+        // ASCII is filtered by `af` only.
+        assert_eq!(iter.next().unwrap().s, " 𠀀𠀁");
+        assert_eq!(iter.next(), None);
     }
 
     #[test]
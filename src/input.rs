@@ -1,7 +1,9 @@
 //! Cut the input stream in chunks for batch processing.
 
 use crate::as_mut_slice_no_borrow_check;
+use crate::auto_encoding::STDIN_SAMPLE;
 use crate::options::ARGS;
+use lazy_static::lazy_static;
 use std::fs::File;
 use std::io;
 use std::io::Read;
@@ -10,6 +12,7 @@ use std::path::Path;
 use std::path::PathBuf;
 use std::slice;
 use std::slice::Iter;
+use std::sync::Mutex;
 
 /// This is the type used to count bytes in the input stream. Maybe in a future
 /// version we raise this to `u128`.
@@ -24,6 +27,34 @@ pub const INPUT_BUF_LEN: usize = 4096;
 #[cfg(test)]
 pub const INPUT_BUF_LEN: usize = 0x20;
 
+/// A part of the input that could not be read: the rest of an input file
+/// starting at `offset`.
+#[derive(Debug, Clone)]
+pub struct UnreadableRegion {
+    /// `None` for `stdin`, `Some(1)` for the first file, ...
+    pub input_file_id: Option<u8>,
+    /// Position in the file where reading failed.
+    pub offset: ByteCounter,
+    pub error: String,
+}
+
+lazy_static! {
+    /// All `UnreadableRegion`s `Slicer` has come across so far.
+    pub static ref UNREADABLE_REGIONS: Mutex<Vec<UnreadableRegion>> = Mutex::new(Vec::new());
+}
+
+/// Remembers an `UnreadableRegion`.
+fn unreadable_region(input_idx: usize, offset: ByteCounter, error: &io::Error) {
+    UNREADABLE_REGIONS.lock().unwrap().push(UnreadableRegion {
+        input_file_id: match input_idx {
+            0 => None,
+            c => Some(c as u8),
+        },
+        offset,
+        error: error.to_string(),
+    });
+}
+
 /// Struct to store the `Slicer`-iterator state. The iterator fills the
 /// `input-buffer` with bytes coming from files, whose names are given in the
 /// vector `ARGS.inputs`. When one file is exhausted, the iterator switches
@@ -48,6 +79,9 @@ pub struct Slicer<'a> {
     /// * 3: ...
     current_input_idx: usize,
 
+    /// Number of bytes read from the current input so far.
+    current_input_len: ByteCounter,
+
     /// Is true, when this is the last iteration. After this, comes
     /// only `None`.
     current_input_is_last: bool,
@@ -57,16 +91,34 @@ pub struct Slicer<'a> {
     input_buffer: [u8; INPUT_BUF_LEN],
 }
 
+/// True, when the input comes from `stdin`.
+pub fn input_is_stdin() -> bool {
+    (ARGS.inputs.is_empty()) || ((ARGS.inputs.len() == 1) && ARGS.inputs[0] == Path::new("-"))
+}
+
+/// The name of the input `input_file_id`, `-` for `stdin`.
+pub fn input_name(input_file_id: Option<u8>) -> String {
+    match input_file_id {
+        Some(i) => ARGS.inputs[i as usize - 1].display().to_string(),
+        None => "-".to_string(),
+    }
+}
+
 impl Slicer<'_> {
     #[inline]
     pub fn new() -> Self {
-        if (ARGS.inputs.is_empty())
-            || ((ARGS.inputs.len() == 1) && ARGS.inputs[0] == Path::new("-"))
-        {
+        if input_is_stdin() {
+            // `--auto` has consumed the beginning of `stdin` already.
+            let reader = if ARGS.auto {
+                Box::new(io::Cursor::new(&STDIN_SAMPLE[..]).chain(io::stdin())) as Box<dyn Read>
+            } else {
+                Box::new(io::stdin()) as Box<dyn Read>
+            };
             Self {
                 filename_iter: None,
-                reader: Box::new(io::stdin()) as Box<dyn Read>,
+                reader,
                 current_input_idx: 0,
+                current_input_len: 0,
                 current_input_is_last: true,
                 input_buffer: [0u8; INPUT_BUF_LEN],
             }
@@ -79,6 +131,7 @@ impl Slicer<'_> {
                 Ok(file) => Box::new(file) as Box<dyn Read>,
                 Err(e) => {
                     eprintln!("Error: can not read file`{:?}`: {}", filename, e);
+                    unreadable_region(1, 0, &e);
                     Box::new(io::empty()) as Box<dyn Read>
                 }
             };
@@ -91,6 +144,7 @@ impl Slicer<'_> {
                 reader,
                 // Convention here: `0` means "not started".
                 current_input_idx: 1,
+                current_input_len: 0,
                 // There might be more than one file.
                 current_input_is_last,
                 input_buffer: [0u8; INPUT_BUF_LEN],
@@ -120,12 +174,16 @@ impl<'a> Iterator for Slicer<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         let input_buffer_slice = as_mut_slice_no_borrow_check!(self.input_buffer);
         // Fill the input buffer.
-        let no_bytes_received = self.reader.read(input_buffer_slice).unwrap_or_else(|_| {
-            panic!(
-                "Error: Could not read input stream no. {}",
-                self.current_input_idx
-            )
+        // On error, we skip the rest of the stream.
+        let no_bytes_received = self.reader.read(input_buffer_slice).unwrap_or_else(|e| {
+            eprintln!(
+                "Error: could not read input stream no. {} after byte {}: {}",
+                self.current_input_idx, self.current_input_len, e
+            );
+            unreadable_region(self.current_input_idx, self.current_input_len, &e);
+            0
         });
+        self.current_input_len += no_bytes_received as ByteCounter;
         let result = &input_buffer_slice[..no_bytes_received];
         let this_stream_ended = no_bytes_received == 0;
         let input_ended = self.current_input_is_last && this_stream_ended;
@@ -143,12 +201,14 @@ impl<'a> Iterator for Slicer<'a> {
                 // we already and know there is at least one more filename.
                 let filename = self.filename_iter.as_mut().unwrap().next().unwrap();
                 self.current_input_idx += 1;
+                self.current_input_len = 0;
                 // The next run needs to know if there is more.
                 self.current_input_is_last = self.filename_iter.as_mut().unwrap().peek().is_none();
                 let reader = match File::open(Path::new(filename)) {
                     Ok(file) => Box::new(file) as Box<dyn Read>,
                     Err(e) => {
                         eprintln!("Error: can not read file: {}", e);
+                        unreadable_region(self.current_input_idx, 0, &e);
                         Box::new(io::empty()) as Box<dyn Read>
                     }
                 };
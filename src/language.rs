@@ -0,0 +1,132 @@
+//! Identify the language of a finding.
+//!
+//! A naive Bayes classifier over character trigrams: every language profile
+//! in `language_profiles` lists the most frequent trigrams of the language
+//! and their probability. The language whose profile explains the
+//! finding's trigrams best, wins.
+
+use crate::language_profiles::{COST_SCALE, COST_UNKNOWN, PROFILES};
+use crate::plausibility::lower;
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use unicode_normalization::char::is_combining_mark;
+
+/// Findings with fewer trigrams are too short to be identified.
+pub const LANGUAGE_TRIGRAMS_MIN: usize = 8;
+
+/// Separates the language codes of `--language`.
+pub const LANGUAGE_SEPARATOR: char = ',';
+
+lazy_static! {
+    /// For every trigram: the indexes in `PROFILES` of the languages knowing
+    /// it and its cost in this language.
+    static ref TRIGRAM_COSTS: HashMap<[char; 3], Vec<(u8, u8)>> = {
+        let mut map: HashMap<[char; 3], Vec<(u8, u8)>> = HashMap::new();
+        for (i, (_, trigrams, costs)) in PROFILES.iter().enumerate() {
+            let chars: Vec<char> = trigrams.chars().collect();
+            for (t, cost) in chars.chunks_exact(3).zip(costs.iter()) {
+                map.entry([t[0], t[1], t[2]])
+                    .or_default()
+                    .push((i as u8, *cost));
+            }
+        }
+        map
+    };
+}
+
+/// Returns the ISO 639 code of the language identified by `name`, if there
+/// is a profile for it.
+pub fn language_code(name: &str) -> Option<&'static str> {
+    PROFILES
+        .iter()
+        .find(|(code, _, _)| code.eq_ignore_ascii_case(name.trim()))
+        .map(|(code, _, _)| *code)
+}
+
+/// All languages with a built-in profile.
+pub fn language_codes() -> impl Iterator<Item = &'static str> {
+    PROFILES.iter().map(|(code, _, _)| *code)
+}
+
+/// Returns the ISO 639 code of the most probable language of `s` and the
+/// confidence in percent. `None` when `s` has less than
+/// `LANGUAGE_TRIGRAMS_MIN` trigrams or when no profile knows any of them.
+pub fn identify(s: &str) -> Option<(&'static str, u8)> {
+    // How much cheaper the trigrams are in a language, than if all were
+    // unknown.
+    let mut savings = vec![0u32; PROFILES.len()];
+    let mut trigram_nb = 0usize;
+    // A word is padded with spaces. `window[0]` is the oldest character.
+    let mut window = [' '; 3];
+    let mut in_word = false;
+    for c in s.chars().chain(std::iter::once(' ')) {
+        let c = if c.is_alphabetic() || is_combining_mark(c) {
+            lower(c)
+        } else if in_word {
+            ' '
+        } else {
+            continue;
+        };
+        if !in_word {
+            window = [' ', ' ', ' '];
+        }
+        in_word = c != ' ';
+        window = [window[1], window[2], c];
+        if window[1] == ' ' {
+            // The first character of a word.
+            continue;
+        }
+        trigram_nb += 1;
+        if let Some(costs) = TRIGRAM_COSTS.get(&window) {
+            for (i, cost) in costs {
+                savings[*i as usize] += (COST_UNKNOWN - cost) as u32;
+            }
+        }
+    }
+    if trigram_nb < LANGUAGE_TRIGRAMS_MIN {
+        return None;
+    }
+    let (best, best_saving) = savings
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, saving)| **saving)
+        .map(|(i, saving)| (i, *saving))?;
+    if best_saving == 0 {
+        return None;
+    }
+    // The posterior probability of the best language.
+    let sum: f64 = savings
+        .iter()
+        .map(|saving| ((*saving as f64 - best_saving as f64) / COST_SCALE as f64).exp2())
+        .sum();
+    Some((PROFILES[best].0, (100.0 / sum) as u8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identify() {
+        let lang = |s| identify(s).map(|(code, _)| code);
+        assert_eq!(lang("The file could not be opened."), Some("en"));
+        assert_eq!(lang("Die Datei konnte nicht geöffnet werden."), Some("de"));
+        assert_eq!(lang("Impossible d'ouvrir le fichier."), Some("fr"));
+        assert_eq!(lang("No se pudo abrir el archivo."), Some("es"));
+        assert_eq!(lang("Не удалось открыть файл."), Some("ru"));
+        assert_eq!(lang("Не вдалося відкрити файл."), Some("uk"));
+        assert_eq!(lang("Δεν ήταν δυνατό το άνοιγμα του αρχείου."), Some("el"));
+        assert_eq!(lang("ファイルを開けませんでした。"), Some("ja"));
+        assert_eq!(lang("파일을 열 수 없습니다."), Some("ko"));
+
+        let (_, confidence) = identify("Die Datei konnte nicht geöffnet werden.").unwrap();
+        assert!(confidence > 50);
+
+        // Too short.
+        assert_eq!(identify("Datei"), None);
+        assert_eq!(identify("1234 5678 9012"), None);
+
+        assert_eq!(language_code("DE"), Some("de"));
+        assert_eq!(language_code("xx"), None);
+    }
+}
@@ -0,0 +1,2514 @@
+//! Built-in language profiles for `language::identify()`.
+//!
+//! Generated by `scripts/make-language-profiles` from the gettext message
+//! catalogs of a Linux system. Do not edit.
+
+/// Costs are `-log2(p)` in units of `1 / COST_SCALE` bits.
+pub const COST_SCALE: u32 = 4;
+
+/// Cost of a trigram missing in a profile.
+pub const COST_UNKNOWN: u8 = 52;
+
+/// ISO 639 code, the most frequent trigrams - three characters each, a space
+/// stands for the boundary of a word - and their costs.
+pub const PROFILES: &[(&str, &str, &[u8])] = &[
+    (
+        "ast",
+        concat!(
+            " dede es  lael la entan  co s un  ma paar al os ",
+            "ang esta se  si nuón na tu  el re in d icaor est",
+            "nun ca taconte iónca ia herquenteru ra  ba se al",
+            "ng del no ka enandianen men filanon fic socheció",
+            " pu unara ll l terienaci teda eru saicher arigua",
+            "alaernáu ngu ch nado allnta fapa res tirn amari ",
+            "staanale  prndi qu lostentu ditrama aniinding an",
+            "si ue manmarlu leslicnu lli moont melinga ria mi",
+            "tes poasenesge iu ai repnga neimaongndo arera im",
+            " daagetandeschialifalntrperec ten a inaortcarcom",
+            " toin ba uagastespeleadasig boran dobli kolos gu",
+            "icitec tristetenda amme dorrenuet su vehua leant",
+            "nia foamiignoriadoro  waionparuraxe sanescre mal",
+            " bu biprellatalli taraxetieetaua norolo peya  va",
+            "puevertorchathecolagalordiconeiniemewa lle kupor",
+            "edeproic llu acpúbúbl haepú yeeseuriunaitaineanu",
+            " yasarambberni atoralerengoerogn nomlemsa endaba",
+            "barericiaenedenomemaxencsu arrndeancpanforengmba",
+            "go ha ye akamo tosrmachunonrad usribhin be gaaqu",
+            "tamayasouam nci zaawa y garoutormpudrrauthumemin",
+            "bleka uan tuataocuban atungkan isintiguareechyan",
+            "amemi udoamo asquioreomaet stuoracu alorth liesi",
+            "altpaqersíu apadu ti ale crlisriuena cucalpaltic",
+            "no karomocanonaanotursh argnicganspa ni muto ati",
+            "nel pibo icostrvalcrioroeuttriuedwesst esamatrsi",
+        ),
+        &[
+            26, 28, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33,
+            33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "be",
+        concat!(
+            " па s ая ць  нескане  кака кая вы закі на  на пр",
+            "ны праае скі маія ыя вы стаараавааль фа дальніка",
+            "арыньнайл рага ва нскфайанааць адца танзнаня рав",
+            "агама  са рэай траераля памкар мованан ра  баовы",
+            " стдля длла лікнаята аліаньакаццаымаці  пепер та",
+            "андаў ран кркіяадаалоначры ні далар стрбліраданы",
+            "тармагьнекалрэсдзе знйсккраастмовчымыцьпаў ко аб",
+            "амыавітрыканама сіансыстда емагчыку рамагчйл пад",
+            "манчэннікрысіцьалаольадкменеццыкаылкнемлі райль ",
+            "вац і цыяэспазвататалублленпубспуходвыкатрлкамыл",
+            " тэназся  ар з  сеангэнтад ьнаасцдзі ла ў прыодн",
+            " гаак ннялосмаррымтваразмі  ваверрабаўтпіссанўда",
+            "барьні n каеадзам ас паравевараўнныяннеамейланта",
+            " чаэннграладант ня ўд асачэнгаалент  ліосяова ці",
+            "аннавыьняён расагрнакчнааніацыджа вокамданпасзап",
+            "ланраўнагнда у ваеазаласрацчанрскым аб  аўшчазаг",
+            " буамірыных этаіва верагсцькол су інствабацкаача",
+            "ралентьнынае як віабуінамероўнпавнія беень сп зь",
+            "акррыцгалты він атстэ гэ co мекавмы шыр акмалыва",
+            " алрыяільвалгэт хавод уснасвадду звапатненытаены",
+            "ызнба эньапіарэачыапакла нільккрынты кітэрер іст",
+            "енаахоароту кас дэніц гртэмэльліцаўдліндам кунг ",
+            " поракклюon  d ік выялючасачаснёвўва тоартрэннал",
+            "стоionьскза захкаточн антаўтэнўноўднон сцізенным",
+            "аёнпанса найедаадр дылівночднё місу ведіранервыб",
+        ),
+        &[
+            28, 28, 29, 29, 30, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "bg",
+        concat!(
+            "на  нане  за пр s ане нета  из пото ванза те  да",
+            "да ите сеия но ата отва се ка  е ен  копреени фа",
+            "айлфай съредпроранменетони  моразоже в ки от при",
+            "овемож с ира ст реостнияденниеиятже  рааваподри ",
+            " опли стацияат праполие ани и  обентавиестът кат",
+            "зваленнат им доравандствимееждйл извекттелотоход",
+            "скаданнит грнепилипциопц састождаятазнаориизпма ",
+            "давнетндаторетезадтанаци тослеледса атоти носаде",
+            "върят звески ка инве ада диенарешст реквил беоме",
+            "дър паматказепрстрком ар симе ватрмаез  клика въ",
+            "олзлзвнов слормдиргрефорявадел ил новежде мерина",
+            "стиписво алилововако емеченмесивазапед атеомаман",
+            "ирепъл d даденотваешкод брабез вртовройартшкайло",
+            " знремit ърж къаза фограла укаклюлючедеичнлонржа",
+            "спе ниеле укктоъм истодаянелноренобрверълнкъмусп",
+            "акоумеетитекексзполна акеднненреж giлнинти бъра ",
+            "ене вс маромедоизтвенметдарgitзи изхви ветобе та",
+            "иетква вибъддат те спатиеусастел аргер по ресзхо",
+            "постририянт бекан тноешнайт везданеунскалнконнал",
+            "ртнтойтросва reамеброелимо кетема дъратсъзъздърв",
+            " пъ бредианаъдетентвоимакраврейноженрабтреойнващ",
+            "ой абооканотлаворе вхгумргучетстедованоднициион ",
+            "пешува ба ликтиди позботеченакшновхочнозпъограпи",
+            "елначаилеилнпотрамнас coсъсзатсамозиче лик тр де",
+            "чватиркодребритамодос осдреотвалеъв одибли кррез",
+        ),
+        &[
+            23, 24, 25, 27, 27, 28, 28, 28, 29, 29, 29, 29, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32,
+            32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "bn",
+        concat!(
+            "ের  প্ করপ্র্যাার ান রা করা্য িত  s  বা ব্ত্রবে ",
+            "ব্যন্তস্থ মানা টি িয়া সম স্তে  নাাইলয়া  বি্র ার্",
+            "্রিন্য হবমানিক হবে নি্ত্ন্টযে  ফাছে ান্ক্ষ্ত  কি",
+            "্যবরে কার্রা্ট  সংফাই্থা পর অনির াম নেরর্থাতন্থ ",
+            "ক্সতন্্রজ সারজাজাতন্ডির্্যের্বনিরযবহয়ান জনজন্ক্র",
+            "ারেবারকে রি রের অব ক্অনুবস্ পা অ্অ্যনাম্রেওয়ার্শ",
+            "্বাধ্যমধ্করতরতে ছবছবিদর্ কােক্্রদ মধটের্থিনি িন্",
+            " হয়লে হারথিতর্ত এক কো্যরিনাশন  ভালেরক্তবর্যর্ুন ",
+            "ট্রস্তেছে আর মেরদর যা্ট্স্ট উপ আইষ্টইল রিয়গ্রকিন",
+            "েন্রণ পরিযা বহাাপ্ারিমেন্রসরার্শন দ্্ড বি দ্ব উই",
+            "ারাট্যয়েছষা কা তি নো  যেম্ব বরকি সারভাষাষা এইযান",
+            " চি্তরএই ধারথান চাপ্তকটি টে এরবা রানসম্ তািকাপূর",
+            "্ন শ্যাস এর ূর্ ট্তা  নয়নয় যোগ নেরুনানাচ্ছপস্ বু",
+            "রস্্টেৃত খ্য লেত্যমেরবির্রতল্ডমাপটেকস্কহ্ন অক্তি",
+            " coুটিএকটায় ্যক্রুঅবস ইনচিহিহ্মারালা সে্সটপ্যুক্",
+            "যাপনিয়সংখংখ্ানি সরোড িষ্করুলিকানেরী কোনআইক ডিিকে",
+            "ইকনোগ্ম্ভon েট রিবলাইাবে্ধােলোলিইলে লি্টা্তুযায়",
+            "ইন্ ডার্যয়নিডার ও ারণিবরকরে আকরিক হলবিনইজেনে  র ",
+            "েই রিনেকেিন  trযাকদ্দেবেটারাল  রার্ধেখা ধর সির্ম",
+            "েল হয়েথ্যলি ্ছে তথতথ্অক্ বৈকান্দেশিত লাযাস বেসর্",
+            "উইজুয়াজেটion থেথেকদেশলা ঙ্গযাব্স রিতয়ারমরিগ্যবহৃ",
+            "বাট পূহৃতপারর্দম্প্রোধরনারীউপস্ষরহলে আবর্কাই াইন",
+            " গ্ লোানো বো সকীয় ্ডাইল্নেই ত্য়ে বেলেশনযাশিক্য়া",
+            "বৈধরূপজানলাম d রম্ংক্entস্যত্তরো ায়নমালআকাভূমূমি",
+            "ৈধ চাই পু্ব সেলবাচমর্রিম্সে উলসময়মেমেমর্ষেভাববরা",
+        ),
+        &[
+            26, 28, 28, 28, 28, 30, 30, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "ca",
+        concat!(
+            " de s de  noes el  eler  esno  cola  laió ent a ",
+            "per d  caat  en un ha pe fi l ciónt estque rear ",
+            "da en ha al  po seés ls  infittxeitxxerra conun ",
+            "or desmenta comica prtrana stadelacire ectts les",
+            "ia  alnom di éselsada siion quut om ns tereixres",
+            "esp amproerscte pars  lestrtorgutix  ex marec ll",
+            " mo i onsri amb tridair foristtatcarit inaca  fo",
+            "ontue  araqumb escant opet oriormprecioeu eratre",
+            "unalitntentrte ot rmaoguan pogficifi acspencisió",
+            "dir supciadeerrint o bleradstàveromplla teariran",
+            "opccadssitenetapotle ostura soma matlid obse emp",
+            "rro oritzlicuesbresi  er miàlionaameid ire vetro",
+            "alldenparsenmpr vaus vàlitaactdoraràdretzaeguord",
+            "uetvalcapcrità cto vàecirorortualtes meriage scr",
+            "seresspecablcansteodegitminos fernti límosalicia",
+            "iu loríni giméstar da fassaermmetreall tal usloc",
+            "is lle aqonesegargcacmericilínmplntaefenvialscat",
+            "cci tonalposiliegimisado tafinieslattgecamctuara",
+            "sa st rrepaqnar emcif tirsiatgntsissanctemtipume",
+            " crreppriassènc imrefnatncaniaic imenes baincret",
+            "anvrticre analova tabregtanexttilimiàctmantuadef",
+            "ignturrobemaors n  ad aplliràcrt iniertinsticap ",
+            "té erepla cl grivaats loaltobrrdr hilison viatin",
+            "ecueroclaeleracratampcorinepusstiorabliurerarena",
+            "porpleipucalfilemeol ro infhi imaindrimdifomearr",
+        ),
+        &[
+            23, 25, 25, 26, 27, 28, 28, 28, 28, 29, 29, 29, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31,
+            31, 31, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33,
+            33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "crh",
+        concat!(
+            "an  ma sa baetierili  taangala kari amaet aralan",
+            "iling  tirettil bi teni lerndati arelarna la en ",
+            " keandniñ suda iñ terma si  meuriariesiya  yaini",
+            "er  pa etir  builelamar ana ha ch domanmaliye ko",
+            " laik şarin  naeni alki yet dera ec nıñ mi tuian",
+            "ıñ ası ar seca nga içga anısurgenriy oltanaliere",
+            "ğanstede ril beka  curan motec bota gi iriongün ",
+            "biral  İşle  ki köİşa wahuron ai cumndemi çünsı ",
+            " daumhnı mhuiçüne  to kuken ve peaca quaniarımar",
+            "sinam ba re  siurehuarinchi islenikaek ataist so",
+            "aq  tüiy  zaat agadanuruınınyalla sıkanme indwa ",
+            " s ing qıelencese ğı tıradakleencambiyalmametınd",
+            "engçikpen pi ga carmambaakayan yete mas anadıbu ",
+            "ege gurı dososykösöstinangomenawaungsan coay kar",
+            "igisye amel lem li qatin adbil mucerakiyeslğaame",
+            "hindi panerarikolaimaabaua sa go ce tarnişmadağı",
+            "alesaydı eliılatirstaemeırıak lleinetik niullsın",
+            " lobarayaapochaia orotuşmatatieklantamilı bas re",
+            " fobelbanillha no balro harru ip bo letiz  legan",
+            "ekiligyükqulil urahannamilaaltır  shemiit minve ",
+            " nowantesapatam pokseote yünguge  dindiun nanatı",
+            "biternkal faleşmayalıomalge ölştiano urisias za ",
+            "egisızdırmerrsiendumaişlverralenahi lavers negua",
+            "potasatal ng eklmeula hioloao raqrenabiuansel ra",
+            "ci etedarizaatelikmesita dönte va unorarlakirtem",
+        ),
+        &[
+            29, 31, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "cs",
+        concat!(
+            " s ní  ne po př prje na  naprosou se so jeeníoub",
+            "borubo vyze stapřeová zaný vánné ovace se  chch ",
+            "at áníor  stostrovit  doznaujero ho no  v pou od",
+            "vatou  a chystuhyb veužilzeru ky příkonpřientnel",
+            "nebelzto  ko vý relo orureska  nále podoužastte ",
+            "ná lattup obinaem  klké mencí terku načatenepkaz",
+            "ých zndattavslotel batin rost ovýveren  da innen",
+            "tu  d  adny eboet  jaovébo strzenba nov arřepdno",
+            "plaek adratn padrefornamvyphodéholik lita  spvol",
+            "odnranšti malíčlovpisnakínapínřádři vanrazepívý ",
+            "lož de taledtí ormakoko ty  řáodpklíské no tejak",
+            " ho záybali prá syacetraporměnistčísstiístpovnas",
+            "notobrím bylázeer  foesánt tabseldnířen toickpos",
+            "ně tenpoločeid echsářbraont alam  coak tovříkven",
+            "mén číednestezníkaprapočepl slže  n torkovředlu ",
+            "názaloaz án atamu dpoaleícívé žit sk sila ume mo",
+            "ry ivaargrozenozad užče on ti choživsle bu byci ",
+            "de do dovlnínouokuač  jmvá  u extnte z ballicave",
+            "typste karávignově ceádkkte ty zpvytíslanýjménos",
+            "časlenorovýs meikozí tníoloká sahovnytvše jícovo",
+            "vníby álnacíikaěnívýcelh kttexíhoatiracnezion o ",
+            " pl k otojedupnev zovup sto zmtnýelečetes lhaích",
+            "čeneli vovelezehalánozměnut býmísýt pozvstít ser",
+            "být akec dánujíandalítroalive ýstpokoliame lo op",
+            "spojsoodkenažádoleobsato vsbudtvomá  zomi  trzev",
+        ),
+        &[
+            26, 27, 27, 27, 29, 30, 31, 31, 31, 31, 31, 31, 32, 32, 33, 33, 33, 33, 33, 33, 33, 34,
+            34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "da",
+        concat!(
+            "er  s en et kkeforke  foikking detilil  ik tiere",
+            "de ndeteror  inder affilllees lerind fing ver st",
+            " erre ed  mene te andste i ent en kadensta ko ud",
+            "af endan sk tteog om ional  briventeretangse at ",
+            "nge re skruggenbruedemengerkanels veerselllin so",
+            "ninskron talmedlseunddetrinle mme ma ankriata te",
+            "dig vi prproeri ognnesom lirenligiskge tiovisst ",
+            " el atstrig atidel op un fekerkunnd navdatileavn",
+            "ejlfejyldel  kutettankon siern saskaldiegnken pa",
+            "væreksgt kom seresgylkalrer argivtegriv fr hvuge",
+            " på alngs beis ortallnt  sy fl adser etvenmerill",
+            "orm ugpå ett tamatagemanndtiv jl len d  væ dalde",
+            "ovevedugy mira despe dt ensdregletredskngiontlag",
+            "igtskeinjnjestirelid nsk omvalkat nanerrt ar ige",
+            "ist harmalemfrajerug vn rstrnerogred blstøvarlog",
+            "ppeypeve typktivet geommansnstintunnsentenertnta",
+            "ndrammlt  la x hed nuelttor nordi b  hefla læone",
+            "tatselme estore bilangn  lofinndata ardafs gr po",
+            "ateideag  coodeignsiolok trilstivnøgøglbli ovrsk",
+            "dsthvisamssetekastgra toiftudselealgtem nøgetsni",
+            "sætlutdarantramrsiargumepreoldeliessektletkstark",
+            "rseengrd rtetid di momelrrens lad n ranndsonsalo",
+            "em arsslublesymemeharnorærdhol spskiin  tyminndo",
+            " vaper ekit pakudeoprmbo slbolmma baakklissesvne",
+            "enearatørgruængennrvelstførnumatolæstabesktrandl",
+        ),
+        &[
+            23, 27, 27, 27, 29, 29, 29, 30, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32,
+            32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "de",
+        concat!(
+            "en er ich s sch deeinderchtungte chedenht  daver",
+            " ninic bees on ndeie  auin ch  di unatedieten ei",
+            "iondat inng  we ventezeigenistierterertben anine",
+            "rdestest tiort ersentteiweriscendere ko genge si",
+            "it  voeicnenle  zune hennd ausren re erei ige is",
+            "eitang feabe fü seberehl miellfehmenerdfürür mit",
+            "et  kaund wi zege  passeannlleaufde nn lenkansta",
+            "andrei kekontiggeb stdesvon alsie maatiebenntese",
+            "ameindchlbeiltewenrtekei nages scsenerwernrunhle",
+            "nis prrd althe achnamode arerz ennt ltifor talis",
+            " d lerim  coas rzeingheruf erulichreültgülan gab",
+            "iteme wir opselre dasone leem zu se telel al ird",
+            "onnegegerls rweile abum chn meest soptieiltztchr",
+            "us rst nu spur nertzeusg noensallsetintpro bitra",
+            "ageiesort oduntranserescnstennetzmatoptngüvor ha",
+            "at artormlt be is weiien foüssaktchiwarrmaassket",
+            " waübektialseig umtiefunhal lianzll  te übhnitte",
+            "hl zenferrestetlesenengsesstanzt ara ba neeraenu",
+            "etemmetypngagt mergeg laoll ak grlüsss utznutali",
+            "hlüts torriestieimtab ch imalekomspror sioinsord",
+            " muommele trlieefespeinilgeuchigndettes syerhume",
+            "änddaronssei obrsiitipertat saargnne pocha lonze",
+            "sinstr gilan wu moedeech exitsgelahllatrchar wur",
+            "zerfeneisurdpar subelnorergid gefarirtilaumannda",
+            "sgeck erlerbmalrscrn ratektasterfntrtemittnacmus",
+        ),
+        &[
+            22, 26, 28, 28, 29, 29, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32,
+            32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 44,
+        ],
+    ),
+    (
+        "el",
+        concat!(
+            "ου  το s το ση ης αι  δεος  αν απ κατουνα ων  αρ",
+            "ία  πρ στ τηας δενια μένεν ει μα ματ η τικ με δι",
+            " επστοσηςρχεαρχ συικόίναχείγραστηιστής ται είαν ",
+            "τα οπο ναπρο παγια γικατησηην είνναιεπιδια χρκό ",
+            "ρισείοτη τηντε  υπαναετασε  εναπουνατή ού τηςδυν",
+            " σενο λογατοτοςές  ποπαρχειμενες  μηεί απόικήνατ",
+            "ών  πεός περαραραφκά κή μη ικοίουένοπό ικάισμερι",
+            "στεπιλμε ίαςποιαταπουίο αλλυποατά δυστατανωσητων",
+            "καιατιόνο ταομέεργραμμετ αλ πλ ειστήέναοριλει κλ",
+            "ομα δησιμ γρσυναμμτεραυτ d ημαδικνωνδημγή  μπεση",
+            "νομ εκασηαντιο ανά coσει αυ ορναμιλοέχενικεδοουρ",
+            " έγον σίαιμορήσκυρένωτονχρήθα αρι αδγνωποτηκετρο",
+            "ρησενοισηντοποίις  θαντικε ρο μή  ο ατήδομήταμία",
+            " ήτ σφφάλδεδσφάλμααφήολήλλαάλμon λικ τιθηκ κε ή ",
+            "νταεκτμισειδionλή τυχ μέυργκούοίησυμίησασίογή re",
+            "σμέεντνου ένετε έχer κλενωσκέτοι  τωείτ όνήματασ",
+            " εγσα id γκυέγκτά φή χρηακοροσφορουςυς it νη ήστ",
+            "μόςρα  μιοντγγρme ορίησιίσττό  μαεικρακιακολοαμί",
+            "ρικτησάστργαενευρομοπτολειαρέπ σύ εμέπεπειπολγία",
+            "ρου κοτοποδογαστείλαγ εξπορριθάγνμποόμε x ιουθεί",
+            "βολποσστιακέ μοοσττήρργίεμφπάραφουτόιθμ λετύπγλώ",
+            "λώσλουτιμαδυκανδουώσσειρ γλροςνεισμόπρόκα ίζερά ",
+            "ως υστχου τεπισλείένητίατρέ βρυσηενό ελακτροεατί",
+            "ακαημοσμα ερ trconριογέςτελυμπ χαπακημισυσορενερ",
+            "μμήameρίσίτατοιte νισρατικαμιοκαθοιηειςτάσαπαούν",
+        ),
+        &[
+            28, 28, 28, 29, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33,
+            33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "en",
+        concat!(
+            " s ed  thon ion inthe re cotiole ng he inger or ",
+            " no toot notto es ile foect sefor fiis ent ofin ",
+            "nd of filatiternt  iste  deandate a  prcti pa ca",
+            "se re con stble witedme st ame ma anan val be us",
+            " unth  di exal usege resnamet  li arablessut ver",
+            "id ry alirea opcomns  chcatld it  alcan na d rec",
+            "ts onsallist enen  lo onstaas intat ageve eadith",
+            "locmen dowitort suerslinll ly out tabe ch ce  sy",
+            " whste sisecde  vaprone ineset me ortinstrpecnte",
+            "angtorliddatindpreranad sio sherrmattab mo keire",
+            "insper haserareporicaontannailno rin tr spiveled",
+            "ow keyher wainv daatass chaizethiptirronno fa er",
+            "ct pe hanrt  baignnvaue ackormcteerarortrissiode",
+            " laredratexpypetypoululdndeararenomm so x  assym",
+            "che frhis nunstocadefoptrs  adope mumanoredirlic",
+            "put pock  bulesrel typar miom ic ult ouactdis ne",
+            "mbocouainend veargchinumderrma byberay bolustalu",
+            "spesinifingepacextsuptra he atoveze addymbitiase",
+            "luembeererd wheromoryfai leounuppiteliseci teenc",
+            "ernompemocescreestol calfiesed u warplaunddinta ",
+            "ecocolrsiumbtemlt lowshomodnincodsizel tar geppo",
+            "ey  cr gifrolatget imharpat bieleritordds one t ",
+            "umentatrullo acturassownnalregplecifntrheneatmes",
+            "ty buttenprielofinls equthaurerteourref tiup onl",
+            "remnlytat cltchhasarc sance itideracreprgetesock",
+        ),
+        &[
+            26, 27, 28, 28, 28, 28, 29, 29, 29, 29, 30, 30, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31,
+            32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "es",
+        concat!(
+            " dede  s el  nodo  se cono os  elón es  laión es",
+            "la  ense ent rear cióconen adora  paas or  in un",
+            "estparto te da nteal icaro aciara putraficer ero",
+            "ta na queion caidostarescom prun menperada fion ",
+            " sireceracio lo aristdeledestrdesien al diespre ",
+            "pueuedntoida poporntrone a terio ue neslidndorad",
+            "loschecciandect opdenrioivolo  quontarcten tiher",
+            "enetoscadunable d las exvo encncibrealiichpro us",
+            "carifiescspe so tainarchdossermbromble abltorchi",
+            "troranverpo  hanomato vastema oriprehivdirmiterr",
+            "doráli y act maváluraia  acsió fa vetadstáir rma",
+            "ciaari moforca cac sutareccpcireglic tece  fuici",
+            "anttabctoalliza o omp peireso  erereopcrarormnta",
+            " meecindiintdeb trrea dativmintipdatlizlesfalrro",
+            "mo ne  liit valargractietesamaror obermcesiniona",
+            "rmiudocersecematá ersmeripo fopudntimielorposort",
+            "ecucidea qui saebeusain  x itefinextegiincaloace",
+            "asepecneacladicoloeta baemprtago ncobe ualnalece",
+            "end lecammpoinvlecdef an líodoctuins u rsi bi cr",
+            "crescrcridad clambnstnerorean iva orcifamieteesi",
+            "salicosinigutamctienambioperosdasordmod cuonoume",
+            "co ad si tictanve mpl nuiliingrepertnarimi ranic",
+            "rde togen mutilrenetoefiriacol núlla vápleelende",
+            "nváaderasid utatal mi adgisibllisavemasnúmaráale",
+            "onfjo amedisgitindiertuaartuarnocsolúmebolcorine",
+        ),
+        &[
+            22, 24, 27, 27, 27, 28, 28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 29, 30, 30, 30, 30, 30,
+            30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "et",
+        concat!(
+            " s  kane ise võmisse ta utaaille ud on faistada ",
+            "ga  faus  vakasigaili vä ontudasust id  koend vi",
+            "ei  ei ku sesutataatuja ti minte estamiineimi sa",
+            "oleastvälli  siisted äljel võituseriik avavigali",
+            "nimvõt tenda resel ja nikuitamks eerlis arimestu",
+            "ui de  su miadaidetatlt si lja nuri  prkir oluse",
+            " po pais il usteks sülooitami  alndiane lialdõi ",
+            "ajaelees  lu ki lo laer di valni ab ingaks taümb",
+            " keetannekonsaaandirjliknumjas mätavet entsiseel",
+            "steäärit egaganma tu undldanesemaemime keeal bol",
+            "ad katsüm jämbosen baidagi reaariontead madatvii",
+            " vetmeäraõnn piiteumbseana  stritademenriiimaoog",
+            "kseas talatemalamamatstioonlinral ineiditupolõtm",
+            " mutad ananind asege alotakia tansuunteuur toõti",
+            "teesioisi kä n aad tuolljärproargeemlem d  igsed",
+            "ahevermitoli tüdiskorinurjevainulaalonira ng tab",
+            "ormnt beriikogi lõilelitsamterlidainaatess cojut",
+            "aa ljuteiikitteete eesek leeraign peea rguan uud",
+            "esimberinikumastsimääkusinalõpldiridala esdesat ",
+            "ngukimultdusoodiksvad asisarantortek houmeabaang",
+            "ittmuuara tärjuliikässeesõnndm puatirmibaieva so",
+            "uutsitkooaarallvabarv erbarvahiivagaantebajunhen",
+            "peala lokvas sõrsiorrlliaikses vorusratioooma nä",
+            " et õneliikaärggumuba ri c  eb dell ledin  ühuru",
+            "resaidssikstbripartri aillambragentisal t dam u ",
+        ),
+        &[
+            27, 30, 30, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32,
+            33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "eu",
+        concat!(
+            "en erako ra  s ren ez da erare batzean ak tu egi",
+            "da atuia zenerrea ta ateekoarroa akoetaez rre be",
+            "in  kona atztzaua  fiitxtegtxa durriabifitentxat",
+            "utarakdu  egrabketgia deioabil paik itu izbat di",
+            "tua aralienaiertenaraitzmenntzbal alart aualdtea",
+            "harean ze es gaezi bi leala irreaore sala bertut",
+            "ikoailzindat karek in mainarraagoziosta harroika",
+            "taklikizeriarazgo aziakekonile etainri ezarateha",
+            "tatar rtztikte abezeadirkaraztiraiargaidag edlio",
+            "earkeruruestendat io tekka  tabehteretetareneste",
+            "talirekatdenzekurakoazailererentudo andginiaknda",
+            "ztezeride zatsiizaekirepdea mo prrikutsesk hoari",
+            "istrte geertedo sonektzioriaukpenleaortbidamapak",
+            "pro laait meeakzarldeonttorgarmanrorltz troarume",
+            " te huburgabranmaiantnar orlat adtanlankinorruke",
+            "gi untilimaremaakurtuiltadisiogunre aleon  gohut",
+            "ek sarrertradieldaoakekt ithauidaoneukontaatikur",
+            "zanurr siuaritazakizketidiade dests  eniteza ki ",
+            "abaueneheaur on id heaguzatadaingkitnakblibarenb",
+            "ilaonanteagaubltxiargpubudiformathonndobak attur",
+            "elebaiepuuneint d er aut jaegouna boanggurionorm",
+            "kenuak luoraotaaltsor buma ogaskanergoeakakalro ",
+            " eliennetleioko ikrts reaktiogzkozia guurt doord",
+            "badresemeral hiruairurma ekgitlorkor condisa ze ",
+            "eku looenzkeanangobeale kut fo giomazabaki ablak",
+        ),
+        &[
+            24, 25, 26, 27, 28, 28, 29, 29, 29, 29, 29, 29, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31,
+            31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "fi",
+        concat!(
+            "en  s iston ta nenine vain  eiei ettstoosttieell",
+            "le an  ko kä ti viedoieddosstalintetoitsa lliäyt",
+            "ssa ta tuitttä virtta ol onlle sirhesteirholettu",
+            "alikäyvaleenaintu littaato tonja tus liiseenteel",
+            "us iteet menteeaa ttästiitu luttimertelavanni ar",
+            "ia imenisimila  mu kutteaanllasetmisksiessstulis",
+            " la paallnimtunhee sy saritsensi its lomäääärytt",
+            "kohmattsivoiän erktäääänkki kavai voiviti intenn",
+            "utt as jakis d  reatoestetutavminhtejoitaiill su",
+            "tulää oisntaoll me poakeisätii jokireri nilukaus",
+            "ase x alalä itarkk seirjsä ko kässsäte olintesin",
+            " tä aletaustumesymlaiäri kisoiuutonnri uvaoso pi",
+            " näataksesisulotin tova varaisranemannearv ty ha",
+            "iinikkmi bolai losrekepämbo maoi aiklohohkhkoymb",
+            "terstä vä epoa  veuotohtverntiit pernetna issrki",
+            "tilhe ilaytätuirvoisiat kooskyäsk entamtoiarkuks",
+            "untui attukuuetlueekiekssimiä elmty lläsestyyoko",
+            "li sek ri mäamieesamaotevatun ikasiiansvaapäoäon",
+            "ijo kesiosiaien jäast teiankokkanot liittykonkuv",
+            "sijktikemtämatitioalueratasunnkai yhiondeniirrja",
+            "oridotersope ohjentueut  b inkltapalaramuo u ood",
+            "muuodoodihdersiki datrosasssuouseyteeroaat pu n ",
+            "ity ospoiämärivargttötememika asaohjväliet ot op",
+            "komlaumet bioss priivälirjootapäi notoaakitukomi",
+            "sititäandtarinaudeelesalkeetuutenäinntt mideltys",
+        ),
+        &[
+            26, 27, 28, 29, 29, 30, 30, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32,
+            33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42,
+        ],
+    ),
+    (
+        "fr",
+        concat!(
+            " dede es le ion s on  leer tio pare entur  co la",
+            "nt ne la  in d les unns te ficqueeurour noas pas",
+            " po l ati enich reue ierchimen ficonbleestlis es",
+            " dédesst trerescheen ctidu  supouansun  du li se",
+            "parhieonnet  maire n dan ré pr dacomantge ectrs ",
+            " ch à iquutise ée  sossiageessnteiliont auonsit ",
+            " neemevertilunets valut ign utali trteristce  do",
+            "cha moiblnomal posanders exten imsio taideme ser",
+            " op a ise pellerreus ec ar is mpoifiomm sintr ar",
+            "nde av ou vaimport quranautact foaleertienara lo",
+            "mantraangsibstrées etossabl ca vettertiavestaate",
+            " cenonindintnneréeie catproureerrêtrica éc diive",
+            "ntiperau  ba saom ou  syir ptité forrecssepe  êt",
+            "argrenpeuisateuin gnedéfite teillncosupat incini",
+            "coroiroptffinceompouv pltanancvecodemataisrép af",
+            " erormendporhe modligairnéedonafftiestefinpreins",
+            " stérilidtaiuppenceutnalitityptur acrou toypepri",
+            "lansonleuursratatthercesez  tynnéoustabtessursec",
+            "tif al étuvereu apsatoitraitromin clmmeorrrmassa",
+            "ndaellés ntaett atng ulelon misanan tivqui biine",
+            "si échnstnes pu crinv anèretatrsi x acendicodux ",
+            " meve isésouorstouailasscteout u dredenépeéfiére",
+            "ntspértalina obnguapppu uesermmbotriallextéci gr",
+            "el oritoibredoinvapluéranu regguelocrchaqubassym",
+            " sprercarongboltréalasigremriecrirtodifcalracond",
+        ),
+        &[
+            24, 24, 25, 27, 27, 28, 28, 28, 28, 29, 29, 29, 29, 29, 29, 30, 30, 30, 30, 31, 31, 32,
+            32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "ga",
+        concat!(
+            " s achan omh anar ir mha cois ann lech id com ch",
+            "na haiithhad nínn  a  arle thead ha amhdh cht ag",
+            "il in aintha naáidearailteabhaaisdirnea aihe idi",
+            "éidní eamaitionas eanchaairtaiáin roeac neus agu",
+            "ghaaidgus ta bhadhath deáillan thchoreahea teigh",
+            "chunnahar seta imhlacon  ga ioht ne idhoghrogart",
+            "hbhinm fhmhbtá mh ilíint fé i anasáiféihomarrgh ",
+            " marailí  saoch sc ineadte  athtaúsá táheiuim ca",
+            " soaigán ag irede eáiríoth nt naiile úsráiníoinn",
+            " ealea fostaascrthhái go isíomabhganmhágo  st lu",
+            " po nóblanm adaaí oireis ra á bh  d nó rt  dh as",
+            "aghiomadlargalasc  n dlaonrspelei ha linac orpeá",
+            "seoordíorthraoninelai cueag ío réíocarascarrácar",
+            "rú  shscrceaonaeasla baitar amip ispsioonn náhéi",
+            " baaío tr sihá iriracíl ontsonangmar t éancheeip",
+            "haniscocr gc ia ceraíréiníl doobloinnra risa orm",
+            "istite prhéarbh resea uintaná iltéamshoeo olapob",
+            "rit oi líóinuaiéislínisior  slur huriosal gac ac",
+            "bhfeániarra ntehuirioidegóiforacage caieoihoimhi",
+            "mhncríaoimái faagaiú ll álaaim spostre cuise sai",
+            "nchinirgó panasorbortranhir p fhé lanadéir é uac",
+            "alluirschíonítetacnga loeitrg bhroibnishnihocrmá",
+            "ce eol diléaastnne c cáiúil toam eir citáidaistá",
+            "mhsris x ghrirt lé crriapri buconéad f isehshaít",
+            "os ial ao gh da dédú laouillte mhínembaréame  gn",
+        ),
+        &[
+            26, 27, 27, 28, 28, 28, 29, 29, 29, 29, 29, 30, 30, 30, 30, 30, 30, 30, 30, 31, 31, 31,
+            31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "gl",
+        concat!(
+            " dede do  noon  s  coos  sese iónar ón  o entnon",
+            " esra da ro  doas  a  pacióto  inica rearaestte ",
+            " caado un po maconqueficpares or ta men fi praci",
+            "un  datrano eiral nteche te eniroen resstaca er ",
+            " lina ich moan heila terpro sidesadainaio idoand",
+            "ntoia ect qu baue comrecco lo ma angarindorioel ",
+            "stebelha  chistura di meao ont é  sa focar nalic",
+            "espntrali suerrou ingpre larad taranper aoposng ",
+            "tenme po omenta soactind er kaporelerronhaidandi",
+            " us acalaatounhtec loere e esc exemaantverdosstr",
+            "orinom peiciicochaianionamaodego cto as al lere ",
+            " arso taltor anns dor os tríbeanoortonacorstáai ",
+            "ametosnguintcacanaall vecia vaera timannticaduse",
+            "és is  ou fatarlloserfortiveci bodicngaifincinal",
+            "repticlasguari adeinimarmo ua imauciir ónsteminc",
+            "podle ambminiza mi or bucansíb toduccrindetadga ",
+            "ignete imomaaxeracong haersdebsióompdeneroclaria",
+            "endalolanxe tesndarasormrodlinabespeitaerivaltro",
+            "merargemeccilidciormaoduoreecelemosínor opmasenc",
+            "cidlecorrrospecbe gartá camelauetetaesesen ni id",
+            "lizquiin bliostrarnosscrdir binic apctioraaveder",
+            "ivatursigale atgnoreaempsinaseusaipo xanco gregu",
+            "ata elermtipitocalfin d gurdadcerrtelormalumeius",
+            "mitmbiasianianemprtanmoschiaba pumat cret ocaaga",
+            "ordrsilariraea ralireve ciurrevo ngoextrmiba ati",
+        ),
+        &[
+            25, 25, 27, 28, 29, 29, 29, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 31, 32,
+            32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "he",
+        concat!(
+            " ththehe ית ed on er  s  toor ion into le ng ing",
+            "is tio co שלשל ים  wi ofentות  foof nt es  is wh",
+            " rein forcon pr se no de a ניתnd ectwheatiileet ",
+            "en  beme ow יה ble finotterot  anuseandherקה  us",
+            " pafilte  maont קוcatindate sh stted הרeth di mo",
+            " chal promeness trיקה איון icanteameverנה valth ",
+            "st ablhet enresintwinge ce בלי lild  ac al bure ",
+            "thich לא se ll  לאובלרפוdowליקפוב tetorns הרפ or",
+            "agets  caמה  lo arוניctisetue  on מק או מסnamid ",
+            "hisreaan  scור ndo vabe as  me hederbutext ex po",
+            "ין  nasho sp המרה act la keve keyמונlicly לה app",
+            "ypeאת onsers מוerrtyp tatrape  apounangut at loc",
+            "rrock allקובpac unareali tyxt lineleortscrss sed",
+            "if luede om cha ניrec miistockalult  hatex doובץ",
+            "בץ  suיותwiditehenנת  sirataceertplatinני  אררית",
+            "ar ry  opprecol להemeתמוrs pecultus ineserovedis",
+            "por ifcom ereadizewitsiotenledgetspaמניct  אתdef",
+            "ליתdesel ty pplctene stacrettoodeey rt it rorrin",
+            "cesund itמך illendacc sostetem הוcaliverenrouאה ",
+            "ifired פוtri veoululd atlis מיail תמpliמקש hoיני",
+            " bohowls  baeenccehanideמסמze  asico cl leimeith",
+            "atochehemackבית d chiסמךרביlayutttonrandin אנ ti",
+            "era x אנגntaiti מת האfauaulem ndehar שמefamodtru",
+            "tabcurirecanקשיוריhou ברlesstr אלlidperdgelat pl",
+            "speighטית רו cuft שיםעם  סיoutדי מותmatarativ fr",
+        ),
+        &[
+            26, 26, 27, 28, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "hr",
+        concat!(
+            "je  s  pr poijena  zaka  neja ki ni  na jeti  da",
+            "datne  koskiatoanjstatekotecijtotza  ninje u rij",
+            "ke  stija izorinijranostpreno pro seira re mose ",
+            "znaprili ma akomenva om ikaitired is odta  i  sa",
+            "jenjednakistra entko  doati ramogljaju ogutav op",
+            "nja kastiekate  br vrililiktoranigućrojjanvanće ",
+            "ak aciina su di ar imlazpisnosbropos znena grava",
+            "edn sian  ilpodimeme stodirova intanrenrisorakor",
+            "pcinskopcrazstrima biemaoj og ekenu ljeicito ret",
+            "jelućealjispnemenist rekkomaraku  matre ve ak tr",
+            "vi dnoniciredaneli svanaih ektod odave val deda ",
+            "ao ji kovmje baivaanouspporevaeno slišeri umetra",
+            " liareulapozforaligreci la  spemekojormenješknt ",
+            "prajev taše aka tiratada ovver n ravrešnikkaoica",
+            " uskonatavorsa remavičinim držon ansvri up meezi",
+            "ca oje fontejskspinevce ktoargaz ljugratriitarma",
+            "bit alnaror vljzad ober oznrinavl noodrovesu ore",
+            "čitlič teeraemoazn d ing tomatesttaront loeciene",
+            "jezedaže lo načnovnoginisniba en ik škaizl pavrš",
+            "andizvpjespjisaitevarpotponoviznivnizlaičkle oji",
+            " ulineaj opiarirebovnsigoliupoekubling ressammo ",
+            "edetak coepozorstuaveadrrepetamoronenavrit kramo",
+            "jerlinajtržadarskadniedbeloalnvezgumedivelslinal",
+            "tipnataneat amaokaenuradiceromeksnihonadretvoazi",
+            "nepdenijuifiid ustsprnomternda vaoljsadogr virgu",
+        ),
+        &[
+            25, 27, 27, 28, 28, 29, 29, 29, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32,
+            32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "hu",
+        concat!(
+            " a  s  sz me azaz  neeleen em  kilenmegnem hatt ",
+            "és ásafájájlsa tástelet ek  ka el begy  fá leasz",
+            "csoegyaranálmen kö vaak  éres an ok  egás hastés",
+            "terentsznagysze fehatznájel al cslt felése tase ",
+            "al ett hiértor sszítá foényjl  mincseneáll éstár",
+            "nt forsolsítlítottat tó ja szatal kelet rekapint",
+            "atóre hozerezésranszáálarásmin paapclatpcsneton ",
+            "korra tumáltsztvénzetcs gyehetkaraktrakva el er ",
+            "ezeala tekerolóelyközsorvagsi válrvéllíti kezmez",
+            "érv mazámpar ho pr sozás arlis z os ítészi adség",
+            "nyeni hibláslyeyelnakelmrenhelrteba ányetelmeus ",
+            "lhayte henekezőló esz kéatoendzertarktenytart je",
+            "eg ik indöveincancírá liezéormis etiságez ha ont",
+            "atábanlestékllepro bi nyum  vátetlapta  töásoalá",
+            "rt ibartéve ellrül nitateál áltotoz ndecsadatver",
+            " innévretész sirmá is fi konin nésakerüai ár beá",
+            " desokkönnyvelőgadelveganyo coönyst ntealmeme lé",
+            "ben veadaargortégetreyvtvtávetlananglegimeumeval",
+            "sikyezző lemissztáres vi utntuozá d át istül las",
+            "etőll  idot ehekatképlmamagév  akeltoma baössolá",
+            " oleténevatavanvek tá ezleh kuikeelhillásáia  es",
+            "tő áralinlvagessz éremazlokasáók lakálóertásiam ",
+            "evelétultkelfejbb módte ág  n ejeelsán ciókiíiír",
+            "porandszoite e  lali visrta ösle ltoköv pokus át",
+            "áli mólta máosímer enlésnosrésgjene essionmásten",
+        ),
+        &[
+            25, 29, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "id",
+        concat!(
+            "an kan da s  diak  meng ang tiidadaktidsi men pe",
+            "at  beah  seengberala ke bakasri ngaikaariterper",
+            " teal as ata inasi ta reuk ntuda gan unyanaraapa",
+            "tuk yaamaadarkauntaka ma paerkran kopatlamam ar ",
+            "daler  deingalima dartan saeramemukadappenlanand",
+            "namunghanai  nanyaeriit ngg ka siunaya agais seb",
+            "gun ganda bu adembid ngkon entelantabarini labua",
+            " ha arlahka danilagalmbadenni  stet ilior  vata ",
+            "rinnakin ke bah at bipillikenaisiik  no coint su",
+            "ia iantakahara valen  ja anna bolasaol tareksdi ",
+            "ebumasus  prsa strlidtorau  d gkamat piersura op",
+            "el mboihasanset ca pode lihbageludiale indstakun",
+            "veristtikoriendlaitauban alana akesikonla es ris",
+            "amb lemanndeuanodearustedir lo moatiabaantiretu ",
+            "ti uattamukupanbelkomluaerlaktketharuarga uahrsi",
+            "atuforgagte dik chditlkarekesajankarsimgguamppad",
+            "ipembulok nieta aprmapak fooka toalu liertong ku",
+            "hasproraknaltemanyek nteektlat x ionaanormkelarg",
+            "buketiolelu  gusik mitastri giiteut emulisurupa ",
+            "ebatifur gitrenagireslin veeredistenisantiksihka",
+            "pesasupe saltahnanimbilk wanilerbks ndiensaiksuk",
+            "uladipacatraayampiinaregre mpaontigulehli emamod",
+            "nguselkatrelruspredid figga muktopemnsieneum ope",
+            "umeruknt tiprikca eleua itachiainjalikeiledattin",
+            "awabacbunmeranierr obspesisimaenuih eh rahdibtel",
+        ),
+        &[
+            22, 27, 28, 28, 28, 28, 28, 29, 29, 29, 29, 30, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31,
+            31, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "it",
+        concat!(
+            " dito le  s re ion codi  dene  noon ziooneentla ",
+            " innoniledelta ellconte atoil  il ripernteer ti ",
+            " fista unpos laica semenareessel  pe esazissilla",
+            "bil è no fil reest daalimpo pr imun lo na  stere",
+            "impra  ne maibiore alcomterni chiataveross suin ",
+            "se ettall sotat ch pache l ati vavalntiro omedo ",
+            "ll ca me sibso aletenio  caonitrasio lentoficist",
+            "ina sitorli ifiand po tr mostritaserostma preatt",
+            "uraonoeseratri ia erirannelame qutroirendipo olo",
+            " i  metteingda  tahe araont licat d zzacor ar op",
+            "ttoerrsegco oristeint sc usricariizznom a car ve",
+            "sci nupro teunaitoindiniggilitresit ve aggmanfor",
+            " spsseeraza  e al quelloporce dat erinerimmerrec",
+            "stoei ndollerma lomincolimedireromodstilidntasso",
+            "antililicfin puusasa orttri baga  tiizitaban rro",
+            " viparlinasselepri elerstesichnesttisi lizdeiry ",
+            "nal fogio sang ico o dicsonrisreaonaacceneutospe",
+            "ut umersiroratetalorm crgliicentr gienzodiopzppo",
+            "orapziect atlocdalecirigangpecienmatvis utcitria",
+            "de sol ananclor pihiausctipturndeticeguompritden",
+            " faggecifiustaralorio hacescre doede ornumargrta",
+            "putcrisenssa aptà difondavetivspouesoryguiomaerv",
+            "uppnatabiiposistemiannzibassupnitscrferorrorddef",
+            "ute agidonsiet ovaual minguefiiviano fuermitàase",
+            " u st id  duscongaiavregllidisollsulimaalttamnza",
+        ),
+        &[
+            27, 27, 27, 27, 28, 28, 28, 28, 28, 29, 29, 29, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31,
+            32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "ja",
+        concat!(
+        " s ませんせん ます した です できまファイァイルきませションていまましたする ありまりませ",
+        "しますいますしましれていがあり d でしたしてい ファせんでんでしされてデータョン エラーle ",
+        "イル on  coプショionいませりますされまオプシ x ポートile fifil失敗しer ",
+        "敗しまレクトクショid ディレィレククトリを表示 reされたさい ユーザに失敗ーブルテーブくださ",
+        "ださい u てくだーショ このまたはサポー b 定され無効なシンボラー me してくはあり in",
+        "としてを使用tioない コード de表示す示する m  n ベースなりまフォル c ている st",
+        " オプしないセクシ 無効nt ジョンージョバージcon読み込サーバータベタベーverれましること",
+        "ーバーデフォォルトマンドコマンal しませ デー指定さingentres seを指定ではあれます",
+        "ード サイズ存在しet ameシステステムate です は  prte イルをンボルst トされ",
+        " pa文字列forれませ posioge ng ット ければnamなけれ tr定する li また",
+        " 警告 f 用するectートさse  fo不正なイルのリンクパッケッケーケージes を作成ロック",
+        "警告 メントバイト定できを設定指定しックスdatter teage用でき ディーザーが必要nte",
+        "使用す対するersに対す laループモード nustrを削除 maジェクェクトント  noll ",
+        "ぎますグルーョンを要ですドレスキストッシュ必要でin ran si chテキス からブジェ を使",
+        " p たは アドレ luce  di veインデ使用しセージ在しまトリ セットtra tacom",
+        "オブジ デフメッセッセーdirld 見つか a  文字すぎまケーシができlu にするっていはでき",
+        " exrt  op ar lo場合 or ヘッダかりまlin ユーtorするこ セク インlat",
+        "ont エラ シン要があype必要がpe ればなangtypつかりデックsta場合は id書き込",
+        "プロセintされるどうかリケーことがかどう使用でit を読み プロンデッ t  入力新しいat ",
+        "ばなりバックはサポser表示しrsi daostレーシsqlクラスposロセスextatiアクセ",
+        "が指定設定  toリスト サーが見つラーがarg 使用チェッェック に対triフィーde アップ",
+        "ア語 prosetール のファ ldみ込み alth  wa バーistールド 出力 plィール",
+        "には る必要rg するかloc発生しメータが発生odeansていな表示 ラメーョンの合わせパラメ",
+        " バイ 不明共和国 不正和国 pream cha テー ja unタイム再配置とがでけませである",
+        "うか  usリレー ty naptiきますbleを取得bugブル ブロッメモリクセス演算子opt",
+    ),
+        &[
+            24, 27, 28, 29, 31, 31, 31, 32, 32, 32, 32, 33, 33, 34, 34, 34, 35, 35, 36, 36, 36, 36,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 47,
+            47, 47, 47, 47,
+        ],
+    ),
+    (
+        "ka",
+        concat!(
+            "ის ები s  გა შელი ბისბა ება არია  დალებელირი ული",
+            "ნებ სალისარა მორებლიაბი ამოგამ მიმა ვისილიენედომ",
+            "ებეშეცრისომადა არე ისბულმარებუეცდცდოერიბელან ორი",
+            "ში  მავერნისურიტის ფა სიობი პასახთვიენტარ ტი აილ",
+            "ფაიიისაციანიანამისყენალინი ნა იკა კობს წერრამმეტ",
+            " ჩაზე ვებძლეპარმებ ვედი ნტისი ვა ახესთვერთხელობა",
+            " d სებ სეშეუდებ რემენტრიისთრასამეართ მეას რილეუძ",
+            "უძლბლიერაად ციააა  წატებსწოწორარილა თებციიერ ოლო",
+            "ადაილეით ცემასწ ბაემელობნაცმონიერეტიითიასანელონა",
+            "დანსისველმომთი ელო პრდამ ნა როეტრულერა გან ტიხმა",
+            "დისმნიid გადისაონიტიპქციოდითითექსრაააცე ინებლამა",
+            "ნდა ანხრინიშგაროს  მხტან ცხმხმცხრერეარს უნ coაზი",
+            "ცვლირერულმდეპროელელიკითეუნდებსრსესამბაზბებიპიებო",
+            "სერლოდვნეეთ ომხალუენიკონროლიდალურადიიშვ აღმოსტორ",
+            "რთირდავეტობსმითანეშვნვენნდეარდშემ u  მნსასივიდაყ",
+            "სტიმატაშიბობ სტ ფუაწერმადასიანერვ ენრთვავსოსალად",
+            " x შვე სვინაქსითავღებოლიფუნმოყამიემოისისრუon ატი",
+            "ამ სვერვეკაცუნქისტნქცრე ხარვი ნობსტეთხვაღაფორშეი",
+            "ოვნ ერრძართოომელო კა ალდსაქ m ვანოებionესიბითბლო",
+            "ორმსიმ ამრესომიკოდოყეაყეახუღალახა დივლაინიმოტაქა",
+            "ქაღ ბრტერატოლემოდეგრაზისინდთხოგას იყal მოიმხაჭერ",
+            "ოვე ზეშესჩვედროერს კაბოლენაიებდე er რანხვაიყოუბლ",
+            "მიმრტი დრკის ხა ავ რაროცქმნატეპერმოთ reონტ თანია",
+            "ეს ავიგენტიკხულვლეოცერსიეთირიასიასტრ კლრომ ახვე ",
+            "ვნიყოფდენაკიხალვს ემთაგრსაკლოკაშვხოვტრე ტრყოსიყე",
+        ),
+        &[
+            20, 26, 26, 27, 27, 28, 28, 29, 29, 29, 29, 30, 30, 30, 30, 31, 31, 31, 32, 32, 32, 32,
+            32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "kn",
+        concat!(
+            "ನ್ ನ್ನನು ್ನುಲ್ಲಕ್ ಲಿ ್ಲಿಲ್ ಯನ್್ಯಾಾಗಿಂಗ್ದೆ ಟ್ ಾನ್",
+            " ಮಾಸ್ ಾರ್ಗ್ ಿಯನಿಯಾ s ಿಕ್ತ್ತ ಸಂ ವಿರ್ ವನ್ಾಂಗ ಕ್ಿಲ್",
+            "ಿನ ಿದೆುವಾ್ಲ ರಿಯಿಸುಕೆ  ಸ್ಿಸಲದು  ಅನನ್ಯಲಾಗರಿಸರಿ ರ್ನ",
+            " ಪ್ಸ್ಟ್ನ್ಮ್ ವಾ ್ರಿ್ಯ  ಭಾಾದ ೆಕ್ಯಾ  ಸಾಸಲಾಿನ್ಕ್ರತ್ರ",
+            "ವು  ನಿಳು ಲು ಕ್ಸಭಾಷಡ್ ನಿಯಾಷೆಗಳುಪ್ರುವ ಂದು ಕಾುತ್ ಚಿ",
+            " ಮಿ ಮು ಸೆ ಕಡ್ಯವ ಕೊ ಹೊನಿ ಂದಿ ಅಂಕಡತಗೆ  ನಾಬೇಕೆಯ  ಒಂ",
+            "ಾಕ್ಿತ್ೊಂದಾಲಿಟೆಕಒಂದಿಕೆಕ್ಷನಾ ದಲ್ಾಗುವಾಗಲಾ ಅನ್ಕ್ವಮಾಡ",
+            "ಸಂಕಕ್ಕಸು ಯಿ ಂಡಿ ಗುಂಕೇಾರಿ್ವಾಕೇತರುವ್ಟಿಟ್ಟಸುವಟರ್ಷೆ ",
+            "ಚಿತಗಿಲಗಾ ಕಾ  ಎನ ಬಾ ಸಿ ಮೆ್ತಿಳನ್ಿರು್ಟ್ಯಾಂಜ್ಯಗಳನಾಲ್",
+            "ಗಿ ಎನ್ ಕುಲಿನಮಾನ ಹೆಗಿದ ಅರಹೊಂಟ್ರಾಂಡರಾಜೇತ ಲ್ಯಾಜ್್ಕೆ",
+            "ನಾರಮಾರಾಯಿಿಂಗ ಬೆಿಯ ್ಸ್ಗಿನಿಯೋರು ೆಗಳ್ಲಾ ಕೆಾನಿ ವಾಸ್ಥ",
+            " ವೆ್ರಾ್ಟರ ಬು ಪುರ್ತಯಾನೆಸ್ ಲಾಸಿ  ಗಣಂಗಾೆಟ್ಳಿಸಶ್  ಅಕ",
+            " ಮೊೀಸ್ಿಸಬಿಸಿಯ್  ಅಗವುದ ಬಿಲಿಯಸಬೇರಾ ತ್ ಗಣರಣರಾೊಂಡ ಟೆ",
+            "್ರ ಮಾ  ಸುಟಿ ಬಾ  ಟ್ಿನಿವಲ್್ತರಂಟ್ಸ್ಕಡಿ ಪಟ್ಸಾಲಕಾರ್ವೆ",
+            "ಹೆಸಾಸ್ರಿಕಮಿ ಮಿಕೆಸರ ಬಳಸ್ತ ಪರೋರಿಗೊಳಗು ಕು  ಗಾಲೆ ನೊ ",
+            "್ತದವಿನಯೆ ್ಸಿಷ್ಟತದೆವಾನವೆಸಿಶ್ ಹಾಯು  ದೋವೆ ಂಡ್ರೆ ುವಲ",
+            " ಯಾೆಂಟರ್ಕದೋಷಿಂದ್ತಾದ್ದಾತ್ ಕೋವಾದಗಳ ೋಷ ಿಸ್ಂಡಾಾಮ್ುಂಡ",
+            "ೆನ್ುರುಟಿಕಡಿಯಬೊ  ಟೊಅಕ್ ಮಲ ಶಿೊಳಿೆಂಬವಾರ ಇಂ ಪಿ್ವಿ ಲಿ",
+            "ಲಿಕ್ಯೂಂಬಾಟಾ ಯಾಕೋಲ್ಯೊ  ಪಾ ಟುರ್ಗುವುಕೆಯಟು  ಅಮುಗಳಲಾದ",
+            " ಡೆಸೆಂಗಾಗ ಈ ್ನೆಸಿಯಸಲು್ಕಾೆಲ್ ನ್ಯಾವೋಟೆಿಗೆ ಲೋಂದ ಮೊರ",
+            "ಪ್ ್ಷಿತೋರಡಾ ಗೊಂಕೊ ್ರೆೆರೆ ಬ್ ತೋೇಕೆಗೊ ರೊ ವಾಂ ಈಸಈಸ್",
+            " ಬೊಬಿಯ ಮೌನಲ್ ಎಂದರ್ಬಳಸೆಯುವರ್ಲಾಂಯವಾತಿ ುರಿಗ್ರರುತಾಂಬ",
+            "ತರ್ಸಂಪಾಡಲರಿನಾಯ್ೂರ್ ಗಿಪ್ಯ ಸೂತಿದ್ಷರ ಕರ ಮೇವೊ ೌಲ್ ಡಿ",
+            "ಯಾಗಪರ್ಪೂರಡುವಿಯೊಂಗೊಸೂಚಗುತುಂಗಮೌಲಅನುಟಿಯನೆಯ ಟಾ ಅಲ ತೆ",
+            "ರೆಯ ಲೆಶಿಯ್ತುಡತವಮೇಲ ಸಮ ಆರಯಾಸಾಟ್ರಲ್ತೆ ಲಾವಸರು ಕಿದ್ರ",
+        ),
+        &[
+            26, 29, 29, 30, 30, 31, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "ko",
+        concat!(
+            "니다  s 습니다 수 합니다 파일 사용없습니 없습 옵션없음  없음파일 에서  이름 지정",
+            " 문자하지 으로  이  d  출력 실패 명령않습니 않습있습니 설정디렉터렉터리 디렉 잘못",
+            " 있습 co 오류 re이름 일을 입니다파일을하는  프로데이터 작업 형식 데이 표시테이블",
+            " 테이 입력오류 on id  인자실패 옵션 용할 it  를 또는  또는 자료잘못된못된 ",
+            " u  합니ion했습니십시오시오 문자  중  실행er 사용할 을  x  만들 기본 지원",
+            " c  m  서버le 사용자사용하 함수션을 문자열 gi출력  크기 필요al 없는  위치",
+            " 목록 없는베이스 정보 있는 상태con하십시 않음git않음 te 있는  에  변수터리 ",
+            "옵션을자가  다음 po 버전 숫자 inver no형식  해당ate 바이 현재 하위려면 ",
+            "자료형 칼럼nt 이터베터베이 st 시스 시작시스템me 현재  의  항목es  대상 인덱",
+            "res pr바이트인덱스지원하하위  se pa de었습니자를 거나  커밋설정 적절한절한 ",
+            " 로  모드이트 메시지 메시 가 ge 텍스트입력 시합니et  이미ent id원하지이스 ",
+            "스트 인자  부적부적절 대한대한 명령  n 작업 값이 용자 되지  때 션은 리를 해당 ",
+            " 않았 알 프로세 모든모든 ll  너무너무 ect 추가 열 ter지정하 표준ng  처리",
+            "값을 로세스 trse ran 값이 값 용합니st 옵션은숫자 프로그자열 ing 경로sio",
+            "파일이 패키 는  링크로그램패키지 te 경우dat수를 tio 번호표준  wa하고  시간",
+            "지만  인증 여부는데  f 일이 되었습파일에ont 필드 fi 연결 제거rt 이블 만들 ",
+            "이름을름을  he사용 력합니 그룹출력합 내용ers fo크기  삭제 표현 lirea에는 ",
+            "정합니 nutra코드  새  함 ile 아닙닙니다서는 아닙니 값을해야 agefor정할 ",
+            "았습니스를  저장 ve있음 sql스템 트를 일의 ed  t  lasta대상 기본  압축",
+            "위치  할  참조 있음파일의지정 하려면여부 실패했 ch 동작요합니 변환angce 필요합",
+            "보를 ame 가져 선택정보를패했습 반환 시그 무시 di 정렬or  범위페이지 허용어야 ",
+            "pos lu 하나다음 nte 쓸 표시합ck  al목록 ct  구분 각 아어 시그널in ",
+            " 다른 a  들어다른 fil공화국화국 lat 권한 오브오브젝브젝트 최대번째  변경값은 ",
+            " 은 ostql  메모메모리set to lo ta 페이 않은않은 ead 대해이미  공화",
+            " 종료표현식 트리시간 터리를지를  접근 확장않았습rsi 연산연산자번호  키  정의 로그",
+        ),
+        &[
+            22, 23, 26, 28, 29, 29, 31, 32, 32, 33, 33, 33, 33, 33, 34, 34, 34, 34, 35, 36, 36, 36,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45,
+        ],
+    ),
+    (
+        "lt",
+        concat!(
+            "as  pati os is  ne s tas prmasini kaai io us pav",
+            "ių ja  reamastatin nulaiės  sueikklamo  arijaima",
+            "int lamenra ko  išepanepkaima ali koentdin kl fa",
+            "ailantfaitų sti vi na tanau apto raš aturilannta",
+            "prorin vata imoaudvykaviaraperrod maram peyti ti",
+            "istavyikaomapasnt nimda nas sa siangrastrajosran",
+            "linprikallisinėcijiamaus teijoverudoelies yko ve",
+            "inkparaidgrantiinaatyka reinų  tuameveigalaiktai",
+            "iksotiyraba  pirtiestand gaungspa se rapalar nis",
+            "ustiesjunlas ireritieasiprakastur dealair oja da",
+            "albkurmos an yradije tikskalioresidačiųoritisogr",
+            "uotikisislavia paknusnetiau ku skkom sptytila ši",
+            "tatvaraisseningdživieaukdytanktarlų valartiet ba",
+            "odyrisui  poaiteislikersri ngankaamo di menė  in",
+            "mą astlo te  roetiekt gemat į  užlau būpatvadiai",
+            "iti liavebliečijam kičiamet stktoetaakeaciard bu",
+            " trėlivišavaanausiritliaikokliiusžymforertminato",
+            "er vinstekiaenatė  beno na ngt plntekinojiteime ",
+            "leigasktagiaaktjimkslarbespkeleraolirakublkarspu",
+            "ytadojdas greleiškslėiečterdartosntrormsu ms sij",
+            "pubatirogriaonaari jumanume keduoei do  miplarie",
+            "iantorlėl foautso kamkstrsiniobūtešiilulba leikt",
+            "bailygsiotemoro įrtanrų ralašakeikų namiliaujpau",
+            " n ūtiapisimndaenoičijo ionataapltekrijamigtiaka",
+            "stųrma doojeėraijungigeseni nėnėr žyilomailimarp",
+        ),
+        &[
+            23, 28, 29, 29, 29, 29, 30, 31, 31, 31, 32, 32, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "lv",
+        concat!(
+            "as  at nets  paes  s  no izai  va da ieietms  sa",
+            "ta ja staās ijatu kst redat pi ko irumska ir pie",
+            "ies lais  kavietievaiot  viar na lieaksent tijum",
+            " lišanetokumtot marakikama jasiekinā prnes uzda ",
+            "iešlikatntneananas ap lo arrādpāratt pā nadītlai",
+            "ienam slēizvīt cijne nosšu eizaukparus skaaistēl",
+            "saulēg fonavttēībalog se stav atumu osaaralasizm",
+            " un vetranu ukuneimenvērru ontreplu varešuādīla ",
+            " teatsblirinkasuz iemist krra  taun  ja dostsumu",
+            "dev au gaem zdeublti vāspubga ls ērtepuņu ju ver",
+            "tikevaizdkrāevārastītēt  pozīmto rs  vēestaitkon",
+            "derumatarnoriel inekskaiindgu tībriemandartāj ra",
+            "kļūatret val kufordasprost erīpla kļzvēstīlat ri",
+            "ormmērast plokugs rtīkā āciām alsļūdtorrīgtek di",
+            "ūdamaiautvēltatkursa ali sknevzmaantprintsisklis",
+            "tā ojumasiksba lstdesturaladu jamarpēlaveivis kā",
+            "onatasno  devadeid mivirnte biandotnstiievnātrei",
+            "arbējaumeek ku rāsme ursedebalpalbūtres traloni ",
+            "bu  motumsu atbpakaidko ailtslatintoram benedbas",
+            "te nieionējutipodastāēgtgai si falodoga bundaon ",
+            "tristu cokar rāang bans ad nepetisereraatīks ran",
+            "strdokājudaļrmā toeviusēpilārsatosasenofonntalīd",
+            "faiītaterkadapanotaugerslabikoītsorādotlusamarēt",
+            " jāānari ildos iņaaudslaeriīgsss koturaelāotāiju",
+            "emepapokljauošaanukatikāēmajā  slsartbaid rsiekš",
+        ),
+        &[
+            26, 29, 30, 30, 30, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "mr",
+        concat!(
+            "्याया  कर ना s प्र प् स्ही ाही काता न्गले नाहक्ष",
+            "हे िक  आहआहेला  मारा ्य ना का वा म्बचे ची िन्ती ",
+            " क् वा चित्र कोण्यीत  भाकारंग  सं फान्हचिनमा  बा",
+            "रीतगा  अस्लिक्र कु रिील ान ली यन ार्िया कि नि्रि",
+            "षा  न्लिकाइल त्री क्सेक फाइ्ह भाषाषाअन ध्यत्तन्ड",
+            "मध्ार यास मो पा्राब्लकरी मधन्यीता्वाेलेल्यिअनियन",
+            "ास रिप सा ऑफित  के जा सुान्ऑफ  मि विर्वकरा अर्रत",
+            "िपबपब्ते टी रे तर स्त लाकरणोन् अप्षि्ये सि पुइल ",
+            "च्यिमाागारतिस्थ्ग ून साठपूरये रण् गु पर से मु ते",
+            " या्लाांगतेककरतलेलूर् सम मेाठीनी ेन्स्वर्य जुस्ट",
+            "ुआ ोन ्ताठी र्शणे ्रे अकाव  लि्तरमानळी तिम्त  दक",
+            "दक्बा षिणनाव बो तु उत्चििर्ाराकेलउत्स्क्बा्रुनिय",
+            "रि  बुदर्चा श्चिन  अनिक्वे िम रियॅन्ाचेस् वापमाल",
+            " ले बेानाव्हर्मिंगमारनो ्ट  म््व  बिात ारी अवरिक",
+            "ापरुन्ासाितीगो  पू्गा ग्वर ारि येंतरलि  डाअक््रो",
+            "ेक्शी मिकने को चिमालांग्र्गबो  चा्षरिण ेत पश्वेळ",
+            "णी  पॅाप्ेरिरो िश वारघटक्यु डेंगाक्वपेकन्ट तोाची",
+            " शकडा  होग्रंवारमााहि अलरुट आवकिं एकरत िकाुटी्रम",
+            "्री लोालेट्र हाेन ्बेदी ्वेत्ययानिस् सोमर्ेल््गो",
+            " घट आढाइ ोरो उपसा आढळमेनर्थ अंक्यिनाानि दिेवेोते",
+            "तेवयाच ओळ्यक डिकन ढळल दरप्त पोालिरंग्काोर्ेळी्था",
+            " तासुर्टी वरकेज्हामाहेलाब्रगी  पिपुरनिर पशरूप सर",
+            "न्द लु झाेट पॅकीन बारुर्फ्ररू सते्र मी ॅकेराप ब्",
+            "वानसमर्बोुम्वैधैध  गितीलमोकल्ल्सिावा इनामाशियर्क",
+            "्रकिलेपासंत्याकजागवाचर्तक्तवी  वेाक हितहा ेल ेज ",
+        ),
+        &[
+            29, 31, 31, 32, 32, 32, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "nb",
+        concat!(
+            "er  s en kkeet ke foril ikk foing ikte or  er ti",
+            "tilterlerfilver st fing  avre  me en in deom de ",
+            "lleent bres  i stebru ve sk utrukav ed tteere ko",
+            "ig  vaalgvalettellrte soallandne staopp å tt end",
+            "somertinnskrnnendengelindigmenntederrt ker reog ",
+            "ll  på og op maviskri siartmed lipå nt datldital",
+            "rindense gendet kl temmelarser elrerklaan ata et",
+            "avnnav pr fe kaeilukefeige on is  vi seyldgylle ",
+            " lesjojonrenppeal kanlenrivel  hvekstetegn ugten",
+            "vargerldetegers pa laugyndavn strkomrespe jenner",
+            "meristkelangdre armanproeneeriinjuttappar  mi an",
+            "konst ignta areat lge d njeligund dunøkiv  aluk ",
+            " frgt ormtrelg elsengid antargngs talgtilendront",
+            " unkaldu gn lse nøsk ede salagort heskantajerøkk",
+            "førut ivefraommrma naliktan toerniskbliretoveill",
+            "maphvi be haennra rd kst n ateenssig slrditor om",
+            "set blumeelestøtatvedardlutvennin ba noagemelsti",
+            "erdmma dandoordlestidmet ovselnstass føgjeoredel",
+            " sylisesspervellt atuteseltfikhetsisdarildslukk ",
+            "senattharrseske grus asjammoneode admatnetramesi",
+            "latsamsseredgetsesnn ideme premingnaindeve bikje",
+            "kengralastekletelinen byrtirepoldisekesilsbyttar",
+            "barfinans måeldgru ekyttull gj sp nyittestast fl",
+            " poype neholør  faika cotypiernesreligetivlanunn",
+            "era moavsrstrantemtabblenkeiftsvaår arsete tr di",
+        ),
+        &[
+            22, 26, 28, 28, 28, 29, 29, 29, 29, 29, 30, 30, 30, 31, 31, 31, 31, 31, 32, 32, 32, 32,
+            32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "nl",
+        concat!(
+            "en et de  s an  ge deandsta bever va invaner een",
+            "est veingtanndeaar henie ni opbeskenoorte schie ",
+            "iet isis nd tieaanden teereng  on voegehetin  al",
+            "gel ma eederor ngegensteordrderen reten toelders",
+            "eernaaental vooter meuitar rd ekegeberdls ven ka",
+            " stes men nalenel cht coeve enrui wost  aagevdig",
+            "worebrtal pavoelleardbrukanuikatige nt  watekmet",
+            " bi preli uigeeigeondns als dile angens arendam ",
+            " dolij ofachof oereleongwaalinldinenon proit ont",
+            "optat iscindconreg kontekt  ta mopti daop taaall",
+            "re ch aledt aamaaltoechinta la miijdche leaatgeg",
+            "telonereeijkoutijnnst we foid ertrdterwndagesmaa",
+            " zi noovegro babijanswijmapttekkeezeakeld  grfou",
+            "dinap arapense ike om af saran liut slulanij eel",
+            "charijleeig istellerkdaawersiegindelantngsts rs ",
+            "zij sehtert om ite scpaktonume d ortdatjn argeri",
+            "senresodeiesppeoet sylaatijoegakkervinsameketkop",
+            "ect ovintescitvtvokermeruidrsi hoeksndsrin brtee",
+            "volcomreiht ctiik eidna arebelroogetjdenvoke ze ",
+            "chrrondieica tihe ede potramis sius erg soeneber",
+            "ne recndirwiser bu cabredootypkeltroage ch wiek ",
+            "ettrstorm elmanuktiekypegraate eralierbssiaak zo",
+            "repstrislditoepmmepreforarimakinaeraoudbarevorgu",
+            "gumion anjk lukess ac lortemmaft  bovinmeeeincod",
+            "inveme slpe iefssepelatsherroeramoonrgema ernrsc",
+        ),
+        &[
+            22, 27, 28, 28, 28, 28, 29, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32,
+            32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 44, 44, 44,
+        ],
+    ),
+    (
+        "oc",
+        concat!(
+            " dede as  laentla on ionnt menica es lo d  cocio",
+            " l  s ar ficor  pa se reat  ines er perlo  en pe",
+            "da del unacica ichel ns  cana staadara  a queta ",
+            "lasièral traconpasame pr imlic ma fièr chise  di",
+            "estts  qure desge  noun ndien  acia le chassihiè",
+            "indresontiza dote instgeatgeletatactdicafi liibl",
+            "nta trimpandsio alnomrepblevalveresslorserpostil",
+            "ons mealals  ve mosibha alian esplizdinaquautili",
+            "emeuracom poant ut suona soracist afutios mpopre",
+            "ut ue rs  bastr va tainaosslemmatprodoresctennda",
+            "carnteèstolotal apimama  elsa blitorunagranha e ",
+            "ccieraus cacargplamanrrodefarainiparri  cldasic ",
+            "om ranublerrlospubita foivacolsonciactieputivtre",
+            " grlispriorslecncisseecttarifiuesrecet idair  fe",
+            "aricaplhalin aqhar exerialo ererssenaccit finmin",
+            "ntrcalenè siiciomeaçaetassarorap  aurra boid fen",
+            "nèsspaxteterinhomaelsònaumeatsambrsisticamarr pl",
+            " arertplirad tèocu tiuetimelar frntsturlandrecla",
+            "pe cadalhsteefiquaça  amire miticimi o  èslam to",
+            "blaadocorintompsatin èssmpr an pimploliriadenèrs",
+            "empratbarnaspon letèxèxtesactutantipipemarllaapl",
+            "ensormatifor icualdonondrgartilaçervanatonorimod",
+            "ntirasialfraecc opotocònraf tega rma ge virimèct",
+            "hatai ansvarctetamicòsorèl utoseleu segpleme lid",
+            "suscumdatcattiframdocís èmaçambrepacisinicscr or",
+        ),
+        &[
+            22, 24, 27, 27, 27, 28, 28, 28, 29, 29, 29, 29, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31,
+            31, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "or",
+        concat!(
+            "ରେ  କରନ୍ତକୁ  ପାବା ତ୍ରଙ୍ଗାନ  ପ୍ପ୍ରିବା ନାିତ ୍ତୁ ବା",
+            " s  ଚିତୁ ାର କରି ସ୍ିକ  ମାକାର ବିକ୍ଷଣ୍ଡ ଭାପାଇ୍ର ରିବ",
+            "୍ତ୍ାହିନାହ କାହିଁିଁ ଡ଼ିଟି  ସାାଇଁଇଁ  କିଚିତସ୍ତ ନିରଣ ",
+            "୍ଗ ଛି ୍ଯ ଲା  ସମଗୁଡମ୍ବୁଡ଼ଙ୍କଭାଷାଷା ତାସ୍ଥ଼ିକକି ଷା ",
+            "ତି ୍ତରଆନ ାରେିଆନବାରିୟା ଫାର୍ବାରି ସଂାଇଲଧାର କ୍କ୍ରନା ",
+            "ତନ୍ୟା ତ୍ତାନ୍କା ମାନ ବ୍ିତ୍ଫାଇାଲି୍ରି ଆର ପର୍ୟାାମ ରନ୍",
+            "ିକା୍ୟ ାର୍ ଗଣନାମକରନିହ୍ାଙ୍ଚିହଣତନହ୍ନଗଣତ୍ଥାାରାରା ଥିବ",
+            " ସେୂର୍ ଧାପୂରଷ୍ଟ ଉପ କୁ ଏହ୍ତ ରୀ  ଅନ୍ରା କୋରୁ  ୱିେକ ",
+            " ଗୋୋଟି ଜା୍ନଟ୍ଯାର୍ଶନଟ ପରି୍ବାଟିଏିଏ ତା କେତବସ୍ ବୁାଏ ",
+            "ଲି  ପୂରି ଗୋଟଯାଇ ଚାନିରାହା୍ଡୋିକୁସାରିମ୍୍ରକାଇ ୍ରସାରଣ",
+            "ଅଛିରିୟିର୍ ସିଧ୍ଯ ଉଚପାରଥାଏାକୁକ୍ସ ଅବସି  ଉତଉତ୍ ସୂ ତ୍",
+            " ବୋଇଲ ାଯାନ୍ଦଗ୍ର କେ ମଧମଧ୍୍ଷିକରା ପୁହି ହାରୟାନ ମୂ ମି",
+            "୍ରୁତର ଦର୍ାନିହୋଇାଣ୍ ଅସବର୍ମା  ହୋସୂଚସଙ୍ିନ ଣା କ୍ତାଲା",
+            "୍ତି ବେ ନୁଶ୍ଚିଆ ରାଯ ହେ୍ଷରଦକ୍ଥାନର୍ଯ୍ଯ୍ମି ୍ଦ୍ ଦ୍ଏହି",
+            "ିନ୍ଯୋଗସାମୁକ୍ ଦକଲିକଷିଣ ଏକନୁହିଲା୍ରେ ଏବମାର ସର୍ରତାମ୍",
+            "ବୈଧେଲା୍ଟ  ଆକ୍ଗୋର୍ତେତ ତନ  ରେବାକଣ୍ଟର୍ଦ ଅକିଣ୍୍ରଣଅନୁ",
+            "ଦ୍ଧ ସୁିଙ୍ମୂଲୂଲ୍ସର୍ର୍ମଏବଂବଂ ଭାବଅକ୍୍ୱାିଣ ଇଛିମ୍ଭଧିକ",
+            "ସ୍କଦ୍ର ଆବୱିଣୁଟି ଗ୍ ତୁରାଜୃଷ୍ୁରୁ ମୁ୍ଗାାନାପୁରୱା ଗୋ ",
+            "୍କେତାଲରିକୋଙ୍୍ୟକରୁଟଉଚିନିୟରକାଉପରେଶ  ବସବ୍ୟ୍ଚାତ୍ଯାକ୍",
+            "ାତ୍େଟ ୟକ ୍ନ ଆକା ଗୁାସ ଗା ୍ବ  ତେନି ବୀ ଟେକନୋ ଣୀ  ଦର",
+            "୍ଡ ୍ତାାଇବ ଥିଫଳ ଷ୍ଠସମାସମ୍ ଲାମ୍ପକରଣାମାିକରରିତନିମପାଠ",
+            "ଜାପ ମୋରକ୍େନ ାଠ୍୍ୱେକାନରଙ୍ୈଧ ମାଲାରୀରର ାମିାପୋାକ ୋଟେ",
+            "୍ଥିମ୍ନାଇଥପଟି ରଙନୀ କରୁୋଗ ବେ ୍କାପୋଟ୍ବିଦି ସମୟ ମେ ଆଲ",
+            " ଯୋରୂପରସଙ୍ଡାଶ୍ୟଞ୍ଚଇବାକ୍ୱ coପରେବହାପୃଷ୍ଶା୍ବୀୁହଁିନା",
+            "ଲେ ୁଆ  ସହବ୍ରହଁ ନ୍ୟ୍ଟିାଡ଼ଗିନବରେ ଆମରିଆିକ୍ଲ୍ୟଯ୍ୟ ପି",
+        ),
+        &[
+            29, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "pa",
+        concat!(
+            "ਾਂ  ਹੈਰੀ ਹੈ  ਵਿ ਜਾ ਮਾ ਨਾ ਕਾ ਕਰ s ਾਨ ਦੀ  ਸਾ ਲਈਲਈ ",
+            "ੀਂ ਹੀਂ ਨਹਨਹੀਕਾਰਨਾ  ਕੋੱਚ  ਬਾਾਈ ਦਾ ੀਆ ਿੱਚਵਿੱ ਕੀ ਫਾ",
+            "ਅਨ  ਦੀੀਅਨ ਵਾਂਗ  ਟਾ ਨੂ ਪਾਲਾ ਤੇ  ਕਿਤੀ ਾਂਗ ਭਾਾਰ ਲੀ ",
+            "ਨੂੰੱਤਰਰਾ ੂੰ ਾਇਲ ਲਾਕੀ  ਹੋ ਉੱਾਰੀਤਰ ਨੀ ਗਾ ੰਗ ੋਂ  ਲੋ",
+            "ਉੱਤ ਸਕਭਾਸ਼ਾਸ਼ਾੇਕ  ਚਿ ਮਿਫਾਇਾਈਨਇਲ  ਪਰਨਾਂਮੀ  ਮੈਵੇ  ਲਿ",
+            "ਿਆ  ਕੂਰੋ ਰਨ ਾਵਾ ਗਲਈਨ ਕਾ ਾਰਾਾਲ ਵਾ ਾਲਾਿੱਤਤਾ ਗਲਤਜਾ ",
+            "ਿਕ ਰਾਜਦੇ  ਸੈ ਸਿਮਾ  ਮੋ ਦੇ ਵੇਵਾਂਟੇਕਲੇ ਤੋਂ ਡਾਾਜ ਬੀ ",
+            " ਪੂ ਇੰ ਦਾਸੀ ਸਾਈਟਾ ਕਰਨਕਾਨਾਨੀਸ਼ਾ ਬਾ  ਵਰਕੀਤ ਹਾੂਆ ਰੇ ",
+            " ਗਣਗਣਰਣਰਾਮਾਰ ਆਈਾਲੀ ਅਰ ਮੁ ਚਾੱਟ ਣੀ  ਸੇਾਓ  ਨਿਡੋ ਾਨਾ",
+            " ਦਿਾਮਾਕਰੋਿਨ ੱਖਣਜਾਂ ਤੋਕਸਟਪੂਰ ਮੇ ਲੇਚਿੱਰਬੀ ਬਿਾਵੇ ਯਾ",
+            "ਬਾਰਿੰਗਟੀ ੋਨ  ਟੇ ਦੱਾਕਾ ਟੂ ਗੁਨੋ  ਬੋਰਾਨਨੀਆਲਾਂਗੋ ੌਂਗ",
+            "ੈਂਡ ਪੱਦੱਖਮਾਨਂਡ  ਟੋਡਾ ਾਕ  ਸੰ ਅੰ ਟੈਵੇਖੱਕ ਆਂ ਂਟ  ਪਿ",
+            "ਲਤੀਵਰਤ ਇਸ ਫੋਹੀ  ਕੁ ਬੇਵਾਲ ਪੈੇਨ ਿਕਸ ਟਿਡੀ ਕਿ ੰਡੋਾਗਾ",
+            " ਜ਼ਾੈਂਟਰੀਆ ਸੂ ਵੱਾਂਡ ਕੇਸਟ ਮਾਲਕੋ ਬੋ ਹੂਆਕਾਲਾਮ ੱਤੇ ਗਿ",
+            " ਅਤਅਤੇਣਾ ਲਤ ਹਾ  ਕੈਾਟਾ ਮੂਮਾਂਿੰਡੱਲ ਟਿਕੈਕਸ ਇੱ ਪੁ ਸਮ",
+            "ਮੋਰਹਨ ਪੱਛੱਛਮਕਰੀ coਰਾਂ ਤਾਰੂ ਂਗੋ ਚੋਟਰੀੀਆਂੇਖਾਯਾ ਿਕਾ",
+            "ਯੋਗੋਡ ਇੱਕਵਿੰਵਾਰਲੈਂਸਾ ੋਈ  ਇਹਪੁਰਤਰੀ ਅਸਲੋੜਾਪੂਨਾਲ ਸਰ",
+            "ਲਾਈੋਟੇਇਹ  ਪੋੋਰੋਂਗਾਜਾਵਾਪੋੂਰਬ ਅੱ ਡੋਦਿੱਿਟ on ੀਤਾਾਰੇ",
+            "ੋਗ  ਸ਼ਖਣੀਮਿਕਰੰਗੋਰੀੂਰੀਾਉਣ ਅਕਮੁੱਿਨਾਡੇ ਜੀ ਡਰ ਬੇ ਵਾਨ",
+            "ਜਾਣ ਪ੍ ਥਾਲ੍ਹ ਰਾਬਾਈੁੱਲ ਸਟਿਲਾ ਗੂ ਰੰਟਰ ਪ੍ਰੋਮਾ ਨਗੱਧ ",
+            "ਟਾਈ ਸੋਸ਼ਨਸ਼ਾੁਰਾਸੈਂਰਿੰਟੋ ਾਮੀਂਟਰਸਟੇ ਗਾੋਣ ਹੋ ਸਟਾ ਬੂ",
+            "ionਿਸ਼  ਯੂਰਵਾਾਹੀਵੀ ੋਵੇਛਮੀਪੋਟਸ਼ਾਵਸਕਦ ਜੇਮੇਨੇਟ ਈਕਾਇਸ ",
+            "਼ਨ  ਅਲਾਬਾ ਪੇਨਯਾ ਜਿਿੰਟੇਸ਼ਲੋ ਰਾਮਨੂ  ਡੇਆਈਕਟਾਲ ਬੁਤਾਂ",
+            " pa ਹਨ ਜੋ ਖਾਲੋਡੌਰਾਜ਼ਾਪੈਕਟਿਸਟਕਿਸਖਣ ਉਣ  ਦੌਦੌਰਚੋਣਕੇ ",
+            "ਸਾਰਨਾਮੇਰਾਾਸ ਟਮ ਕਰ  ਗਰ ਬਰ ਸੀਇਆ  ਰਿ ਲੂਨੇ  ਡਿਾਲੇਹੋਵ",
+        ),
+        &[
+            30, 31, 31, 31, 32, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 45, 45, 45,
+            45, 45, 45, 45,
+        ],
+    ),
+    (
+        "pl",
+        concat!(
+            "nieie  s  po nianina  pria  wy na zaniaki wan do",
+            "enistaowany lik plch rzeprz jepline go  moów ego",
+            "st ski kościest w możychwiepodjespisany liznaawi",
+            "ej ku la  strzyji do ka ent paostczyożnżna od ma",
+            "ane opowy reać użyikaiencze ka siwy  użczarawdan",
+            " z ciewa je ja owezencji ropronyckie us błzy  i ",
+            "nikprakat intu no  czik ierkonkowikucjaowinazazw",
+            " zncznem ię sięci amiacj tednimenpowyć  typcjopc",
+            " obzmiza dzi d ionfor ar seodawarnegmie wibraicz",
+            "miaywataninieraranak talra ym  alale ludow kllic",
+            " wele onyartstraloorzrozzasść ty ko ków dlustdla",
+            "iet syume taormatajścyst sktawpollogist wazonsu ",
+            " nobierowaln mizytdłoli toroścanańskrtoto apijąc",
+            "oleersnakrmaość zmez ło linianacz ba sp tozan co",
+            " jaachonełowres daiowwejeśl trzapucztwolucoczwe ",
+            " wskluta worandączszyłącchoakotośięcszetówrakąd ",
+            "er grabłą brłądterub mi isa okastakilubić  deano",
+            "nt it ze ielramon uniwidcen fopozsteontjakkreara",
+            "nej be n now bifikbłęłędonajskargtowzwanalodcędn",
+            "wymsaniejiem gizieyfitrzagaeksni an komwerdomzer",
+            "dczmacstę saes wykii lecieplanęcima wypwninantar",
+            "ługmatodnce kcjogrnię byskaalizysobiujeająas  pi",
+            "by że  zonio o at et tępbezeroiwaąceektsekokrono",
+            "ikóecezosnymlneżytntetylcjeymagitwyjlnylisożetra",
+            "tkory repypiedn chweg lookanacte tek szczoieżga ",
+        ),
+        &[
+            25, 25, 28, 28, 28, 30, 30, 30, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33,
+            33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35,
+            35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "pt",
+        concat!(
+            " dede ão do  s  coos  pada  sera entar adoas  in",
+            "ção rees  o  a par esarato comem te ntero não nã",
+            "or coner fic no po domenica umadatrata  fo pr fi",
+            " ca arter li daidoestaçãresstaum eirma dosontal ",
+            "rad tequeveriropos ex emcheel quiforivo en dindo",
+            "no porse veldesnto quvo ia andespichistou io  é ",
+            " e  maomeme heiecintrrquess usíve faarq meuivida",
+            "om rioser op ouprelin moproõesnom suinaura taalh",
+            "mo  veesccarso cadifina lidsãoeramanue  siha spe",
+            " immpo alir ossizapo  aodadsteho iniperormfinria",
+            "umarmasívionao çõeerrtemstrefissíimp vanhais liz",
+            "inhlo álitadinvdefameválali pefallocintere er ap",
+            "testaropçrectivca rroort d ranre ciaade neomplho",
+            "dir sa trdortenargnvántalic ascri navalve dasári",
+            "usaodeageóri lotamaloco pricespecomandeeroactoca",
+            "ersextireendantmerumealtama atinciliitaatilha so",
+            "la ora osual baros toremcorrguemarar chitooi foi",
+            "evetosminicoretnaltilnhoermectmas núonalisscrcio",
+            "encrepncimitaisnúmomoorecifela obetaimetro acupo",
+            "tornco ti stmatle tip x rmichaponlorpacssocaçfer",
+            "pçãúmepod grpadsemcidquaostídacalrão leoteecusa ",
+            " crrimaídens anam id erion ivatuaatoiciadrsaíemo",
+            "itescocam idtalnorgemrtaroctórfilenhtexgurmprref",
+            "ntireg laegutrimodstiramticacoder n pasctehecanh",
+            "segraçracmaiecedrãit odonenmesatuiçãsineleindgum",
+        ),
+        &[
+            24, 25, 26, 26, 27, 28, 28, 29, 30, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31, 31, 31, 31,
+            31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "ro",
+        concat!(
+            " dede te re are s  nuul ea ent setă  în cole rea",
+            " finu ntr inestatesteiun pe refișat ier a trurul",
+            "ză ui în  es dise  prru num laie ișișieele nepen",
+            "la menune polui caerețiuoarcarazăulucă  cuor  un",
+            "nteumeileeazicaterintconnt  acire litorne valați",
+            " arneaectali suist mafic dastatater ată foifiliz",
+            "ii cheun comili stvertul ex opizari ră cu al  și",
+            "trarec al pacțisă tilit preme lic o eruloc va si",
+            "ces utproșteiniutiia erouniuri saoriți oatin str",
+            "nă și tarid  sparaar ta aloact trtreseclor ti er",
+            "bilut au  d forca ei pți ve modinimbecuime meace",
+            "resicilă poaopțatoormratrmativimprarineroaocacat",
+            "icăiondesastpriers săliderist ecice tur im ch af",
+            "ecțcitcteări te taspeperzatchisauina scdatsiu lu",
+            " doșirpeclulrilpor noneclinandparoruon cifite ad",
+            " pumărabiteadiracăompmat x rieonttipeștplitriet ",
+            "ctiortmulmințiera itătabalepta auîncferținrsiept",
+            "simdaciveextstăcutloamplcrimodindput cecuneva ci",
+            "mbo u escbolrimafiesautuermscudă nevumăscrmanert",
+            "uraran loțiind pe ițieraimaimimairellimtenmar ni",
+            " apantndecre ienta pbinsnalpb ai itaregcalebu el",
+            "liseșihetetahei anlatrmitelccefi easunobuitalcep",
+            "rtinic biel ni ult n darmelarguntsuncoroloitieme",
+            "ticieșcodatiincschîntonaide toezăndațiaolumitacc",
+            "nostimcațrepcurblipul cronesup câetectofie urlur",
+        ),
+        &[
+            25, 26, 27, 27, 27, 27, 29, 29, 30, 30, 30, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32,
+            32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "ru",
+        concat!(
+            " не s ть ени по прне ие ия ние в полать заова ко",
+            "ольменый ся ет но ля нияка  длпро растрайл фафай",
+            " вы соанипер наровдляитьвертсяна ватный стльзнны",
+            "го алоии ая ой преки  додалетсраз паов ий удаста",
+            "анност удли  от об исльн пеереоготрось спо каств",
+            "ченестванредельом  сиые зовентленых ое дел репри",
+            "новаетдан d подироиспстила лосметось с комнач им",
+            " иззнателта торустписженранемерамныхерапарстозап",
+            "скауетлючклюьзорав и  ин опныееннарациикатневива",
+            "можамеацироксимказбратанатадерловикайл итеистски",
+            " сете ти ержилиимеаченноатеоши даноеремра шиб ош",
+            "зменогобржнопус тоано ноаза знок ненандволектмер",
+            "ен етрибкпращенока слимвкцимвослинаяденерн тавле",
+            " ил веер конмя ию нойход мо арсле клодифорвоз бы",
+            "ржиальормбливодто ожеолнталей ьнотолыть ус x бка",
+            "ри ожнрмаваеоднда адачитавилогевоука укьны сптно",
+            "по посототратов тирежкийтип вотекми  этиче батву",
+            " мефикимядолко екседеопуле йлаолозадаблтныолжеве",
+            " u ем стены змоозмод ностаткаязде маоволь бытном",
+            "матервовкенечесрасавлслолькомаатоаздтр опеедовыв",
+            "локрекак ываина теиз  фоэтоманумеврересдопва  чт",
+            "чисенонитескалиелеапиьконии coастзанлитмещнийым ",
+            "живратку  су диздаемыциятабори reкодан окинстяет",
+            "оддддеоздущеосл есдносозры ифиомеодеазодатросгра",
+            "нт ещеиморабую скоеготы непоткраженаопрабово  тр",
+        ),
+        &[
+            27, 27, 28, 29, 29, 30, 31, 31, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33, 33, 33, 33, 33,
+            33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "sk",
+        concat!(
+            " s  pr poie je  neniena  naovapre jené ný  súbor",
+            "súbúbo savansa ov iť ia ať  nika  vy v enior re ",
+            " za repriná stamenlo neprovba  akky  chznaani ná",
+            "ujekon doho inaostch stu obpodpouverentoužtor al",
+            " ko sp vestr odom  stte  zo mozovastožnresalene ",
+            "chyhybobrto  a tuppročin ináciiadlatko ebopríoru",
+            "ru mož matavnáz ho ského výanýakoázopla parazený",
+            "vaťepoatnuži vobolku  banamstihod sekovri  znnia",
+            "brareddencieanéslo sy teenétovžnéforýchdnoodn ad",
+            " ro číteľkaz toak lenní oro d ok ateormbo er ta ",
+            "lebciany pornas záprárepybaestriaktodartvoadr de",
+            "drelikistávaari meadanakložej nenodpsprnovnotepl",
+            "sť alooloou voľ arlovrmánt tanoveameoználnká no ",
+            "vorčasam tu ti typovýnačráviesla íkaickrantreven",
+            "tenet nos fo ripračít tyítara  ktnéhoľbžitdpovol",
+            "ilobal trzoble by vý odaívarozon tnýextanísahpís",
+            "čístie zlmieštaoririe no borázramva ikáskorilkci",
+            "upn či byvatžívontpisužírentrapol mipozčeníslalí",
+            " codovaveavido oreveľlíkátostnkomovéter budiami ",
+            "nuttexpovce danké az  di lirojid  kaal enáposite",
+            "me de sárvstovnzadosťmetalierzajúry obs plmá rzi",
+            "lu otopočnezec esákľúľúčika taekt okmu  kľion n ",
+            "aratro uržiaolizly čaanáladlyhtal sonýcyhanteže ",
+            "yť and vsľa ovohalvytarcamisekjú byťiacaktnedoča",
+            "olezorrík má javýsdkaty  štazyumeči ská hebsatla",
+        ),
+        &[
+            26, 26, 28, 28, 29, 29, 30, 30, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 33, 33, 33, 33,
+            33, 33, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "sl",
+        concat!(
+            "na  pr na s  poni ka je pre za izno  da nidatne ",
+            "anjatotekti ote netot jeostnjeprimeninaja sta do",
+            "ki  ko moredke  v portev sestiza ranoračinnikzna",
+            "nosikapodimega če  vrogo stščijenliken in li eni",
+            "lja im allo mog inrazalise egagočov očeta  rako ",
+            " upst ih velupo veska obrabpis spavnizbevejavnak",
+            "to ekakovšteaj odava ilovilenaira oditeevientpro",
+            "te ra  de ma z stoeljave reri ednaviočiem ve  pa",
+            "la ikoitinjarstme  meenopakvrsdol št zn vshod si",
+            "vanik ravbir arljezbinegstran anitavnam kanaploč",
+            "akaeke usot asttra slkazizpovemo naslnida dnoolo",
+            "nevati br tent čenistnajce apa ti kizpianaremeva",
+            "jo ma  enjangrakotbrabliavaisa ba laestnihev od ",
+            "tankonakoovaposeneed ca var bi sk tavnapravnoeli",
+            "oljiceticpov tr okameede saterer  opek on am ica",
+            " sodniljunavijaom vnidelumejemandeznargilaenj če",
+            "venpolemeovnvečtreodndenakereptor nosprledre zap",
+            "vi vezustvrerat n  tokljritovopišgumujeelovedvse",
+            "odple rezjučmedrekor ediram bonskčilak man piet ",
+            "lov uktipslo lometrenateso sporev be isski d dar",
+            "rejverbitizvamonemnovetouka lesteeza coemači iz ",
+            "danoroirianospemi rivnicmestovilnarikrantevitraj",
+            "alntempomrogmožeteba oreogrnalsebajtal žnoljinda",
+            "ar  dr klvorazaapiesevo zavožniškzorponvzeinemer",
+            "ajaoneniz vherivhoresretemostnde brebi adnju  lu",
+        ),
+        &[
+            27, 27, 28, 28, 28, 29, 29, 29, 30, 30, 30, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32,
+            32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "sr",
+        concat!(
+            " прје  по s  да нека  нана  зане да дат јетек из",
+            " коатоње  нистапреоте саза тотостно ња ке ва ори",
+            "ти ред у авани ије одта  мопропрама ањеравистпод",
+            " ременањаја им ујеки писли  доприан исп стса  оп",
+            "те  исзнаом ра ниј вродестиија секорикациј канос",
+            " грива сиакоази и овазивогумог веилиспрлазсе ак ",
+            "екаскиам рисван бртаведнремгу ликвањпос ра биеме",
+            "исако разку  таинаављог назскаве нисдржпозелиодр",
+            "бродноројимавреарарешодаентла еноумегреријораеке",
+            "рансамдељој нак упенаениешк илнов меењеадр арсто",
+            " ос обовеитиеману тањсим d пциопцторатанеистешта",
+            "авнеисграем личспииск x ај то ера ус дирајтраао ",
+            "ани маниквелшкаржаих рекомеитаст авиакаци од ју ",
+            "кљуључчинознчитула судреив спеусп свдиразнектимб",
+            "болстризлмбонскви су злаијуисуваричиитеен еку ба",
+            "оренеп денем сл актресадареизвепокој u  овбитног",
+            " знвљавезирељенираницкаоворрадсноупокраанапотрик",
+            " n веркон спнатмеррамкомтакме  виедоати pbастзор",
+            "навпорће pb  лиајуља аноозоикоишеји реногррстово",
+            "аргавеама укморалиенеаз во ребзапказ унпомексврс",
+            "једсниапиајеештнарвномо дниаци p етаиниазуојевна",
+            " тетваба рењамоктоосн улшавпокше нимтан крео сту",
+            "ењаеде трсуј чи беичктајанд инестојица ри ретјум",
+            " паајтређајазвонт окредбмешазаовиков дуанеденеда",
+            "ле овнствељаримвршблиадаресратрањ coвишеди имвед",
+        ),
+        &[
+            27, 27, 28, 28, 29, 29, 29, 29, 29, 30, 30, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 33,
+            33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "sv",
+        concat!(
+            " inen  s er ntete ingintför föeraterör et de an ",
+            "ar ng ra  annde stion dell tt ta  tininand koill",
+            "änd me enfil kavertil filerom är  i tiosta reon ",
+            " avvänadestellemed ärattellatandaktirad sk soska",
+            "kanent ut atrinaranväed anvdengen maka eriav  ta",
+            "ad varangnt as tigtalngena  viundati senam vaes ",
+            "isttansom pådatga nd menortyck mikomder sa arpå ",
+            "el seral ch  d at felamn pala ettid ngsre strers",
+            "allngail  lä fe omckedes prmmaig ltimatnst ocdet",
+            "ektisklagochiletormn  elntaare flernmanttailtela",
+            "or ranrt  frst neronemer hafor kutarns agg tegt ",
+            " nakonkatinnlislogskrdaraktant silankrigilkuneck",
+            "stärenigtpro väomm baindrat etlla sygaruppärdsa ",
+            " al noällflaormlentabda  darivge typckarde poogi",
+            "keninsparlutängin  berarhar lirma ratec og veans",
+            "fråvärdelmmeessresonssluenstra trstoligtenut  ny",
+            " måivealo bitatvid x per boiv ate cotivättfinsio",
+            "kt astutabarövererkalkadtetvis tock äntmisendreg",
+            "ts lat gi locheontign än övtadrd  lanorbelssl fo",
+            "ggaån rn erv upma rna grndrrsiissit ollrån disig",
+            "tälramnnaumeketardle isasamposaligrasen u nenlt ",
+            "nnelyc exereras chartarkgerse bordrasek tyargapa",
+            "abesk slyminletstöarimal du movallän unsätmbo op",
+            "ker okarnsökammränierkod spkän slkap byalaordsym",
+            "pa bolhan geåstknids gordniittndoetetniextbasläg",
+        ),
+        &[
+            27, 27, 27, 28, 29, 29, 29, 30, 30, 30, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 32,
+            32, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "ta",
+        concat!(
+            "ன் க்கப்பம் த்தல் ட்டது க் ்குின்டிய குில்யன்ும்",
+            "்திிக்்பட்புான்கு  முங்க கோியால்லம்பன்ட பிுக்ர் ",
+            "ுடி s  காட் யா ிய ஸ்  பாய் கா பு தியியனலை ந்தள் ",
+            "்டி்கா செபட் நி்டுன்கார்ங் ்லை மாிப் சாற்றகள்ற்க",
+            " பெரியுத்்பிரி ாக் சி கிகத் உரோன்ாய்்து்பாடு ென்",
+            "ண்டமுட போாங்்கிசு ெக்வில விள்ள்கதனா ்டா மொாட்டி ",
+            "்கள்ட்மா ்கோலா ொழிமொழகோப புிட்ோப்ரு  க் தெகோ ாம்",
+            "படுவா டா ுப்பா ரா ்ஸ்ால்கையியரப் குடுன்ைப்குற கை",
+            "செய்க டுத வாரசுன்ன மேயரசலி உரு மி தி நா்லாகப்டுக",
+            " இடகும து்த ான  டோருவடுமிரிியவனி டெகவ் ்கப தா சே",
+            "வு போ  அடுய்ழி காட தேையாயோ  அம்றுயவிாகாகுயியோபெய",
+            " உளஉள்ுகளர்க படுரு அர்க்னிய அலஸ்டபுக சுாலியர்பின",
+            "்ட ிறத ஒரிகளெய்்போ வடைக்ானி்வா வேகிற வரன்பாஸ் இல",
+            "டிகயான அகபாராரிிரு்ப ுறிுவாபியேண்கானையொயொபொப் மோ",
+            "ருகறது இநகளைெயரெர்ுள்ோக்இந்வரி்டெக்யெற்க்ரதி  மத",
+            "யல்யினளை க்ஸ பய மெடக்திரசினோர்ரிகபிர்டதகம்பி பயன",
+            "பானுமா்தாச்சடையபிகேற்தை ்சிெயலஒருமி வேண்ரிஇல்கி ",
+            "னோ  இரருபோல் கரசியமானகன்ிசை அனமிகியிறும லாபிழிழை",
+            "திபகை வடகழுதஸ்கசி ்யு நகாக தானமாரமுன மறப்ரதெற கொ",
+            "துகெட்லம் யார்பயில்ராஅல்ானா ஃபரோ மதிபிடடதுஷ் போத",
+            "ுகிட்சமேறயாக ஸ்ாவ் th வகரம்போடிழக அசகிழுறைதில பொ",
+            "ழை வாகோவ்்தைல்பேர்யை  ஏறிம்வகைோம்குராது்யாடோ  பக",
+            "ஏற் டிசெனன்ஸ சோtheபிலிஸ்ட்ரழக்ிங்தினபாட வெ எழசிக",
+            "்டோேன்திகபுரகார coினைடம் லிோங்எழு்பெபெரஸி ்னாரை ",
+            "ல்கலியாபாாலா டா்பைெல்வானவிட ப்உரைுகுிலியாத தவுரி",
+        ),
+        &[
+            26, 27, 27, 28, 29, 29, 30, 30, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33,
+            33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "th",
+        concat!(
+            " s ไม่ที่การ ไมฟ้มแฟ้ื่อข้อของม่สมารกเกอร์ามาเกจ",
+            "แพกพกเารถสามได้ชื่รับีย ่สาใช้ั้งอง ้อม้องผิดมูล",
+            "อมูต้อภาษาษาวามควารัฐตั้ค่าาร์ร์  ภา เกลาดิดพดพล",
+            "พลา้ม เป็ป็นารณเกิาน กิดตัวสาธ สาาธาธารด้ มือรณร",
+            "ณรัหรัอผิ้อผ่าง่านสำหำหรกจ ลืองกาเลื่มีรียับ ิดข",
+            " อาแบบรือือกษามียนรายถูกามืขณะอารรูปดขุ้่นอยูาง ",
+            "ร์เรี หรืยู่ทอร้าง แฟว่ารุ่และิดตติด อัดตับรา่อง",
+            " กาอก ่ได coาดขาณ อรีต์ เรีำหนหนดระบองกอ่าม่มองแ",
+            "กำหเรกดเรังกลังม่ร่ใชารารกทปิดลูกไดเัน จากือ  ที",
+            "สดงนี้กับั่ง่า ่วน เปกทอวันแสดารเม่ไ่อแองรดขณส่ว",
+            "งแพรองร็จสถา เซัวเี่ย มี ชืันตสำเนียกา นต์เร็อน ",
+            "ำเรโบร่สำร์กต่อกลาเนี็จ ราณาย ภาพ d ให้นา ล้วูล ",
+            "นที่อยประเปลแล้ กำเมน่อน ไดกันิร์ิด ่น ่ไมูกต ต้",
+            "ลายออก แสเกา แพร้าะวัแต่นด์ตะววเลโดยionถาน้ง ี่ไ",
+            "สร้on ลาง มาบรรซียเครยกาึ่งยังขียรถเ่ถูเขี th้ว ",
+            "หม่ช้ กต้verconโก ายก่ง งแฟ de เขใหมอัก reเซีบกา",
+            " ตันแฟบแฟม่ใ ราด้วสั่โปร จะ่อ ครืงานด์ ีกาางเเปิ",
+            "ม่ถ้วยเขตข้าูปภปภารา นิด ขอ แลหน่ ข้ม่พทาง อิู่ ",
+            " เพรื่งรั เอะบุ หรล่ากำลำลั่รอกินิมพเบีบียาด ชนิ",
+            "ม่ าร ักขนข้ลี จักมีกอควจัดูปแงไมคุณกจทารบรทั ซา",
+            "ี่จปแบ้ายใช่จทีาตัลิงยายขระแลนลนดส์ ิก มนต แตมพ์",
+            "รรททัดกขรุ่มผสมลี่พิมเพืพื่ พบารส่เกกาะัด ้อคน้า",
+            "ก่อี่เูกผกผสsioเชีชียหัวหน้่จะไว้ ในปลี่ยนคำส้น ",
+            "ปัญรแกย่า ใชนขอ่าตำสัิน ัง เลขอย่ยชืageร์นย์ ียง",
+            "นาด็น id nt กระนอรารีใต้ขึ้ึ้น ค่สม บบ enter  เบ",
+        ),
+        &[
+            26, 28, 31, 31, 31, 32, 32, 32, 32, 33, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35,
+            35, 35, 35, 35, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45,
+        ],
+    ),
+    (
+        "tr",
+        concat!(
+            " s laneri biin ir an en lar deama doleranı ba ya",
+            "er ara ka vebirileya  gearı sasyados içosy olası",
+            " kuiçiiliri or lamyor seçin dile lenak eçesı ini",
+            "ar ma lladeğ taklealaıladı ullsteeğienendakulli ",
+            "lemde ni da esi ar ha be pasi ndealıeklbilır  ye",
+            " al koçeradı buetiınıemerı indrinraklirenitergeç",
+            " giayını atame lı  soek ın anaolaşlene  iltırdır",
+            "tandeneliandiz  ad ayla iri maayamadik iyodi ve ",
+            "ersrsi daverbeltirbaşyenyazman gösindilsizistınd",
+            "ereki seçuruineatıtar siişlsınhatsay yoizikarta ",
+            "lma iş boırırilna  reyaral mi et  andanrın heok ",
+            " teyal d sondizıyoit çıknınrmaedilleyasnamangrla",
+            "likeleçennımision ca ısıılıulaninğişemişti önğer",
+            " çıbu rılkenrleeğenekmasleş kiunuamızinparerlnce",
+            "re ce  sıyanyer sü lidiroluil ketği linürü iskte",
+            " çaış eyeeya inse ng  mevey tüel satyokilmldınıl",
+            "germalmeybağ uyiğiabi condırannum neonuturti mak",
+            "ndi ettı  gürulim ellnmeresımlgirirdra un am şar",
+            "nlayap ekazıralut te endedealtulu noarltemaktkay",
+            "olmrmetekağliş unastaenmlisklaıcıakikaladaarisür",
+            "may stimlirtem ey aşaçalrdimiyktıız cı lışmarimi",
+            "ştıekitalınamleırmıktmışmesdesrtinu  zagündurüm ",
+            "tiltürğlaaca famedestüzeiçedeklu  miakemıymiş en",
+            "gitbulığıluşsıznesgörğil lambo duaynol eks vanah",
+            "ecelmerliştu chaz arguştdu östıştpakrektik pruma",
+        ),
+        &[
+            28, 30, 30, 30, 31, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32, 33, 33, 33, 33,
+            33, 33, 33, 33, 33, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34,
+            34, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43,
+        ],
+    ),
+    (
+        "uk",
+        concat!(
+            " не s ти нняня  поне  вика  заенний увана  прати",
+            "аннванно пер коере накорся ів  довідькаля знаськ",
+            "ориистстаогонийні го  ро пепро у  пі фааноитиайл",
+            "файдля длалорозих танрис ст ма вівик паченико мо",
+            "начпововить ватістаченихарастодалено си з  таки ",
+            "ани даданентльн бувда рерамтовпритрипомомипід d ",
+            "стиновмож каверлос зн вдпар якосяписостії до мен",
+            " обленми оваіднказтьсьсямилає ва ореилком  ти ін",
+            "денандметим страмеку зап вк базміног секомла вол",
+            "каток овівкаредільженальсимектрядтор спреказащо ",
+            "типконовуримстуціїра етрія опе чиабоути арманбо ",
+            "имвта анімвоід рес мі абапизанераворйл ділковчас",
+            "вивті іка ря клою  щори мінробназаціазврівовобут",
+            "ідоєтьритматтволкаожнхідкціктнму  всзділів чаозд",
+            "ранмаєекоміс є домтувстьої за ома x  веалеядклу ",
+            "днонекизнтал u ненниматаівнатоій форсу  і потиво",
+            "коднсьту  опзавлі клюпра сафіключормтнивстевідже",
+            "читдовствсерреноро ноклавийтівпор в ому біномсув",
+            " дівувавану трі змастло влепівналамиотрнтаайтрма",
+            "йсьаблтатстіує ше нев теанаексесу фопособрас ан ",
+            "ланласво тектрапусжнанепічнмалрегтаб кінемравлог",
+            "ідтовлюва меви мовідподуорі беоліто ершумевністе",
+            "агаалаолотр  n овналіивеьниифіці одивизякщкщоика",
+            "мірестдтримаідкозм coнаненинь лок слма  денніока",
+            "докблиір разкритимвилднатисканйлаливле ецьаргльк",
+        ),
+        &[
+            28, 28, 28, 28, 28, 29, 30, 30, 30, 31, 31, 31, 31, 32, 32, 32, 32, 32, 33, 33, 33, 33,
+            33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35, 35, 35, 35, 35, 35, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36, 36, 36,
+            36, 36, 36, 36, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "vi",
+        concat!(
+            "ng  th ch kh s ông trhônnh khô ti phin  nhên ập ",
+            "tin giác  cá tậtậpcác đư ngch hi ỗi ượcợc có  có",
+            "thểhể iếnần đượho  sốsố tiế và hi địùngới ết ong",
+            " qu làchuục ối cho củcủaủa ột  lỗlỗirontroịnhđịn",
+            "khiếng liển  sara ại  mộmột dùthadùn tê lệtênay ",
+            "là chỉọn họnchọhiệ rahỉ ầu mục mụ tạký thưiệuệu ",
+            "iênhư  ký coào  bảhayang đầặc  bi ho ki viiểuểu ",
+            " đãđã ải đầuất ạng vớvới kế tùtùyùy bảnvà on kết",
+            "ặp ến  d  độ tự đặìnhtự ếu phầ đốhầnai  nàđối đi",
+            "íchời an  hợhợpợp ao hiểườnờngản gặp gặvàoộng ha",
+            "nhậit ện áchài iệnảnhều òng bộ tư đểđể bộ iều bỏ",
+            "bỏ  từày  lạ cảặt  reưa ghikiể bịbị  đachi ghhuy",
+            " dò đổuyểyển in x đặttừ coniểnổi lạiphảdònhànhải",
+            "đổiam kholệ ạn anhhị chư dạdạnọc  cầànhhiếgianày",
+            "ượnốngợngân liệệnhlện xuthịau ungtheảngtrưthứươn",
+            "ơngấu ạo tạoái  mate eo ấy trìoặctra a heoật git",
+            "nguthôrìnhân migiáthihưasaiánhhoặnhưền trịquarị ",
+            " dụức ây việ ba cấ u đọc đọẫn iá đan caàm áo ắt ",
+            "óa ướcớc  tí to sửúc cầntượua ụngdụniết lathàđộ ",
+            "êu  lưhiềrợ trợ diran tì nếer uỗinếuhuỗ mã b át ",
+            "mã cản da cuuấtrộnxuấ báiếuận hế  pale  vịthờhời",
+            "vị  loệc ínhiệctìmìm liê stngưằng c phâ n id  lầ",
+            "lầnphibáohệ hiêhỗ ực ham bạes  dữdữ trêchứ hệrên",
+            " tahứcdẫnơn quy dẫ nahận đế mà lutiêion hỗ deói ",
+            "saubạntạiép àn nt  hà ở  gó bằbằnse et làm dấuy ",
+        ),
+        &[
+            20, 24, 25, 25, 26, 27, 27, 27, 27, 28, 28, 30, 30, 30, 31, 31, 31, 32, 32, 32, 32, 32,
+            32, 33, 33, 33, 33, 33, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 34, 35,
+            35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 35, 36, 36, 36, 36,
+            36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 36, 37, 37, 37, 37, 37, 37,
+            37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37, 37,
+            38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38, 38,
+            38, 38, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39, 39,
+            39, 39, 39, 39, 39, 39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40, 40,
+            40, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44,
+        ],
+    ),
+    (
+        "zh",
+        concat!(
+            " s  无法文件  d  無法 coit 使用  re檔案  giid  使用 文件giton ",
+            " n er le ion 如果选项  c  x  u conll  不能 选项me  pa de",
+            " trse vernt 失败  无效无效的錯誤  檔案es  prile st選項 名稱  正在",
+            "fil 或  選項 fi模式 entte et  f 错误 目录  sh inhel参数  no",
+            "ng  和  chal ect se m ate p ge  po id失敗 类型 警告 or ",
+            "sioin ont名称  heing 無效 vest ame lirt 無效的大小  te a ",
+            "traageliners格式  fo 警告resang lastacomtor 字符字符  ma",
+            "che t ed rantiofor sinte存在  diterck 目錄 ce namdat",
+            "pro 没有 al lu字符串使用者信息 rsict  l lu 提交  b  在  未知 i ",
+            "不支持 格式all lo命令 服务器版本 pe 数据库om re  ex共和國ort和國  不是",
+            "指定  pl 显示 toell taextsetno ype 不支dirstrrl she软件包",
+            "typead指定的de  需要at 列表  mi 设置用法 的文件 unam ue  用法int",
+            " 指定ack daime 顯示 bucod 命令 沒有ralseransbugine不存在 e ",
+            "rea 参数 en nutench lococklan pireflatto odeplu r ",
+            "th sh 表达式ura分支 xt rg 的檔案 v  模式位置 ve ld  naase設定 ",
+            " 設定lur ur htist 忽略 mstex bils gs  typatenc語系 ad ",
+            "eamne ry  版本發生錯生錯誤gid su y 索引  失败ato 默认 o repan ",
+            " orngu ge meguats feruag 错误nd 对象  is so baelpmsg",
+            "设置 por文件名pre使用的是一个時發生oretatpacss 指令 ps pos h  ca",
+            "引用 ess zh 中的httttptruormjecbleostix cha状态 lp ign",
+            "字元  或者ize出错 ut  crbas无效 ind 是否 預設标准输输出 tab位元組符串 ",
+            "ese ad ustar 失敗 ar忽略 無效 未知的ast 数据out效的 recip par",
+            "有效的ff  aphar ldze 類型 操作 heaget字串 emarsetre文件的找不到",
+            "chi不支援 的 destf ati 第  指令ertinudin scuniuse syurl",
+            "ereain wity orgrs addns ail 名称物件 lisromtriteszh ",
+        ),
+        &[
+            22, 34, 34, 34, 36, 36, 36, 37, 37, 37, 37, 38, 38, 38, 38, 38, 38, 39, 39, 39, 39, 39,
+            39, 39, 39, 40, 40, 40, 40, 40, 40, 40, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41,
+            41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 41, 42, 42, 42, 42,
+            42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 42, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44,
+            44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 44, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45, 45,
+            45, 45, 45, 45, 45, 45, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46, 46,
+            46, 46, 46, 46, 46, 46, 46, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47,
+            47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47,
+            47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47,
+            47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 48, 48, 48, 48,
+            48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48, 48,
+            48, 48, 48, 48,
+        ],
+    ),
+];
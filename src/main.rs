@@ -50,25 +50,58 @@
 //!
 //!  12. `main::run()` exits when all `main::slice` s are processed.
 
+// Lints of toolchains newer than `rust-version`, flagging code that predates
+// them.
+#![allow(unknown_lints)]
+#![allow(clippy::empty_line_after_doc_comments)]
+#![allow(clippy::byte_char_slices)]
+#![allow(mismatched_lifetime_syntaxes)]
+
 extern crate encoding_rs;
 
+mod auto_encoding;
+mod char_ranges;
+mod charmap;
+mod config;
 mod finding;
 mod finding_collection;
+mod format;
+mod grep;
 mod help;
 mod helper;
 mod input;
+mod language;
+mod language_profiles;
+mod merge;
 mod mission;
+mod mojibake;
 mod options;
+mod plausibility;
+mod plausibility_tables;
+mod pre_search;
 mod scanner;
+mod script_filter;
+mod stats;
+mod str_stats;
+mod unique;
+mod word_list;
 
+use crate::finding::Finding;
 use crate::finding::OUTPUT_LINE_METADATA_LEN;
 use crate::finding_collection::FindingCollection;
+use crate::finding_collection::PendingFindings;
+use crate::format::DELIMITED;
 use crate::help::help;
+use crate::input::input_is_stdin;
 use crate::input::Slicer;
+use crate::merge::merge_findings;
+use crate::mission::Mission;
 use crate::mission::MISSIONS;
+use crate::options::OutputFormat;
 use crate::options::ARGS;
 use crate::scanner::ScannerStates;
-use itertools::kmerge;
+use crate::stats::Stats;
+use crate::unique::Unique;
 use scoped_threadpool::Pool;
 use std::fs::File;
 use std::io;
@@ -105,15 +138,43 @@ fn run() -> Result<(), anyhow::Error> {
             let mut output = match ARGS.output {
                 Some(ref fname) => {
                     let f = File::create(Path::new(fname))?;
-                    // There is at least one `Mission` in `MISSIONS`.
-                    let output_line_len =
-                        2 * MISSIONS[0].output_line_char_nb_max + OUTPUT_LINE_METADATA_LEN;
+                    // Every `Mission` may have its own line length: make room
+                    // for the longest.
+                    let output_line_len = 2 * MISSIONS
+                        .iter()
+                        .map(|m| m.output_line_char_nb_max)
+                        .max()
+                        .unwrap_or(0)
+                        + OUTPUT_LINE_METADATA_LEN;
                     let f = LineWriter::with_capacity(output_line_len, f);
                     Box::new(f) as Box<dyn Write>
                 }
                 None => Box::new(io::stdout()) as Box<dyn Write>,
             };
-            output.write_all("\u{feff}".as_bytes())?;
+            // Only the text format starts with a byte order mark, delimited
+            // formats with a header row.
+            match ARGS.format {
+                OutputFormat::Text => output.write_all("\u{feff}".as_bytes())?,
+                OutputFormat::Jsonl => (),
+                OutputFormat::Csv | OutputFormat::Tsv => DELIMITED.write_header(&mut output)?,
+            }
+
+            // With `--unique` findings are aggregated and printed at the end.
+            let mut unique = ARGS
+                .unique
+                .map(|order| Unique::new(MISSIONS.len(), order, ARGS.unique_per_encoding));
+
+            let mut stats = ARGS.stats.map(|_| {
+                let input_file_ids = if input_is_stdin() {
+                    vec![None]
+                } else {
+                    (1..=ARGS.inputs.len()).map(|i| Some(i as u8)).collect()
+                };
+                Stats::new(MISSIONS.len(), input_file_ids.into_iter())
+            });
+
+            // Findings kept back until the strings held by the scanners end.
+            let mut pending = PendingFindings::default();
 
             'batch_receiver: loop {
                 // collect
@@ -129,14 +190,42 @@ fn run() -> Result<(), anyhow::Error> {
                         _ => break 'batch_receiver,
                     });
                 }
-                // merge
-                for finding in kmerge(&results) {
-                    finding.print(&mut output)?;
+                if let Some(s) = stats.as_mut() {
+                    s.add_batch(&results);
                 }
+                pending.push_batch(results);
+                let mut print = |finding: &Finding, also: &[&Mission]| match unique.as_mut() {
+                    Some(u) => u.add(finding),
+                    None => Ok(finding.print(&mut output, also)?),
+                };
+                // merge
+                pending.release(|findings| {
+                    match ARGS.merge {
+                        None => {
+                            for &finding in findings {
+                                print(finding, &[])?;
+                            }
+                        }
+                        Some(policy) => {
+                            for (finding, also) in merge_findings(findings, policy) {
+                                print(finding, &also)?;
+                            }
+                        }
+                    }
+                    Ok::<_, anyhow::Error>(())
+                })?;
+            }
+            if let Some(u) = unique {
+                u.finish(|r| Ok(r.print(&mut output)?))?;
             }
             //println!("Merger terminated.");
-            output.write_all(&[b'\n'])?;
+            if ARGS.format == OutputFormat::Text {
+                output.write_all(&[b'\n'])?;
+            }
             output.flush()?;
+            if let (Some(mut s), Some(format)) = (stats, ARGS.stats) {
+                s.print(&mut io::stderr(), format)?;
+            }
             Ok(())
         });
 
@@ -188,8 +277,11 @@ fn main() {
 mod tests {
     use crate::finding::Precision;
     use crate::finding_collection::FindingCollection;
+    use crate::mission::Mission;
+    use crate::mission::MissionDef;
     use crate::mission::Missions;
-    use crate::options::{Args, Radix};
+    use crate::options::{Args, OutputFormat, Radix};
+    use crate::scanner::tests::with_char_spans;
     use crate::scanner::ScannerState;
     use itertools::Itertools;
     use lazy_static::lazy_static;
@@ -203,10 +295,39 @@ mod tests {
             list_encodings: false,
             version: false,
             chars_min: Some("5".to_string()),
+            chars_max: None,
             same_unicode_block: true,
+            max_scripts: None,
+            mojibake: false,
             grep_char: None,
+            script_filter: None,
+            grep_pattern: None,
+            regex: None,
+            invert_match: false,
+            keyword: None,
+            min_score: None,
+            min_entropy: None,
+            max_run: None,
+            min_distinct: None,
+            word_list: vec![],
+            min_word_ratio: None,
+            identify_language: false,
+            language: None,
+            profile: vec![],
+            config: None,
+            auto: false,
+            ignore_case: false,
+            ignore_diacritics: false,
+            nfkc: false,
             radix: Some(Radix::X),
             output: None,
+            format: OutputFormat::Text,
+            columns: None,
+            delimiter: None,
+            merge: None,
+            unique: None,
+            unique_per_encoding: false,
+            stats: None,
             output_line_len: Some("30".to_string()),
             no_metadata: false,
             counter_offset: Some("5000".to_string()),
@@ -218,12 +339,39 @@ mod tests {
     lazy_static! {
         pub static ref MISSIONS: Missions = Missions::new(
             ARGS.counter_offset.as_ref(),
-            &ARGS.encoding,
+            &ARGS
+                .encoding
+                .iter()
+                .map(|e| MissionDef::EncOpt(e.clone()))
+                .collect::<Vec<_>>(),
             ARGS.chars_min.as_ref(),
+            ARGS.chars_max.as_ref(),
             ARGS.same_unicode_block,
+            ARGS.max_scripts.as_ref(),
+            ARGS.mojibake,
             ARGS.ascii_filter.as_ref(),
             ARGS.unicode_block_filter.as_ref(),
             ARGS.grep_char.as_ref(),
+            ARGS.script_filter.as_ref(),
+            ARGS.grep_pattern.as_ref(),
+            ARGS.ignore_case,
+            ARGS.ignore_diacritics,
+            ARGS.nfkc,
+            ARGS.regex.as_ref(),
+            ARGS.invert_match,
+            ARGS.keyword.as_ref(),
+            ARGS.min_score.as_ref(),
+            ARGS.min_entropy.as_ref(),
+            ARGS.max_run.as_ref(),
+            ARGS.min_distinct.as_ref(),
+            &ARGS.word_list,
+            ARGS.min_word_ratio.as_ref(),
+            ARGS.identify_language,
+            ARGS.language.as_ref(),
+            ARGS.merge.is_some(),
+            ARGS.format,
+            ARGS.columns.as_ref(),
+            ARGS.delimiter.as_ref(),
             ARGS.output_line_len.as_ref(),
         )
         .unwrap();
@@ -303,4 +451,44 @@ mod tests {
         let f = iter.next();
         assert_eq!(f, None);
     }
+
+    /// Tests `--merge` with findings of both missions from the same bytes.
+    #[test]
+    fn test_merge_findings() {
+        use crate::merge::merge_findings;
+        use crate::options::MergePolicy;
+
+        // `--merge` needs the exact spans of the findings.
+        let missions: Vec<&'static Mission> = MISSIONS.v.iter().map(with_char_spans).collect();
+        let scan = |inp: &[u8]| {
+            let mut ss0 = ScannerState::new(missions[0]);
+            let mut ss1 = ScannerState::new(missions[1]);
+            vec![
+                FindingCollection::from(&mut ss0, Some(0), inp, true),
+                FindingCollection::from(&mut ss1, Some(0), inp, true),
+            ]
+        };
+
+        // Both missions find the same ASCII string.
+        let resv = scan("abcdefg".as_bytes());
+        let group: Vec<_> = resv.iter().kmerge().collect();
+        assert_eq!(group.len(), 2);
+        let merged = merge_findings(&group, MergePolicy::Same);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].0.s, "abcdefg");
+        assert_eq!(merged[0].0.mission.mission_id, 0);
+        assert_eq!(merged[0].1.len(), 1);
+        assert_eq!(merged[0].1[0].mission_id, 1);
+
+        // The ASCII mission finds parts of the UTF-8 strings.
+        let resv = scan("abcdefgÜhijklmn€opÜqrstuvwÜxyz".as_bytes());
+        let group: Vec<_> = resv.iter().kmerge().collect();
+        assert_eq!(group.len(), 5);
+        assert_eq!(merge_findings(&group, MergePolicy::Same).len(), 5);
+        let merged = merge_findings(&group, MergePolicy::Longest);
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0].0.s, "abcdefgÜhijklmn");
+        assert_eq!(merged[1].0.s, "opÜqrstuvwÜxyz");
+        assert!(merged.iter().all(|(_, also)| also.is_empty()));
+    }
 }
@@ -0,0 +1,128 @@
+//! Collapse findings reported by more than one mission (`--merge`).
+//!
+//! `kmerge()` sorts the findings of all missions by position, but two
+//! missions finding the same string, e.g. `-e ascii -e utf-8` in ASCII
+//! text, both report it. Findings are the same, when they were decoded from
+//! the same input bytes `Finding::start` and `Finding::byte_len`. As strings
+//! are wrapped after `--output-line-len` characters, all missions must share
+//! it, see `Missions::new()`.
+
+use crate::finding::Finding;
+use crate::input::ByteCounter;
+use crate::mission::Mission;
+use crate::options::MergePolicy;
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Merges `findings`, the findings of all missions of one batch in
+/// `kmerge()` order. Returns the findings to print in the same order, each
+/// with the other missions that reported it too.
+pub fn merge_findings<'a, 'b>(
+    findings: &[&'b Finding<'a>],
+    policy: MergePolicy,
+) -> Vec<(&'b Finding<'a>, Vec<&'static Mission>)> {
+    let is_contained = match policy {
+        MergePolicy::Same => vec![false; findings.len()],
+        MergePolicy::Longest => contained_in_longer(findings),
+    };
+
+    let mut merged: Vec<(&Finding, Vec<&'static Mission>)> = Vec::with_capacity(findings.len());
+    // The index in `merged` of every distinct finding.
+    let mut same: HashMap<_, usize> = HashMap::new();
+    for (&f, is_contained) in findings.iter().zip(is_contained) {
+        if is_contained {
+            continue;
+        }
+        let key = (
+            f.input_file_id,
+            f.start,
+            f.byte_len,
+            f.s,
+            f.s_completes_previous_s,
+        );
+        match same.entry(key) {
+            Entry::Occupied(e) if other_mission(f, &merged[*e.get()]) => {
+                merged[*e.get()].1.push(f.mission);
+            }
+            Entry::Occupied(_) => merged.push((f, Vec::new())),
+            Entry::Vacant(e) => {
+                e.insert(merged.len());
+                merged.push((f, Vec::new()));
+            }
+        }
+    }
+    merged
+}
+
+/// For every finding in `findings`: `true` if another mission's finding was
+/// decoded from more input bytes including its own and contains its string.
+fn contained_in_longer(findings: &[&Finding]) -> Vec<bool> {
+    let end = |f: &Finding| f.start + f.byte_len as ByteCounter;
+    // Candidates containing a finding come before it in this order.
+    let mut order: Vec<usize> = (0..findings.len()).collect();
+    order.sort_by_key(|&i| {
+        let f = findings[i];
+        (f.input_file_id, f.start, Reverse(end(f)))
+    });
+
+    let mut is_contained = vec![false; findings.len()];
+    // The findings overlapping the current one.
+    let mut open: Vec<usize> = Vec::new();
+    for i in order {
+        let f = findings[i];
+        open.retain(|&j| {
+            findings[j].input_file_id == f.input_file_id && end(findings[j]) > f.start
+        });
+        is_contained[i] = open.iter().any(|&j| {
+            let g = findings[j];
+            g.mission.mission_id != f.mission.mission_id
+                && end(g) >= end(f)
+                && g.byte_len > f.byte_len
+                && g.s.contains(f.s)
+        });
+        open.push(i);
+    }
+    is_contained
+}
+
+/// `true` if none of the missions of `m` reported `f`.
+fn other_mission(f: &Finding, m: &(&Finding, Vec<&'static Mission>)) -> bool {
+    m.0.mission.mission_id != f.mission.mission_id
+        && m.1.iter().all(|o| o.mission_id != f.mission.mission_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::finding_collection::FindingCollection;
+    use crate::scanner::tests::MISSION_ASCII;
+    use crate::scanner::tests::MISSION_UTF16LE;
+    use crate::scanner::ScannerState;
+    use itertools::Itertools;
+
+    #[test]
+    fn test_merge_findings() {
+        // The same string in ASCII and in UTF-16LE, in the same decoder
+        // window.
+        let mut input = b"\0\0Password1234\0\0".to_vec();
+        input.extend("Password1234".encode_utf16().flat_map(u16::to_le_bytes));
+        input.extend(b"\0\0");
+
+        let mut ss0 = ScannerState::new(&MISSION_ASCII);
+        let mut ss1 = ScannerState::new(&MISSION_UTF16LE);
+        let resv = [
+            FindingCollection::from(&mut ss0, Some(0), &input, true),
+            FindingCollection::from(&mut ss1, Some(0), &input, true),
+        ];
+        let findings: Vec<_> = resv.iter().kmerge().collect();
+        assert_eq!(findings.len(), 4);
+
+        // They were decoded from different bytes: nothing is merged.
+        for policy in [MergePolicy::Same, MergePolicy::Longest] {
+            let merged = merge_findings(&findings, policy);
+            assert_eq!(merged.len(), 4);
+            assert!(merged.iter().all(|(_, also)| also.is_empty()));
+        }
+    }
+}
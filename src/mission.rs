@@ -6,21 +6,39 @@
 
 extern crate anyhow;
 extern crate encoding_rs;
+use crate::auto_encoding::AUTO_ENCODINGS;
+use crate::char_ranges::CharRanges;
+use crate::charmap::CharMap;
+use crate::config::{MissionConfig, CONFIG, FILTER_ALIASES};
+use crate::grep::GrepPatterns;
+use crate::grep::MatchMode;
 use crate::input::ByteCounter;
+use crate::language;
+use crate::language::LANGUAGE_SEPARATOR;
+use crate::options::OutputFormat;
 use crate::options::ARGS;
 use crate::options::ASCII_ENC_LABEL;
 use crate::options::CHARS_MIN_DEFAULT;
+use crate::options::CHAR_MAP_ENC_PREFIX;
 use crate::options::COUNTER_OFFSET_DEFAULT;
+use crate::options::DOUBLE_UTF8_ENC_LABEL;
 use crate::options::ENCODING_DEFAULT;
 use crate::options::OUTPUT_LINE_CHAR_NB_MAX_DEFAULT;
 use crate::options::OUTPUT_LINE_CHAR_NB_MIN;
+use crate::plausibility;
+use crate::pre_search::PreSearch;
+use crate::script_filter::ScriptFilter;
+use crate::str_stats::StrStatsFilter;
+use crate::word_list::{WordList, WORD_RATIO_DEFAULT};
 use anyhow::{anyhow, Context, Result};
 use encoding_rs::*;
 use lazy_static::lazy_static;
+use regex::Regex;
 use std::cmp;
 use std::cmp::{Eq, Ord};
 use std::fmt;
 use std::ops::Deref;
+use std::path::PathBuf;
 use std::process;
 use std::str;
 use std::str::FromStr;
@@ -33,6 +51,8 @@ pub const UTF8_FILTER_ASCII_MODE_DEFAULT: Utf8Filter = Utf8Filter {
     af: AF_ALL & !AF_CTRL,
     ubf: UBF_NONE,
     grep_char: None,
+    script_filter: None,
+    char_ranges: None,
 };
 
 /// A default filter for all non-ASCII encoding searches.
@@ -47,6 +67,8 @@ pub const UTF8_FILTER_NON_ASCII_MODE_DEFAULT: Utf8Filter = Utf8Filter {
     af: AF_ALL & !AF_CTRL,
     ubf: UBF_COMMON,
     grep_char: None,
+    script_filter: None,
+    char_ranges: None,
 };
 
 /// A filter that let pass all valid Unicode codepoints.
@@ -56,6 +78,8 @@ pub const UTF8_FILTER_ALL_VALID: Utf8Filter = Utf8Filter {
     af: AF_ALL,
     ubf: UBF_ALL & !UBF_INVALID,
     grep_char: None,
+    script_filter: None,
+    char_ranges: None,
 };
 
 /// A filter for Latin and accents.
@@ -65,6 +89,8 @@ pub const UTF8_FILTER_LATIN: Utf8Filter = Utf8Filter {
     af: AF_ALL & !AF_CTRL | AF_WHITESPACE,
     ubf: UBF_LATIN | UBF_ACCENTS,
     grep_char: None,
+    script_filter: None,
+    char_ranges: None,
 };
 /// Unicode-block-filter:
 /// No leading bytes are filtered.
@@ -274,16 +300,56 @@ pub const ASCII_FILTER_ALIASSE: [([u8; 12], u128, [u8; 25]); 6] = [
 ];
 
 lazy_static! {
-    pub static ref MISSIONS: Missions = Missions::new(
-        ARGS.counter_offset.as_ref(),
-        &ARGS.encoding,
-        ARGS.chars_min.as_ref(),
-        ARGS.same_unicode_block,
-        ARGS.ascii_filter.as_ref(),
-        ARGS.unicode_block_filter.as_ref(),
-        ARGS.grep_char.as_ref(),
-        ARGS.output_line_len.as_ref(),
-    )
+    // `--profile` expands into missions of the configuration files, `--auto`
+    // adds the detected encodings. The configuration files are only read,
+    // when a profile is given.
+    pub static ref MISSIONS: Missions = {
+        let mut mission_defs: Vec<MissionDef> =
+            ARGS.encoding.iter().map(|e| MissionDef::EncOpt(e.clone())).collect();
+        let output_line_len = if ARGS.profile.is_empty() {
+            Ok(None)
+        } else {
+            CONFIG.expand_profiles(&ARGS.profile, &mut mission_defs)
+        };
+        output_line_len.and_then(|output_line_len| {
+            if ARGS.auto {
+                AUTO_ENCODINGS.extend_encoding(&mut mission_defs);
+            }
+            Missions::new(
+                ARGS.counter_offset.as_ref(),
+                &mission_defs,
+                ARGS.chars_min.as_ref(),
+                ARGS.chars_max.as_ref(),
+                ARGS.same_unicode_block,
+                ARGS.max_scripts.as_ref(),
+                ARGS.mojibake,
+                ARGS.ascii_filter.as_ref(),
+                ARGS.unicode_block_filter.as_ref(),
+                ARGS.grep_char.as_ref(),
+                ARGS.script_filter.as_ref(),
+                ARGS.grep_pattern.as_ref(),
+                ARGS.ignore_case,
+                ARGS.ignore_diacritics,
+                ARGS.nfkc,
+                ARGS.regex.as_ref(),
+                ARGS.invert_match,
+                ARGS.keyword.as_ref(),
+                ARGS.min_score.as_ref(),
+                ARGS.min_entropy.as_ref(),
+                ARGS.max_run.as_ref(),
+                ARGS.min_distinct.as_ref(),
+                &ARGS.word_list,
+                ARGS.min_word_ratio.as_ref(),
+                ARGS.identify_language,
+                ARGS.language.as_ref(),
+                ARGS.merge.is_some(),
+                ARGS.format,
+                ARGS.columns.as_ref(),
+                ARGS.delimiter.as_ref(),
+                ARGS.output_line_len.as_ref().or(output_line_len.as_ref()),
+            )
+        })
+    }
     .unwrap_or_else(|error| {
         eprintln!("Error while parsing command-line arguments: {:?}", error);
         process::exit(1);
@@ -293,15 +359,20 @@ lazy_static! {
 /// When the decoder finds a valid Unicode character, it decodes it into UTF-8.
 /// The leading byte of this UTF-8 multi-byte-character must then pass an
 /// additional filter before being printed: the so called `Utf8Filter`. It comes
-/// with three independant filter criteria:
+/// with five independant filter criteria:
 ///
 /// 1. The Ascii-Filter `Utf8Filter::asf`,
 /// 2. the Unicode-block-filter `Utf8Filter::ubf`,
-/// 3. and the `Utf8::must_hame`-filter.
+/// 3. the optional Script-filter `Utf8Filter::script_filter`,
+/// 4. the optional code point set `Utf8Filter::char_ranges`,
+/// 5. and the `Utf8::must_hame`-filter.
 ///
 /// The Ascii-Filter `Utf8Filter::asf` and the Unicode-block-filter
 /// `Utf8Filter::ubf` are implemented by the `Utf8Filter::pass_filter()`
-/// function. The `Utf8::grep_char`-filter is implemented by the
+/// function. Multi-byte characters passing `ubf` are checked against the
+/// `Utf8Filter::script_filter` and `Utf8Filter::char_ranges` with
+/// `Utf8Filter::pass_char_filter()`.
+/// The `Utf8::grep_char`-filter is implemented by the
 /// `helper::SplitStr::next()` iterator function.
 
 #[derive(Eq, PartialEq, Copy, Clone)]
@@ -320,10 +391,23 @@ pub struct Utf8Filter {
     /// pass the filter. Otherwise, the character is rejected.
     pub ubf: u64,
 
-    /// If `Some()`, a finding must have at least one leading byte equal to the
-    /// `grep_char` ASCII code. This is useful when you grep for path-strings:
-    /// e.g. "0x2f" or "0x5c".
-    pub grep_char: Option<u8>,
+    /// If `Some()`, a finding must have at least one character equal to
+    /// `grep_char`. This is useful when you grep for path-strings: e.g. `/`
+    /// or `\`.
+    pub grep_char: Option<char>,
+
+    /// If `Some()`, multi-byte characters passing `Utf8Filter::ubf` must
+    /// also belong to one of the Unicode Scripts or Blocks of this filter.
+    /// Unlike `ubf`, it looks at the whole code point.
+    pub script_filter: Option<ScriptFilter>,
+
+    /// If `Some()`, multi-byte characters passing `Utf8Filter::ubf` must
+    /// also be in this set of code points. It is compiled from a
+    /// character-range expression given with `--unicode-block-filter`,
+    /// together with `ubf`. As `Utf8Filter` is copied into every
+    /// `SplitStr`, we keep a reference to the set, which lives as long as
+    /// `MISSIONS`.
+    pub char_ranges: Option<&'static CharRanges>,
 }
 
 impl Utf8Filter {
@@ -346,14 +430,53 @@ impl Utf8Filter {
         // UTF-8 bytes). We first map values 192-255 -> 0-128 with (b & 0x3f)
         1 << (b & 0x3f) & self.ubf != 0
     }
+
+    /// This function applies the optional `Utf8Filter::script_filter` to the
+    /// multi-byte character `c`. Call it only after `pass_ubf_filter()`
+    /// succeeded.
+    #[inline]
+    pub fn pass_script_filter(&self, c: char) -> bool {
+        self.script_filter.as_ref().map_or(true, |sf| sf.pass(c))
+    }
+
+    /// True when the multi-byte character starting with the leading byte `b`
+    /// must be decoded, because `pass_char_filter()` needs to see it.
+    #[inline]
+    pub fn needs_char(&self, b: u8) -> bool {
+        self.script_filter.is_some() || self.char_ranges.is_some_and(|cr| cr.is_partial(b))
+    }
+
+    /// This function applies the optional `Utf8Filter::script_filter` and
+    /// `Utf8Filter::char_ranges` to the multi-byte character `c`. Call it
+    /// only after `pass_ubf_filter()` succeeded.
+    #[inline]
+    pub fn pass_char_filter(&self, c: char) -> bool {
+        self.pass_script_filter(c) && self.char_ranges.map_or(true, |cr| cr.pass(c))
+    }
+
+    /// True when all characters of `s` pass the filter, i.e. when `s` does
+    /// not contain a string boundary. `grep_char`, `require_same_unicode_block`
+    /// and `max_scripts` are not checked.
+    pub fn pass_all_chars(&self, s: &str) -> bool {
+        s.chars().all(|c| {
+            let mut b = [0u8; 4];
+            let leading_byte = c.encode_utf8(&mut b).as_bytes()[0];
+            if c.is_ascii() {
+                self.pass_af_filter(leading_byte)
+            } else {
+                self.pass_ubf_filter(leading_byte)
+                    && (!self.needs_char(leading_byte) || self.pass_char_filter(c))
+            }
+        })
+    }
 }
 
 impl fmt::Debug for Utf8Filter {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "af: 0x{:x}, ubf: 0x{:x}, grep_char: {:?}",
-            self.af, self.ubf, self.grep_char
+            "af: 0x{:x}, ubf: 0x{:x}, grep_char: {:?}, script_filter: {:?}, char_ranges: {:?}",
+            self.af, self.ubf, self.grep_char, self.script_filter, self.char_ranges
         )
     }
 }
@@ -382,7 +505,16 @@ impl Ord for Utf8Filter {
 pub struct Mission {
     /// An identifier for this mission. We use its position index in the
     /// `Missions.v` vector.
-    pub mission_id: u8,
+    pub mission_id: usize,
+
+    /// The position of the mission's definition in the `mission_defs` of
+    /// `Missions::new()`, e.g. of its `--encoding` option. It differs from
+    /// `mission_id` after a skipped scanner.
+    pub def_id: usize,
+
+    /// The name of this mission in output, error messages and
+    /// `--debug-option` listings. Unique among all missions.
+    pub label: String,
 
     /// Start offset for the input-stream-byte-counter. This is useful in case
     /// the input comes split in separate files, that should be analyzed with
@@ -399,11 +531,23 @@ pub struct Mission {
     /// Minimum required string length in Bytes for a finding to be printed.
     pub chars_min_nb: u8,
 
+    /// When set, only findings of at most this many characters are printed.
+    /// Unlike `chars_min_nb`, this is checked against the whole string, not
+    /// against the chunks it is wrapped to.
+    pub chars_max_nb: Option<usize>,
+
     /// When true imposes an addition condition for findings:
     /// Advises the filter to only accept multi-characters in a finding with
     /// the same leading byte. This does not affect 1-byte ASCII characters.
     pub require_same_unicode_block: bool,
 
+    /// When `Some(n)` imposes an addition condition for findings:
+    /// Advises the filter to only accept multi-characters of at most `n`
+    /// different Unicode Scripts in a finding. `Common` and `Inherited`
+    /// characters, e.g. punctuation, are not counted. This does not affect
+    /// 1-byte ASCII characters.
+    pub max_scripts: Option<u8>,
+
     /// A filter, defining additional criteria for a finding to be printed.
     pub filter: Utf8Filter,
 
@@ -418,6 +562,142 @@ pub struct Mission {
     /// indicate this case. It is later used to print out the label `ascii`
     /// instead of `x-user-defined`.
     pub print_encoding_as_ascii: bool,
+
+    /// An encoding `encoding_rs` does not know about. When `Some`, the input
+    /// is decoded with the custom encoding's own decoder and `encoding` is
+    /// only a placeholder.
+    pub custom_encoding: Option<CustomEncoding>,
+
+    /// When true, every finding is checked for doubly encoded UTF-8. If
+    /// found, the repaired string is printed next to the finding.
+    pub detect_mojibake: bool,
+
+    /// When `Some`, only findings containing at least one of the patterns are
+    /// printed.
+    pub grep_patterns: Option<GrepPatterns>,
+
+    /// When `Some`, only findings matching the regular expression are
+    /// printed.
+    pub regex: Option<Regex>,
+
+    /// Inverts the meaning of `regex`: only findings not matching are
+    /// printed.
+    pub invert_match: bool,
+
+    /// When `Some`, the input is searched for the encoded keyword first and
+    /// only the neighbourhood of a hit is decoded. Only findings containing
+    /// the keyword are printed.
+    pub pre_search: Option<PreSearch>,
+
+    /// When `Some`, only findings with a plausibility score of at least
+    /// this percentage are printed, see `plausibility::score()`. Findings
+    /// without characters to rate always pass.
+    pub min_score: Option<u8>,
+
+    /// Rejects findings looking like padding or fill patterns.
+    pub str_stats_filter: StrStatsFilter,
+
+    /// Rejects findings with too few words of the user's word lists.
+    pub word_list: Option<&'static WordList>,
+
+    /// When true, the language of findings is identified, see
+    /// `language::identify()`.
+    pub identify_language: bool,
+
+    /// When `Some`, findings identified as another language are not
+    /// printed. Findings too short to be identified are. Implies
+    /// `identify_language`.
+    pub languages: Option<Vec<&'static str>>,
+
+    /// When true, the input bytes of every decoded character are recorded,
+    /// see `Finding::start` and `Finding::byte_len`. Only `--merge` and
+    /// output formats other than `text` need them.
+    pub record_char_spans: bool,
+}
+
+/// Encodings that are not provided by `encoding_rs`. Each comes with its own
+/// decoder, see `scanner::ScannerDecoder`.
+#[derive(Debug, Clone)]
+pub enum CustomEncoding {
+    /// Single-byte encoding loaded from a mapping file.
+    CharMap(Box<CharMap>),
+    /// UTF-8 that was encoded a second time as UTF-8, after having been
+    /// interpreted as Latin-1 or Windows-1252.
+    DoubleUtf8,
+}
+
+impl Mission {
+    /// The encoding name as it is presented to the user.
+    pub fn encoding_name(&self) -> &str {
+        if let Some(CustomEncoding::CharMap(ref char_map)) = self.custom_encoding {
+            &char_map.name
+        } else if let Some(CustomEncoding::DoubleUtf8) = self.custom_encoding {
+            DOUBLE_UTF8_ENC_LABEL
+        } else if self.print_encoding_as_ascii {
+            ASCII_ENC_LABEL
+        } else {
+            self.encoding.name()
+        }
+    }
+
+    /// True when one of the filters evaluated by `pass_str_filter()` is set.
+    #[inline]
+    pub fn has_str_filter(&self) -> bool {
+        self.grep_patterns.is_some()
+            || self.regex.is_some()
+            || self.pre_search.is_some()
+            || self.min_score.is_some()
+            || self.str_stats_filter.is_set()
+            || self.chars_max_nb.is_some()
+            || self.word_list.is_some()
+            || self.identify_language
+    }
+
+    /// True when strings are held back until they end, to be judged or
+    /// repaired as a whole, see `ScannerState::held_str`.
+    #[inline]
+    pub fn holds_whole_str(&self) -> bool {
+        self.has_str_filter() || self.detect_mojibake
+    }
+
+    /// Unlike `Utf8Filter`, which looks at single characters, these filters
+    /// look at whole strings: `grep_patterns`, `regex`, the keyword of
+    /// `pre_search`, `min_score`, `str_stats_filter`, `word_list` and
+    /// `languages`. Returns `true` if `s` passes all of them, the matched grep
+    /// pattern, if any, the plausibility score, if `min_score` is set, and the
+    /// language with the confidence, if `identify_language` is set.
+    /// `chars_max_nb` is checked while the string is decoded, see
+    /// `HeldStr::char_nb`.
+    pub fn pass_str_filter(
+        &self,
+        s: &str,
+    ) -> (bool, Option<&str>, Option<u8>, Option<(&'static str, u8)>) {
+        let matched_pattern = self.grep_patterns.as_ref().and_then(|gp| gp.find(s));
+        let score = self.min_score.and_then(|_| plausibility::score(s));
+        let language = if self.identify_language {
+            language::identify(s)
+        } else {
+            None
+        };
+        let passes = (self.grep_patterns.is_none() || matched_pattern.is_some())
+            && self
+                .regex
+                .as_ref()
+                .map_or(true, |re| re.is_match(s) != self.invert_match)
+            && self
+                .pre_search
+                .as_ref()
+                .map_or(true, |ps| s.contains(ps.keyword.as_str()))
+            && self
+                .min_score
+                .map_or(true, |min| score.map_or(true, |score| score >= min))
+            && self.str_stats_filter.pass(s)
+            && self.word_list.map_or(true, |wl| wl.pass(s))
+            && self.languages.as_ref().map_or(true, |languages| {
+                language.map_or(true, |(code, _)| languages.contains(&code))
+            });
+        (passes, matched_pattern, score, language)
+    }
 }
 
 /// A collection to bundle all `Mission`-objects.
@@ -436,6 +716,73 @@ impl Deref for Missions {
     }
 }
 
+/// Keys of the items of an `--encoding` definition following `ENC_NAME`.
+/// The first `ENC_OPT_POSITIONAL_NB` items can also be given by position.
+pub const ENC_OPT_KEYS: [&str; 9] = [
+    "min",
+    "af",
+    "ubf",
+    "grep",
+    "patterns",
+    "offset",
+    "same-block",
+    "len",
+    "label",
+];
+
+/// Number of items of an `--encoding` definition that can be given by
+/// position: `MIN,AF,UBF,GREP,PATTERNS`.
+pub const ENC_OPT_POSITIONAL_NB: usize = 5;
+
+/// The definition of one mission.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MissionDef<'a> {
+    /// An `--encoding` definition `ENC_NAME,MIN,AF,UBF,GREP,PATTERNS,…`.
+    EncOpt(String),
+    /// A mission of the configuration files and its name.
+    Config(&'a str, &'a MissionConfig),
+}
+
+impl MissionDef<'_> {
+    /// The `ENC_NAME` of the mission, empty for the default encoding.
+    pub fn enc_name(&self) -> &str {
+        match self {
+            MissionDef::EncOpt(enc_opt) => enc_opt.split(',').next().unwrap_or("").trim(),
+            MissionDef::Config(_, mc) => mc.encoding.as_deref().unwrap_or("").trim(),
+        }
+    }
+}
+
+/// The items of one `--encoding` definition. `None` stands for an item not
+/// given: then the command-line option or the default applies.
+#[derive(Debug, Default, PartialEq)]
+struct EncOpt<'a> {
+    enc_name: Option<&'a str>,
+    chars_min_nb: Option<u8>,
+    filter_af: Option<u128>,
+    filter_ubf: Option<u64>,
+    grep_char: Option<char>,
+    grep_patterns: Option<Vec<String>>,
+    counter_offset: Option<ByteCounter>,
+    same_unicode_block: Option<bool>,
+    output_line_len: Option<usize>,
+    label: Option<&'a str>,
+}
+
+/// The label of the mission with `mission_id` when none is given: `a`..`z`,
+/// `aa`, `ab`.. `az`, `ba`.. and so on.
+pub fn mission_label(mission_id: usize) -> String {
+    let mut label = Vec::new();
+    let mut n = mission_id + 1;
+    while n > 0 {
+        n -= 1;
+        label.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    label.reverse();
+    String::from_utf8(label).unwrap()
+}
+
 /// Parses a filter expression from some hexadecimal string or
 /// number string to an integer value.
 ///
@@ -450,7 +797,7 @@ macro_rules! parse_integer {
     ($s:expr, $x_from_str_radix:expr, $x_from_str:expr) => {{
         match $s {
             Some(s) if s.is_empty() => None,
-            Some(s) if s.trim().len() >= 2 && s.trim()[..2] == *"0x" => Some(
+            Some(s) if s.trim().starts_with("0x") => Some(
                 $x_from_str_radix(&s.trim()[2..], 16)
                     .with_context(|| format!("failed to parse hexadecimal number: `{}`", s))?,
             ),
@@ -466,29 +813,26 @@ macro_rules! parse_integer {
 /// filter-alias-name in `$list` to a filter-integer value.
 ///
 /// `$s` is `Option<String>` to be parsed.
-/// `$list` is either `ASCII_FILTER_ALIASSE` or `UNICODE_BLOCK_FILTER_ALIASSE`.
+/// `$list` is either `ASCII_FILTER_ALIASSE` or `UNICODE_BLOCK_FILTER_ALIASSE`,
+/// `$user` the corresponding user-defined aliases in `FILTER_ALIASES`.
 /// `$x_from_str_radix` is either `u128::from_str_radix` or u64::from_str_radix`.
 ///
 ///  The marco returns a filter integer value in `Option<integer>` and
 ///  returns early when parsing is not successful.
 #[macro_export]
 macro_rules! parse_filter_parameter {
-    ($s:expr, $x_from_str_radix:expr, $list:expr) => {{
+    ($s:expr, $x_from_str_radix:expr, $list:expr, $user:expr) => {{
         match $s {
-            Some(s) if s.trim().len() >= 2 && s.trim()[..2] == *"0x" => Some(
+            Some(s) if s.trim().starts_with("0x") => Some(
                 $x_from_str_radix(&s.trim()[2..], 16)
                     .with_context(|| format!("failed to parse hexadecimal number: `{}`", s))?,
             ),
             Some(s) if s.is_empty() => None,
             Some(s) => {
                 let s = s.trim();
-                let mut oubf = None;
-                for (ubf_name, ubf, _) in $list.iter() {
-                    if s.len() <= ubf_name.len() && *s.as_bytes() == ubf_name[..s.len()] {
-                        oubf = Some(*ubf);
-                        break;
-                    };
-                }
+                // Built-in names do not need the configuration files.
+                let oubf = find_builtin_filter_alias(s, &$list)
+                    .or_else(|| find_filter_alias(s, &$list, &$user));
                 if oubf.is_some() {
                     oubf
                 } else {
@@ -503,22 +847,83 @@ macro_rules! parse_filter_parameter {
     }};
 }
 
+/// Looks up the filter alias `name`. User-defined aliases `user` with
+/// exactly this name come first. Then follow the built-in aliases `list`
+/// and the user-defined aliases, both matching also when `name` is an
+/// abbreviation.
+pub fn find_filter_alias<T: Copy>(
+    name: &str,
+    list: &[([u8; 12], T, [u8; 25])],
+    user: &[(String, T, String)],
+) -> Option<T> {
+    user.iter()
+        .find(|(alias, _, _)| alias == name)
+        .map(|(_, filter, _)| *filter)
+        .or_else(|| {
+            list.iter()
+                .find(|(alias, _, _)| {
+                    name.len() <= alias.len() && *name.as_bytes() == alias[..name.len()]
+                })
+                .map(|(_, filter, _)| *filter)
+        })
+        .or_else(|| {
+            user.iter()
+                .find(|(alias, _, _)| alias.starts_with(name))
+                .map(|(_, filter, _)| *filter)
+        })
+}
+
+/// Looks up the built-in filter alias with exactly the name `name` in
+/// `list`. User-defined aliases can not have this name.
+pub fn find_builtin_filter_alias<T: Copy>(
+    name: &str,
+    list: &[([u8; 12], T, [u8; 25])],
+) -> Option<T> {
+    list.iter()
+        .find(|(alias, _, _)| name.as_bytes() == alias.trim_ascii_end())
+        .map(|(_, filter, _)| *filter)
+}
+
 impl Missions {
     /// As `Mission` does not have its own constructor, the `Missions`
     /// constructor creates all `Mission`-objects in one row and stores them in
     /// some vector `Missions::v`. We guarantee that at least one (default)
     /// `Mission`-object will be created. The initialisation data coming from
     /// `options::ARGS` is completed with default values, then parsed and syntax
-    /// checked before creating a `Mission`-object.
+    /// checked before creating a `Mission`-object. `mission_defs` are the
+    /// `--encoding` definitions and the missions of the profiles.
 
     pub fn new(
         flag_counter_offset: Option<&String>,
-        flag_encoding: &[String],
+        mission_defs: &[MissionDef],
         flag_chars_min_nb: Option<&String>,
+        flag_chars_max_nb: Option<&String>,
         flag_same_unicode_block: bool,
+        flag_max_scripts: Option<&String>,
+        flag_mojibake: bool,
         flag_ascii_filter: Option<&String>,
         flag_unicode_block_filter: Option<&String>,
         flag_grep_char: Option<&String>,
+        flag_script_filter: Option<&String>,
+        flag_grep_pattern: Option<&String>,
+        flag_ignore_case: bool,
+        flag_ignore_diacritics: bool,
+        flag_nfkc: bool,
+        flag_regex: Option<&String>,
+        flag_invert_match: bool,
+        flag_keyword: Option<&String>,
+        flag_min_score: Option<&String>,
+        flag_min_entropy: Option<&String>,
+        flag_max_run: Option<&String>,
+        flag_min_distinct: Option<&String>,
+        flag_word_list: &[PathBuf],
+        flag_min_word_ratio: Option<&String>,
+        flag_identify_language: bool,
+        flag_language: Option<&String>,
+        flag_merge: bool,
+        flag_format: OutputFormat,
+        flag_columns: Option<&String>,
+        flag_delimiter: Option<&String>,
         flag_output_line_len: Option<&String>,
     ) -> Result<Self> {
         let flag_counter_offset = parse_integer!(
@@ -529,31 +934,135 @@ impl Missions {
 
         let flag_chars_min_nb = parse_integer!(flag_chars_min_nb, u8::from_str_radix, u8::from_str);
 
+        let flag_chars_max_nb =
+            parse_integer!(flag_chars_max_nb, usize::from_str_radix, usize::from_str);
+
+        let flag_max_scripts = parse_integer!(flag_max_scripts, u8::from_str_radix, u8::from_str);
+        if flag_max_scripts == Some(0) {
+            return Err(anyhow!("minimum for `--max-scripts` is `1`."));
+        }
+
         // Parse from `Option<String>` to `Option<u128>`
         let flag_ascii_filter = parse_filter_parameter!(
             flag_ascii_filter,
             u128::from_str_radix,
-            ASCII_FILTER_ALIASSE
+            ASCII_FILTER_ALIASSE,
+            FILTER_ALIASES.ascii
         );
 
+        // A character-range expression is compiled into a code point set.
+        // The missions share it for the rest of the program's lifetime.
+        let flag_char_ranges: Option<&'static CharRanges> = match flag_unicode_block_filter {
+            Some(s) if CharRanges::is_expression(s) => Some(Box::leak(Box::new(
+                CharRanges::parse(s)
+                    .with_context(|| format!("invalid `--unicode-block-filter` `{}`.", s))?,
+            ))),
+            _ => None,
+        };
+
         // Parse from `Option<String>` to `Option<u64>`
-        let flag_unicode_block_filter = parse_filter_parameter!(
-            flag_unicode_block_filter,
-            u64::from_str_radix,
-            UNICODE_BLOCK_FILTER_ALIASSE
-        );
+        let flag_unicode_block_filter = match flag_char_ranges {
+            Some(cr) => Some(cr.ubf),
+            None => parse_filter_parameter!(
+                flag_unicode_block_filter,
+                u64::from_str_radix,
+                UNICODE_BLOCK_FILTER_ALIASSE,
+                FILTER_ALIASES.unicode_block
+            ),
+        };
+
+        let flag_grep_char = Self::parse_grep_char(flag_grep_char.map(|s| s.as_str()))
+            .context("invalid `--grep-char`.")?;
+
+        let flag_script_filter = match flag_script_filter {
+            Some(s) => Some(
+                ScriptFilter::parse(s)
+                    .with_context(|| format!("invalid `--script-filter` `{}`.", s))?,
+            ),
+            None => None,
+        };
+
+        let match_mode = MatchMode {
+            ignore_case: flag_ignore_case,
+            nfkc: flag_nfkc,
+            ignore_diacritics: flag_ignore_diacritics,
+        };
+
+        let flag_regex = match flag_regex {
+            Some(r) => Some(Regex::new(r).with_context(|| format!("invalid `--regex` `{}`.", r))?),
+            None => None,
+        };
+
+        let flag_min_score = parse_integer!(flag_min_score, u8::from_str_radix, u8::from_str);
+        if let Some(m) = flag_min_score {
+            if m > 100 {
+                return Err(anyhow!(
+                    "maximum for `--min-score` is `100`, \
+                     you tried: `{}`.",
+                    m
+                ));
+            }
+        }
 
-        let flag_grep_char = parse_integer!(flag_grep_char, u8::from_str_radix, u8::from_str);
-        if let Some(m) = flag_grep_char {
-            if m > 127 {
+        let str_stats_filter = StrStatsFilter {
+            min_entropy: match flag_min_entropy {
+                Some(s) => Some(
+                    f32::from_str(s.trim())
+                        .with_context(|| format!("invalid `--min-entropy` `{}`.", s))?,
+                ),
+                None => None,
+            },
+            max_run_len: parse_integer!(flag_max_run, usize::from_str_radix, usize::from_str),
+            min_distinct_chars: parse_integer!(
+                flag_min_distinct,
+                usize::from_str_radix,
+                usize::from_str
+            ),
+        };
+
+        let flag_min_word_ratio =
+            parse_integer!(flag_min_word_ratio, u8::from_str_radix, u8::from_str);
+        if let Some(m) = flag_min_word_ratio {
+            if m > 100 {
                 return Err(anyhow!(
-                    "you can only `--grep-char` for ASCII codes < 128, \
+                    "maximum for `--min-word-ratio` is `100`, \
                      you tried: `{}`.",
                     m
                 ));
             }
         }
 
+        // The word lists are read once and shared by all missions.
+        let word_list: Option<&'static WordList> = if flag_word_list.is_empty() {
+            if flag_min_word_ratio.is_some() {
+                return Err(anyhow!("`--min-word-ratio` requires `--word-list`."));
+            }
+            None
+        } else {
+            Some(Box::leak(Box::new(WordList::from_files(
+                flag_word_list,
+                flag_min_word_ratio.unwrap_or(WORD_RATIO_DEFAULT),
+            )?)))
+        };
+
+        let languages = match flag_language {
+            Some(s) => Some(
+                s.split(LANGUAGE_SEPARATOR)
+                    .map(|name| {
+                        language::language_code(name).ok_or_else(|| {
+                            anyhow!(
+                                "language `{}` in `--language` is not available, \
+                                 try `--list-encodings`.",
+                                name.trim()
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<&'static str>>>()?,
+            ),
+            None => None,
+        };
+        let identify_language = flag_identify_language || languages.is_some();
+
         let flag_output_line_len =
             parse_integer!(flag_output_line_len, usize::from_str_radix, usize::from_str);
         if let Some(m) = flag_output_line_len {
@@ -567,18 +1076,46 @@ impl Missions {
             }
         }
 
+        if flag_keyword.is_some_and(|k| k.is_empty()) {
+            return Err(anyhow!("`--keyword` is empty."));
+        }
+
         let mut v = Vec::new();
-        let encoding_default: &[String; 1] = &[ENCODING_DEFAULT.to_string()];
+        let mission_def_default = &[MissionDef::EncOpt(ENCODING_DEFAULT.to_string())];
 
-        let enc_iter = if flag_encoding.is_empty() {
-            encoding_default.iter()
+        let mission_def_iter = if mission_defs.is_empty() {
+            mission_def_default.iter()
         } else {
-            flag_encoding.iter()
+            mission_defs.iter()
         };
 
-        for (mission_id, enc_opt) in enc_iter.enumerate() {
-            let (enc_name, chars_min_nb, filter_af, filter_ubf, filter_grep_char) =
-                Self::parse_enc_opt(enc_opt)?;
+        for (def_id, mission_def) in mission_def_iter.enumerate() {
+            // Scanners may be skipped: `mission_id` indexes `Missions::v`.
+            let mission_id = v.len();
+            let EncOpt {
+                enc_name,
+                chars_min_nb,
+                filter_af,
+                filter_ubf,
+                grep_char: filter_grep_char,
+                grep_patterns,
+                counter_offset,
+                same_unicode_block,
+                output_line_len,
+                label,
+            } = match mission_def {
+                MissionDef::EncOpt(enc_opt) => Self::parse_enc_opt(enc_opt)?,
+                MissionDef::Config(name, mc) => Self::enc_opt_from_config(mc)
+                    .with_context(|| format!("invalid mission `{}`.", name))?,
+            };
+
+            let label = match label {
+                Some(l) => l.to_string(),
+                None => mission_label(def_id),
+            };
+            if v.iter().any(|m: &Mission| m.label == label) {
+                return Err(anyhow!("Scanner label `{}` is used twice.", label));
+            }
 
             // DEFINE DEFAULTS
 
@@ -587,10 +1124,9 @@ impl Missions {
                 None => ENCODING_DEFAULT,
             };
 
-            let counter_offset = match flag_counter_offset {
-                Some(n) => n,
-                None => COUNTER_OFFSET_DEFAULT,
-            };
+            let counter_offset = counter_offset
+                .or(flag_counter_offset)
+                .unwrap_or(COUNTER_OFFSET_DEFAULT);
 
             // If `char_min_nb` is not defined in `enc_opt`
             // use the command-line option.
@@ -602,19 +1138,30 @@ impl Missions {
                 },
             };
 
-            let require_same_unicode_block = flag_same_unicode_block;
+            if let Some(chars_max_nb) = flag_chars_max_nb {
+                if chars_max_nb < chars_min_nb as usize {
+                    return Err(anyhow!(
+                        "Scanner {}: \
+                         `--chars-max` `{}` is less than the minimum characters `{}`.",
+                        label,
+                        chars_max_nb,
+                        chars_min_nb,
+                    ));
+                }
+            }
+
+            let require_same_unicode_block = same_unicode_block.unwrap_or(flag_same_unicode_block);
 
-            let output_line_char_nb_max = match flag_output_line_len {
-                Some(n) => n,
-                None => OUTPUT_LINE_CHAR_NB_MAX_DEFAULT,
-            };
+            let output_line_char_nb_max = output_line_len
+                .or(flag_output_line_len)
+                .unwrap_or(OUTPUT_LINE_CHAR_NB_MAX_DEFAULT);
 
             if output_line_char_nb_max < OUTPUT_LINE_CHAR_NB_MIN {
                 return Err(anyhow!(
                     "Scanner {}: \
                      minimum for `--output-line-len` is `{}`, \
                      you tried: `{}`.",
-                    char::from((mission_id + 97) as u8),
+                    label,
                     OUTPUT_LINE_CHAR_NB_MIN,
                     output_line_char_nb_max,
                 ));
@@ -624,16 +1171,32 @@ impl Missions {
             // "x-user-defined" and the `UTF8_FILTER_ASCII_MODE_DEFAULT`-filter,
             // if not otherwise specified.
 
+            // The ASCII part of a character-range expression replaces the
+            // default, but not an explicit `--ascii-filter`.
             let filter_af = filter_af.unwrap_or_else(|| {
-                flag_ascii_filter.unwrap_or(if enc_name == ASCII_ENC_LABEL {
-                    UTF8_FILTER_ASCII_MODE_DEFAULT.af
-                } else {
-                    UTF8_FILTER_NON_ASCII_MODE_DEFAULT.af
-                })
+                flag_ascii_filter
+                    .or_else(|| flag_char_ranges.and_then(|cr| cr.af))
+                    .unwrap_or(if enc_name == ASCII_ENC_LABEL {
+                        UTF8_FILTER_ASCII_MODE_DEFAULT.af
+                    } else {
+                        UTF8_FILTER_NON_ASCII_MODE_DEFAULT.af
+                    })
             });
 
+            // A UBF in `enc_opt` replaces the whole character-range
+            // expression.
+            let filter_char_ranges = if filter_ubf.is_some() {
+                None
+            } else {
+                flag_char_ranges
+            };
+
+            // A script filter does the fine-grained selection, so by default
+            // all valid multi-byte characters are handed over to it.
             let filter_ubf = filter_ubf.unwrap_or_else(|| {
-                flag_unicode_block_filter.unwrap_or(if enc_name == ASCII_ENC_LABEL {
+                flag_unicode_block_filter.unwrap_or(if flag_script_filter.is_some() {
+                    UBF_ALL_VALID
+                } else if enc_name == ASCII_ENC_LABEL {
                     UTF8_FILTER_ASCII_MODE_DEFAULT.ubf
                 } else {
                     UTF8_FILTER_NON_ASCII_MODE_DEFAULT.ubf
@@ -654,22 +1217,26 @@ impl Missions {
                 },
             };
 
-            if let Some(m) = filter_grep_char {
-                if m > 127 {
-                    return Err(anyhow!(
+            // Patterns in `enc_opt` replace those of the command-line option.
+            let grep_patterns = match grep_patterns
+                .or_else(|| flag_grep_pattern.map(|p| GrepPatterns::split_pattern_list(p)))
+            {
+                Some(p) => Some(GrepPatterns::new(p, match_mode).with_context(|| {
+                    format!(
                         "Scanner {}: \
-                         you can only grep for ASCII codes < 128, \
-                         you tried: `{}`.",
-                        char::from((mission_id + 97) as u8),
-                        m
-                    ));
-                }
-            }
+                         invalid grep pattern list.",
+                        label
+                    )
+                })?),
+                None => None,
+            };
 
             let filter = Utf8Filter {
                 af: filter_af,
                 ubf: filter_ubf,
                 grep_char: filter_grep_char,
+                script_filter: flag_script_filter,
+                char_ranges: filter_char_ranges,
             };
 
             let mut print_encoding_as_ascii = false;
@@ -678,27 +1245,111 @@ impl Missions {
                 enc_name = "x-user-defined"
             };
 
+            // Custom encodings are not known to "encoding.rs". We keep
+            // "x-user-defined" as placeholder and decode with their own
+            // decoder instead.
+            let custom_encoding = if let Some(path) = enc_name.strip_prefix(CHAR_MAP_ENC_PREFIX) {
+                enc_name = "x-user-defined";
+                Some(CustomEncoding::CharMap(Box::new(
+                    CharMap::from_file(path.trim()).with_context(|| {
+                        format!(
+                            "Scanner {}: \
+                             can not load custom encoding `{}`.",
+                            label, path
+                        )
+                    })?,
+                )))
+            } else if enc_name.eq_ignore_ascii_case(DOUBLE_UTF8_ENC_LABEL) {
+                enc_name = "x-user-defined";
+                Some(CustomEncoding::DoubleUtf8)
+            } else {
+                None
+            };
+
             let encoding = &Encoding::for_label((enc_name).as_bytes()).with_context(|| {
                 format!(
                     "Scanner {}: \
                      invalid input encoding name `{}`, try flag `--list-encodings`.",
-                    char::from((mission_id + 97) as u8),
-                    enc_name
+                    label, enc_name
                 )
             })?;
 
+            // A scanner that can not represent the keyword has nothing to
+            // search for. The other scanners still may find it.
+            let pre_search = match flag_keyword {
+                Some(k) => match PreSearch::new(
+                    k,
+                    encoding,
+                    custom_encoding.as_ref(),
+                    print_encoding_as_ascii,
+                ) {
+                    Ok(ps) => Some(ps),
+                    Err(error) => {
+                        eprintln!(
+                            "Warning: scanner {} skipped, `--keyword`: {}.",
+                            label, error
+                        );
+                        continue;
+                    }
+                },
+                None => None,
+            };
+
             v.push(Mission {
                 counter_offset,
                 encoding,
                 chars_min_nb,
+                chars_max_nb: flag_chars_max_nb,
                 require_same_unicode_block,
+                max_scripts: flag_max_scripts,
                 filter,
                 output_line_char_nb_max,
-                mission_id: mission_id as u8,
+                mission_id,
+                def_id,
+                label,
                 print_encoding_as_ascii,
+                custom_encoding,
+                detect_mojibake: flag_mojibake,
+                grep_patterns,
+                regex: flag_regex.clone(),
+                invert_match: flag_invert_match,
+                pre_search,
+                min_score: flag_min_score,
+                str_stats_filter,
+                word_list,
+                identify_language,
+                languages: languages.clone(),
+                record_char_spans: flag_merge || flag_format != OutputFormat::Text,
             });
         }
 
+        if v.is_empty() {
+            return Err(anyhow!(
+                "`--keyword` can not be encoded in any scanner's encoding."
+            ));
+        }
+
+        if !matches!(flag_format, OutputFormat::Csv | OutputFormat::Tsv) {
+            if flag_columns.is_some() {
+                return Err(anyhow!("`--columns` requires `--format csv` or `tsv`."));
+            }
+            if flag_delimiter.is_some() {
+                return Err(anyhow!("`--delimiter` requires `--format csv` or `tsv`."));
+            }
+        }
+
+        // Strings are wrapped at different characters otherwise: the
+        // findings would rarely be the same.
+        if flag_merge
+            && v.windows(2)
+                .any(|w| w[0].output_line_char_nb_max != w[1].output_line_char_nb_max)
+        {
+            return Err(anyhow!(
+                "`--merge` requires the same output line length for all scanners, \
+                 give all encodings the same `len=`."
+            ));
+        }
+
         Ok(Missions { v })
     }
 
@@ -708,44 +1359,197 @@ impl Missions {
         self.v.len()
     }
 
-    /// Helper function to parse `enc_opt`.
+    /// Helper function to parse a grep character. It can be given as
+    /// decimal or hexadecimal (`0x...`) code, as `U+XXXX` or as the character
+    /// itself.
+    fn parse_grep_char(s: Option<&str>) -> Result<Option<char>> {
+        let s = match s {
+            Some(s) if !s.is_empty() => s,
+            _ => return Ok(None),
+        };
+        let mut chars = s.chars();
+        let code = if let (Some(c), None) = (chars.next(), chars.next()) {
+            if c.is_ascii_digit() {
+                // A single digit is a code.
+                c as u32 - '0' as u32
+            } else {
+                return Ok(Some(c));
+            }
+        } else if let Some(hex) = s.strip_prefix("U+").or_else(|| s.strip_prefix("u+")) {
+            u32::from_str_radix(hex, 16)
+                .with_context(|| format!("failed to parse code point: `{}`", s))?
+        } else {
+            parse_integer!(Some(s), u32::from_str_radix, u32::from_str).unwrap()
+        };
+        char::from_u32(code)
+            .map(Some)
+            .ok_or_else(|| anyhow!("`{}` is not a valid Unicode scalar value", s))
+    }
+
+    /// Helper function to check a mission label.
+    fn parse_label(label: &str) -> Result<&str> {
+        match label.trim() {
+            l if !l.is_empty() && l.chars().all(|c| c.is_alphanumeric() || "-_.".contains(c)) => {
+                Ok(l)
+            }
+            l => Err(anyhow!(
+                "`label` must consist of letters, digits, `-`, `_` or `.`, \
+                 you tried: `{}`.",
+                l
+            )),
+        }
+    }
+
+    /// Helper function to convert a mission of the configuration files. Its
+    /// fields are parsed like the items of an `--encoding` definition, but
+    /// may contain `,`.
+    fn enc_opt_from_config(mc: &MissionConfig) -> Result<EncOpt<'_>> {
+        Ok(EncOpt {
+            enc_name: match mc.encoding.as_deref().map(str::trim) {
+                Some("") => None,
+                s => s,
+            },
+            chars_min_nb: mc.chars_min,
+            filter_af: parse_filter_parameter!(
+                mc.ascii_filter.as_ref(),
+                u128::from_str_radix,
+                ASCII_FILTER_ALIASSE,
+                FILTER_ALIASES.ascii
+            ),
+            filter_ubf: parse_filter_parameter!(
+                mc.unicode_block_filter.as_ref(),
+                u64::from_str_radix,
+                UNICODE_BLOCK_FILTER_ALIASSE,
+                FILTER_ALIASES.unicode_block
+            ),
+            grep_char: Self::parse_grep_char(mc.grep_char.as_deref())?,
+            grep_patterns: match mc.grep_pattern.as_deref() {
+                None | Some("") => None,
+                Some(s) => Some(GrepPatterns::split_pattern_list(s)),
+            },
+            counter_offset: mc.counter_offset,
+            same_unicode_block: mc.same_unicode_block,
+            output_line_len: mc.output_line_len,
+            label: mc.label.as_deref().map(Self::parse_label).transpose()?,
+        })
+    }
+
+    /// Helper function to parse `enc_opt`. After `ENC_NAME` come the
+    /// items `MIN,AF,UBF,GREP,PATTERNS` by position, then items of the form
+    /// `KEY=VALUE`, with the keys in `ENC_OPT_KEYS`.
     #[inline]
-    fn parse_enc_opt(
-        enc_opt: &str,
-    ) -> Result<
-        (
-            Option<&str>,
-            Option<u8>,
-            Option<u128>,
-            Option<u64>,
-            Option<u8>,
-        ),
-        anyhow::Error,
-    > {
+    fn parse_enc_opt(enc_opt: &str) -> Result<EncOpt<'_>> {
         // Parse ',' separated strings
         let mut i = enc_opt.split_terminator(',');
 
-        let enc_name = match i.next() {
-            Some("") => None,
-            Some(s) => Some(s.trim()),
-            None => None,
+        let mut eo = EncOpt {
+            enc_name: match i.next() {
+                Some("") => None,
+                Some(s) => Some(s.trim()),
+                None => None,
+            },
+            ..Default::default()
         };
 
-        let chars_min_nb = parse_integer!(i.next(), u8::from_str_radix, u8::from_str);
-
-        let filter_af =
-            parse_filter_parameter!(i.next(), u128::from_str_radix, ASCII_FILTER_ALIASSE);
-
-        let filter_ubf =
-            parse_filter_parameter!(i.next(), u64::from_str_radix, UNICODE_BLOCK_FILTER_ALIASSE);
-
-        let grep_char = parse_integer!(i.next(), u8::from_str_radix, u8::from_str);
+        // Stores `$value` in `$field`, unless it was given already.
+        macro_rules! set {
+            ($field:expr, $key:expr, $value:expr) => {{
+                let value = $value;
+                if value.is_some() {
+                    if $field.is_some() {
+                        return Err(anyhow!("`{}` is given twice in `{}`.", $key, enc_opt));
+                    }
+                    $field = value;
+                }
+            }};
+        }
 
-        if i.next().is_some() {
-            return Err(anyhow!("Too many items in `{}`.", enc_opt));
+        let mut has_keys = false;
+        for (position, item) in i.enumerate() {
+            let (key, value) = match item.split_once('=') {
+                Some((key, value)) if ENC_OPT_KEYS.contains(&key.trim()) => {
+                    has_keys = true;
+                    (key.trim(), value)
+                }
+                _ if has_keys => {
+                    return Err(anyhow!(
+                        "`{}` must be given as `KEY=VALUE` after `KEY=VALUE` items in `{}`.",
+                        item,
+                        enc_opt
+                    ))
+                }
+                _ if position < ENC_OPT_POSITIONAL_NB => (ENC_OPT_KEYS[position], item),
+                _ => return Err(anyhow!("Too many items in `{}`.", enc_opt)),
+            };
+            match key {
+                "min" => set!(
+                    eo.chars_min_nb,
+                    key,
+                    parse_integer!(Some(value), u8::from_str_radix, u8::from_str)
+                ),
+                "af" => set!(
+                    eo.filter_af,
+                    key,
+                    parse_filter_parameter!(
+                        Some(value),
+                        u128::from_str_radix,
+                        ASCII_FILTER_ALIASSE,
+                        FILTER_ALIASES.ascii
+                    )
+                ),
+                "ubf" => set!(
+                    eo.filter_ubf,
+                    key,
+                    parse_filter_parameter!(
+                        Some(value),
+                        u64::from_str_radix,
+                        UNICODE_BLOCK_FILTER_ALIASSE,
+                        FILTER_ALIASES.unicode_block
+                    )
+                ),
+                "grep" => set!(eo.grep_char, key, Self::parse_grep_char(Some(value))?),
+                "patterns" => set!(
+                    eo.grep_patterns,
+                    key,
+                    match value {
+                        "" => None,
+                        s => Some(GrepPatterns::split_pattern_list(s)),
+                    }
+                ),
+                "offset" => set!(
+                    eo.counter_offset,
+                    key,
+                    parse_integer!(
+                        Some(value),
+                        ByteCounter::from_str_radix,
+                        ByteCounter::from_str
+                    )
+                ),
+                "same-block" => set!(
+                    eo.same_unicode_block,
+                    key,
+                    match value.trim() {
+                        "yes" | "true" => Some(true),
+                        "no" | "false" => Some(false),
+                        s => {
+                            return Err(anyhow!(
+                                "`same-block` must be `yes` or `no`, you tried: `{}`.",
+                                s
+                            ));
+                        }
+                    }
+                ),
+                "label" => set!(eo.label, key, Some(Self::parse_label(value)?)),
+                "len" => set!(
+                    eo.output_line_len,
+                    key,
+                    parse_integer!(Some(value), usize::from_str_radix, usize::from_str)
+                ),
+                _ => unreachable!(),
+            }
         }
 
-        Ok((enc_name, chars_min_nb, filter_af, filter_ubf, grep_char))
+        Ok(eo)
     }
 }
 
@@ -761,6 +1565,8 @@ mod tests {
             af: AF_ALL,
             ubf: UBF_LATIN,
             grep_char: None,
+            script_filter: None,
+            char_ranges: None,
         };
 
         // Check lower bits
@@ -777,41 +1583,82 @@ mod tests {
     fn test_enc_opt_parser() {
         assert_eq!(
             super::Missions::parse_enc_opt("ascii").unwrap(),
-            (Some("ascii"), None, None, None, None)
+            EncOpt {
+                enc_name: Some("ascii"),
+                ..Default::default()
+            }
         );
 
         assert_eq!(
             super::Missions::parse_enc_opt("utf-8,10,0x89AB,0xCDEF,0x2f").unwrap(),
-            (
-                Some("utf-8"),
-                Some(10),
-                Some(0x89AB),
-                Some(0xCDEF),
-                Some(0x2f)
-            )
+            EncOpt {
+                enc_name: Some("utf-8"),
+                chars_min_nb: Some(10),
+                filter_af: Some(0x89AB),
+                filter_ubf: Some(0xCDEF),
+                grep_char: Some('/'),
+                ..Default::default()
+            }
         );
 
         assert_eq!(
             super::Missions::parse_enc_opt("utf-8,10,0x89AB,0xCDEF,211").unwrap(),
-            (
-                Some("utf-8"),
-                Some(10),
-                Some(0x89AB),
-                Some(0xCDEF),
-                Some(211)
-            )
+            EncOpt {
+                enc_name: Some("utf-8"),
+                chars_min_nb: Some(10),
+                filter_af: Some(0x89AB),
+                filter_ubf: Some(0xCDEF),
+                grep_char: Some('Ó'),
+                ..Default::default()
+            }
         );
 
         assert_eq!(
             super::Missions::parse_enc_opt(",,,,,").unwrap(),
-            (None, None, None, None, None)
+            EncOpt::default()
         );
 
         assert_eq!(
             super::Missions::parse_enc_opt("ascii,10,0x89AB").unwrap(),
-            (Some("ascii"), Some(10), Some(0x89AB), None, None)
+            EncOpt {
+                enc_name: Some("ascii"),
+                chars_min_nb: Some(10),
+                filter_af: Some(0x89AB),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,,,,€").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                grep_char: Some('€'),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,,,,U+20AC").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                grep_char: Some('€'),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,,,,9").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                grep_char: Some('\t'),
+                ..Default::default()
+            }
         );
 
+        assert!(super::Missions::parse_enc_opt("utf-8,,,,U+D800").is_err());
+
+        assert!(super::Missions::parse_enc_opt("utf-8,,,,€€").is_err());
+
         assert!(super::Missions::parse_enc_opt("ascii, 10n").is_err());
 
         assert!(super::Missions::parse_enc_opt("ascii,10,0x89,0x?B").is_err());
@@ -820,7 +1667,7 @@ mod tests {
 
         assert!(super::Missions::parse_enc_opt("ascii,1000000000000000000000,0x1,0x2").is_err());
 
-        assert!(super::Missions::parse_enc_opt("ascii,10,0x1,0x2,0x3,0x4").is_err());
+        assert!(super::Missions::parse_enc_opt("ascii,10,0x1,0x2,0x3,0x4,0x5").is_err());
 
         assert!(super::Missions::parse_enc_opt("ascii,10,123").is_err());
 
@@ -828,18 +1675,22 @@ mod tests {
 
         assert_eq!(
             super::Missions::parse_enc_opt("ascii,10,Default").unwrap(),
-            (Some("ascii"), Some(10), Some(AF_DEFAULT), None, None)
+            EncOpt {
+                enc_name: Some("ascii"),
+                chars_min_nb: Some(10),
+                filter_af: Some(AF_DEFAULT),
+                ..Default::default()
+            }
         );
 
         assert_eq!(
             super::Missions::parse_enc_opt("ascii,10,,Latin").unwrap(),
-            (
-                Some("ascii"),
-                Some(10),
-                None,
-                Some(UBF_LATIN | UBF_ACCENTS),
-                None
-            )
+            EncOpt {
+                enc_name: Some("ascii"),
+                chars_min_nb: Some(10),
+                filter_ubf: Some(UBF_LATIN | UBF_ACCENTS),
+                ..Default::default()
+            }
         );
 
         assert!(super::Missions::parse_enc_opt("ascii,10,my-no-encoding").is_err());
@@ -848,7 +1699,214 @@ mod tests {
 
         assert_eq!(
             super::Missions::parse_enc_opt("ascii,10,0x89AB").unwrap(),
-            (Some("ascii"), Some(10), Some(0x89AB), None, None)
+            EncOpt {
+                enc_name: Some("ascii"),
+                chars_min_nb: Some(10),
+                filter_af: Some(0x89AB),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-16le,min=8,same-block=yes,len=40").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-16le"),
+                chars_min_nb: Some(8),
+                same_unicode_block: Some(true),
+                output_line_len: Some(40),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,10,,Latin,offset=0x10,grep=/,same-block=no")
+                .unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                chars_min_nb: Some(10),
+                filter_ubf: Some(UBF_LATIN | UBF_ACCENTS),
+                grep_char: Some('/'),
+                counter_offset: Some(0x10),
+                same_unicode_block: Some(false),
+                ..Default::default()
+            }
+        );
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,patterns=a=b|c").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                grep_patterns: Some(vec!["a=b".to_string(), "c".to_string()]),
+                ..Default::default()
+            }
+        );
+
+        // Positional item after a `KEY=VALUE` item.
+        assert!(super::Missions::parse_enc_opt("utf-8,len=40,10").is_err());
+
+        // Same item twice.
+        assert!(super::Missions::parse_enc_opt("utf-8,10,min=8").is_err());
+
+        assert!(super::Missions::parse_enc_opt("utf-8,same-block=maybe").is_err());
+
+        assert!(super::Missions::parse_enc_opt("utf-8,len=forty").is_err());
+
+        assert_eq!(
+            super::Missions::parse_enc_opt("utf-8,10,label=utf8-long").unwrap(),
+            EncOpt {
+                enc_name: Some("utf-8"),
+                chars_min_nb: Some(10),
+                label: Some("utf8-long"),
+                ..Default::default()
+            }
+        );
+
+        assert!(super::Missions::parse_enc_opt("utf-8,label=").is_err());
+
+        assert!(super::Missions::parse_enc_opt("utf-8,label=a b").is_err());
+    }
+
+    #[test]
+    fn test_enc_opt_from_config() {
+        // Unlike in `--encoding` definitions, `,` is a regular character.
+        let mc = MissionConfig {
+            encoding: Some("UTF-16LE".to_string()),
+            chars_min: Some(6),
+            unicode_block_filter: Some("0x4".to_string()),
+            grep_char: Some(",".to_string()),
+            grep_pattern: Some("a,b|c".to_string()),
+            same_unicode_block: Some(true),
+            label: Some("utf16".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            Missions::enc_opt_from_config(&mc).unwrap(),
+            EncOpt {
+                enc_name: Some("UTF-16LE"),
+                chars_min_nb: Some(6),
+                filter_ubf: Some(0x4),
+                grep_char: Some(','),
+                grep_patterns: Some(vec!["a,b".to_string(), "c".to_string()]),
+                same_unicode_block: Some(true),
+                label: Some("utf16"),
+                ..Default::default()
+            }
         );
+
+        let mc = MissionConfig {
+            label: Some("a,b".to_string()),
+            ..Default::default()
+        };
+        assert!(Missions::enc_opt_from_config(&mc).is_err());
+        let mc = MissionConfig {
+            unicode_block_filter: Some("Klingon".to_string()),
+            ..Default::default()
+        };
+        assert!(Missions::enc_opt_from_config(&mc).is_err());
+    }
+
+    /// Creates `Missions` from the command-line arguments `args`.
+    fn missions(args: &[&str]) -> Result<Missions> {
+        use crate::options::Args;
+        use structopt::StructOpt;
+        let args = Args::from_iter(args);
+        let mission_defs: Vec<_> = args
+            .encoding
+            .iter()
+            .map(|e| MissionDef::EncOpt(e.clone()))
+            .collect();
+        Missions::new(
+            args.counter_offset.as_ref(),
+            &mission_defs,
+            args.chars_min.as_ref(),
+            args.chars_max.as_ref(),
+            args.same_unicode_block,
+            args.max_scripts.as_ref(),
+            args.mojibake,
+            args.ascii_filter.as_ref(),
+            args.unicode_block_filter.as_ref(),
+            args.grep_char.as_ref(),
+            args.script_filter.as_ref(),
+            args.grep_pattern.as_ref(),
+            args.ignore_case,
+            args.ignore_diacritics,
+            args.nfkc,
+            args.regex.as_ref(),
+            args.invert_match,
+            args.keyword.as_ref(),
+            args.min_score.as_ref(),
+            args.min_entropy.as_ref(),
+            args.max_run.as_ref(),
+            args.min_distinct.as_ref(),
+            &args.word_list,
+            args.min_word_ratio.as_ref(),
+            args.identify_language,
+            args.language.as_ref(),
+            args.merge.is_some(),
+            args.format,
+            args.columns.as_ref(),
+            args.delimiter.as_ref(),
+            args.output_line_len.as_ref(),
+        )
+    }
+
+    #[test]
+    fn test_merge_line_len() {
+        assert!(missions(&["stringsext", "--merge=same", "-e", "ascii", "-e", "utf-8"]).is_ok());
+        assert!(missions(&[
+            "stringsext",
+            "--merge=same",
+            "-e",
+            "ascii,len=40",
+            "-e",
+            "utf-8"
+        ])
+        .is_err());
+        assert!(missions(&["stringsext", "-e", "ascii,len=40", "-e", "utf-8"]).is_ok());
+    }
+
+    #[test]
+    fn test_keyword_not_encodable() {
+        // The ASCII scanner is skipped, the KOI8-R scanner keeps its label.
+        let m = missions(&["stringsext", "-k", "пароль", "-e", "ascii", "-e", "koi8-r"]).unwrap();
+        assert_eq!(m.len(), 1);
+        assert_eq!(m.v[0].mission_id, 0);
+        assert_eq!(m.v[0].def_id, 1);
+        assert_eq!(m.v[0].label, "b");
+        assert_eq!(m.v[0].encoding_name(), "KOI8-R");
+
+        assert!(missions(&["stringsext", "-k", "пароль", "-e", "ascii"]).is_err());
+        assert!(missions(&["stringsext", "-k", "", "-e", "utf-8"]).is_err());
+    }
+
+    #[test]
+    fn test_delimited_options() {
+        assert!(missions(&["stringsext", "--format=csv", "--columns=text"]).is_ok());
+        assert!(missions(&["stringsext", "--format=tsv", "--delimiter=;"]).is_ok());
+        assert!(missions(&["stringsext", "--columns=text"]).is_err());
+        assert!(missions(&["stringsext", "--format=jsonl", "--delimiter=;"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_grep_char() {
+        let parse = |s| Missions::parse_grep_char(Some(s)).unwrap();
+        assert_eq!(parse("€"), Some('€'));
+        assert_eq!(parse("U+20AC"), Some('€'));
+        assert_eq!(parse("64"), Some('@'));
+        assert_eq!(parse("0x40"), Some('@'));
+        // A single digit is a code.
+        assert_eq!(parse("9"), Some('\t'));
+        assert_eq!(parse("U+0039"), Some('9'));
+        assert!(Missions::parse_grep_char(Some("U+D800")).is_err());
+    }
+
+    #[test]
+    fn test_mission_label() {
+        assert_eq!(mission_label(0), "a");
+        assert_eq!(mission_label(25), "z");
+        assert_eq!(mission_label(26), "aa");
+        assert_eq!(mission_label(27), "ab");
+        assert_eq!(mission_label(26 + 26 * 26 - 1), "zz");
+        assert_eq!(mission_label(26 + 26 * 26), "aaa");
     }
 }
@@ -0,0 +1,397 @@
+//! Detect and repair mojibake caused by UTF-8 that was encoded twice.
+//!
+//! When UTF-8 encoded text is wrongly interpreted as Latin-1 or
+//! Windows-1252 and then encoded again as UTF-8, every multi-byte character
+//! turns into a sequence of two to four characters, e.g. `é` becomes `Ã©` and
+//! `’` becomes `â€™`. This module offers two ways to deal with this:
+//!
+//! 1. `repair()` analyses a finding after decoding and restores the original
+//!    text.
+//! 2. `DoubleUtf8Decoder` decodes the doubly encoded byte stream directly. It
+//!    is used by the synthetic `double-UTF-8` encoding.
+
+use encoding_rs::DecoderResult;
+
+/// Windows-1252 characters in the range `0x80..=0x9F`. Undefined positions
+/// are `None`; Latin-1 maps them to the C1 control with the same code.
+const WINDOWS_1252_C1: [Option<char>; 32] = [
+    Some('€'),
+    None,
+    Some('‚'),
+    Some('ƒ'),
+    Some('„'),
+    Some('…'),
+    Some('†'),
+    Some('‡'),
+    Some('ˆ'),
+    Some('‰'),
+    Some('Š'),
+    Some('‹'),
+    Some('Œ'),
+    None,
+    Some('Ž'),
+    None,
+    None,
+    Some('‘'),
+    Some('’'),
+    Some('“'),
+    Some('”'),
+    Some('•'),
+    Some('–'),
+    Some('—'),
+    Some('˜'),
+    Some('™'),
+    Some('š'),
+    Some('›'),
+    Some('œ'),
+    None,
+    Some('ž'),
+    Some('Ÿ'),
+];
+
+/// Maps a character back to the byte it was decoded from, assuming the byte
+/// was interpreted as Latin-1 or as Windows-1252.
+#[inline]
+fn char_to_byte(c: char) -> Option<u8> {
+    if (c as u32) < 0x100 {
+        return Some(c as u32 as u8);
+    };
+    WINDOWS_1252_C1
+        .iter()
+        .position(|w| *w == Some(c))
+        .map(|i| 0x80 + i as u8)
+}
+
+/// Interprets the byte `b` as Windows-1252. Bytes undefined in Windows-1252
+/// are interpreted as Latin-1.
+#[inline]
+pub fn byte_to_char(b: u8) -> char {
+    match b {
+        0x80..=0x9F => WINDOWS_1252_C1[(b - 0x80) as usize].unwrap_or(b as char),
+        _ => b as char,
+    }
+}
+
+/// Number of bytes of a UTF-8 sequence starting with the leading byte `b`.
+/// Returns `None` for bytes that can not start a multi-byte sequence.
+#[inline]
+fn multibyte_len(b: u8) -> Option<usize> {
+    match b {
+        0xC2..=0xDF => Some(2),
+        0xE0..=0xEF => Some(3),
+        0xF0..=0xF4 => Some(4),
+        _ => None,
+    }
+}
+
+/// Searches `s` for character sequences that are doubly encoded UTF-8 and
+/// decodes them once more. All other characters are copied unchanged.
+/// Returns `None` when no such sequence was found.
+pub fn repair(s: &str) -> Option<String> {
+    // The first encoding's bytes, if the character can be one.
+    let v: Vec<(char, Option<u8>)> = s.chars().map(|c| (c, char_to_byte(c))).collect();
+
+    let mut repaired = String::with_capacity(s.len());
+    let mut repaired_nb = 0usize;
+    let mut i = 0;
+    while i < v.len() {
+        if let Some(len) = v[i].1.and_then(multibyte_len) {
+            if i + len <= v.len() {
+                let mut bytes = [0u8; 4];
+                let mut complete = true;
+                for (j, b) in bytes.iter_mut().enumerate().take(len) {
+                    match v[i + j].1 {
+                        Some(byte) => *b = byte,
+                        None => {
+                            complete = false;
+                            break;
+                        }
+                    }
+                }
+                if complete {
+                    if let Ok(d) = std::str::from_utf8(&bytes[..len]) {
+                        repaired.push_str(d);
+                        repaired_nb += 1;
+                        i += len;
+                        continue;
+                    }
+                }
+            }
+        }
+        repaired.push(v[i].0);
+        i += 1;
+    }
+
+    if repaired_nb > 0 {
+        Some(repaired)
+    } else {
+        None
+    }
+}
+
+/// A decoder for the synthetic `double-UTF-8` encoding. It mimics the
+/// interface of `encoding_rs::Decoder`: the outer UTF-8 layer is decoded
+/// first, every resulting character is mapped back to its Latin-1 or
+/// Windows-1252 byte and these bytes are decoded as UTF-8 again. Both layers
+/// may be cut at buffer boundaries, which is why the decoder keeps the bytes
+/// of incomplete sequences in its inner state.
+#[derive(Default)]
+pub struct DoubleUtf8Decoder {
+    /// Bytes of an incomplete outer UTF-8 sequence.
+    outer: [u8; 4],
+    /// Number of valid bytes in `outer`.
+    outer_len: usize,
+    /// Bytes of an incomplete inner UTF-8 sequence.
+    inner: [u8; 4],
+    /// Number of valid bytes in `inner`.
+    inner_len: usize,
+    /// A complete outer character ending an invalid inner sequence. It may
+    /// start a new one: the next call decodes it first.
+    pending_outer: Option<char>,
+}
+
+impl DoubleUtf8Decoder {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget incomplete sequences.
+    #[inline]
+    fn reset(&mut self) {
+        self.outer_len = 0;
+        self.inner_len = 0;
+        self.pending_outer = None;
+    }
+
+    /// Decodes `src` into `dst`, stopping after the first invalid sequence.
+    /// Returns the same tuple as
+    /// `encoding_rs::Decoder::decode_to_str_without_replacement()`: the reason
+    /// for returning, the number of bytes read and the number of bytes
+    /// written. When an outer character ends an invalid inner sequence, it is
+    /// kept for the next call and reported as the extra bytes of
+    /// `DecoderResult::Malformed`, also if it started in an earlier call.
+    pub fn decode_to_str_without_replacement(
+        &mut self,
+        src: &[u8],
+        dst: &mut str,
+        last: bool,
+    ) -> (DecoderResult, usize, usize) {
+        // This is safe, as long as we only write complete UTF-8 sequences and
+        // repair the bytes after the written ones (see below).
+        let dst = unsafe { dst.as_bytes_mut() };
+        let mut read = 0;
+        let mut written = 0;
+        let mut result = DecoderResult::InputEmpty;
+
+        while self.pending_outer.is_some() || read < src.len() {
+            // We need space for the longest character.
+            if dst.len() - written < 4 {
+                result = DecoderResult::OutputFull;
+                break;
+            }
+
+            // Outer layer.
+            let outer_char = if let Some(c) = self.pending_outer.take() {
+                c
+            } else if self.outer_len == 0 {
+                let b = src[read];
+                read += 1;
+                if b < 0x80 {
+                    b as char
+                } else if multibyte_len(b).is_some() {
+                    self.outer[0] = b;
+                    self.outer_len = 1;
+                    continue;
+                } else {
+                    self.reset();
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+            } else {
+                let b = src[read];
+                if b & 0b1100_0000 != 0b1000_0000 {
+                    // `b` is not consumed, it may start the next sequence.
+                    self.reset();
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+                read += 1;
+                self.outer[self.outer_len] = b;
+                self.outer_len += 1;
+                // The leading byte was checked before.
+                if self.outer_len < multibyte_len(self.outer[0]).unwrap() {
+                    continue;
+                }
+                let c = std::str::from_utf8(&self.outer[..self.outer_len])
+                    .ok()
+                    .and_then(|s| s.chars().next());
+                self.outer_len = 0;
+                match c {
+                    Some(c) => c,
+                    None => {
+                        self.reset();
+                        result = DecoderResult::Malformed(1, 0);
+                        break;
+                    }
+                }
+            };
+
+            // Inner layer.
+            let inner_byte = match char_to_byte(outer_char) {
+                Some(ib) => ib,
+                None => {
+                    self.reset();
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+            };
+            if self.inner_len == 0 {
+                if inner_byte < 0x80 {
+                    dst[written] = inner_byte;
+                    written += 1;
+                } else if multibyte_len(inner_byte).is_some() {
+                    self.inner[0] = inner_byte;
+                    self.inner_len = 1;
+                } else {
+                    self.reset();
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+            } else {
+                if inner_byte & 0b1100_0000 != 0b1000_0000 {
+                    // The incomplete sequence is invalid, but the current
+                    // character may start a new one: we decode it again.
+                    self.reset();
+                    self.pending_outer = Some(outer_char);
+                    result = DecoderResult::Malformed(1, outer_char.len_utf8() as u8);
+                    break;
+                }
+                self.inner[self.inner_len] = inner_byte;
+                self.inner_len += 1;
+                if self.inner_len < multibyte_len(self.inner[0]).unwrap() {
+                    continue;
+                }
+                let len = self.inner_len;
+                self.inner_len = 0;
+                if std::str::from_utf8(&self.inner[..len]).is_err() {
+                    self.reset();
+                    result = DecoderResult::Malformed(1, 0);
+                    break;
+                }
+                dst[written..written + len].copy_from_slice(&self.inner[..len]);
+                written += len;
+            }
+        }
+
+        if last && result == DecoderResult::InputEmpty && (self.outer_len > 0 || self.inner_len > 0)
+        {
+            // The stream ends in the middle of a sequence.
+            self.reset();
+            result = DecoderResult::Malformed(1, 0);
+        }
+
+        // We may have overwritten the first bytes of a multibyte character.
+        // Its orphan continuation bytes must go, so that `dst` stays valid
+        // UTF-8.
+        let mut i = written;
+        while i < dst.len() && dst[i] & 0b1100_0000 == 0b1000_0000 {
+            dst[i] = 0;
+            i += 1;
+        }
+        (result, read, written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repair() {
+        assert_eq!(repair("CafÃ© au lait"), Some("Café au lait".to_string()));
+        assert_eq!(repair("itâ€™s"), Some("it’s".to_string()));
+        assert_eq!(repair("Ã¼berÐ¼Ð¸Ñ€"), Some("überмир".to_string()));
+        // Only the doubly encoded part is repaired.
+        assert_eq!(repair("€Ã©Ã"), Some("€éÃ".to_string()));
+        assert_eq!(repair("Café au lait"), None);
+        assert_eq!(repair("Ã and ©"), None);
+        assert_eq!(repair("plain ASCII"), None);
+    }
+
+    #[test]
+    fn test_decode() {
+        let input = "CafÃ© â€™x".as_bytes();
+        let mut buf = [0u8; 32];
+
+        let mut d = DoubleUtf8Decoder::new();
+        let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+        let (res, read, written) = d.decode_to_str_without_replacement(input, dst, true);
+        assert_eq!(res, DecoderResult::InputEmpty);
+        assert_eq!(read, input.len());
+        assert_eq!(&dst[..written], "Café ’x");
+
+        // The same input cut in pieces at every byte.
+        let mut d = DoubleUtf8Decoder::new();
+        let mut out = String::new();
+        for (i, b) in input.iter().enumerate() {
+            let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+            let (res, read, written) =
+                d.decode_to_str_without_replacement(&[*b], dst, i == input.len() - 1);
+            assert_eq!(res, DecoderResult::InputEmpty);
+            assert_eq!(read, 1);
+            out.push_str(&dst[..written]);
+        }
+        assert_eq!(out, "Café ’x");
+
+        // Simply encoded UTF-8 is not valid.
+        let mut d = DoubleUtf8Decoder::new();
+        let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+        let (res, read, written) =
+            d.decode_to_str_without_replacement("abé€cd".as_bytes(), dst, false);
+        assert_eq!(res, DecoderResult::Malformed(1, 1));
+        assert_eq!(&dst[..written], "ab");
+        // `c` is read, but decoded with the next call.
+        assert_eq!(read, 8);
+        let (res, read, written) = d.decode_to_str_without_replacement(b"d", dst, true);
+        assert_eq!(res, DecoderResult::InputEmpty);
+        assert_eq!(read, 1);
+        assert_eq!(&dst[..written], "cd");
+    }
+
+    /// Decodes `input` cut at `cut` and returns the output and the number of
+    /// malformed sequences.
+    fn decode_cut(input: &[u8], cut: usize) -> (String, usize) {
+        let mut d = DoubleUtf8Decoder::new();
+        let mut buf = [0u8; 64];
+        let mut out = String::new();
+        let mut malformed = 0;
+        for (src, last) in [(&input[..cut], false), (&input[cut..], true)] {
+            let mut read = 0;
+            loop {
+                let dst = std::str::from_utf8_mut(&mut buf).unwrap();
+                let (res, r, written) =
+                    d.decode_to_str_without_replacement(&src[read..], dst, last);
+                read += r;
+                out.push_str(&dst[..written]);
+                match res {
+                    DecoderResult::Malformed(_, _) => malformed += 1,
+                    _ if read == src.len() => break,
+                    _ => {}
+                }
+            }
+        }
+        (out, malformed)
+    }
+
+    #[test]
+    fn test_decode_cut() {
+        // The inner sequence of "é€" is invalid, "Ã" starts the next one.
+        let input = "abé€Ã©x é€cd".as_bytes();
+        let expected = decode_cut(input, input.len());
+        assert_eq!(expected, ("abéx cd".to_string(), 2));
+        for cut in 0..input.len() {
+            assert_eq!(decode_cut(input, cut), expected, "cut at {}", cut);
+        }
+    }
+}
@@ -11,6 +11,14 @@ use structopt::StructOpt;
 /// ASCII-decoder.
 pub const ASCII_ENC_LABEL: &str = "ascii";
 
+/// Prefix of an encoding name denoting a custom single-byte encoding. The
+/// rest of the name is the path of a mapping file, e.g. `file:mytable.txt`.
+pub const CHAR_MAP_ENC_PREFIX: &str = "file:";
+
+/// Encoding name of the synthetic encoding searching for UTF-8 that was
+/// encoded twice.
+pub const DOUBLE_UTF8_ENC_LABEL: &str = "double-UTF-8";
+
 /// If no command-line argument `--chars_min` is given
 /// and none is specified in `--encoding` use this.
 /// Must be one of `--list-encodings`.
@@ -48,6 +56,9 @@ pub struct Args {
     /// never print byte-counter, encoding or filter
     #[structopt(long, short = "c")]
     pub no_metadata: bool,
+    /// sample the input and add the encodings yielding the most text
+    #[structopt(long)]
+    pub auto: bool,
     #[structopt(long, short = "d")]
     /// show how command-line-options are interpreted
     pub debug_option: bool,
@@ -57,24 +68,107 @@ pub struct Args {
     /// set (multiple) encodings to search for
     #[structopt(long, short = "e")]
     pub encoding: Vec<String>,
-    /// grep for characters with ASCII-code in output lines
+    /// grep for a character in output lines, given as itself, `U+XXXX` or
+    /// decimal or `0x` code (single digits are codes, `U+0030` is `0`)
     #[structopt(long, short = "g")]
     pub grep_char: Option<String>,
+    /// print only findings containing one of the `|`-separated patterns
+    /// (strings up to 64 KiB are searched as a whole)
+    #[structopt(long, short = "G")]
+    pub grep_pattern: Option<String>,
+    /// search KEYWORD in raw bytes first, decode only around hits
+    #[structopt(long, short = "k")]
+    pub keyword: Option<String>,
+    /// ignore case in `--grep-pattern` (Unicode case folding)
+    #[structopt(long, short = "i")]
+    pub ignore_case: bool,
+    /// ignore diacritics in `--grep-pattern`
+    #[structopt(long)]
+    pub ignore_diacritics: bool,
+    /// compare `--grep-pattern` under NFKC normalization
+    #[structopt(long)]
+    pub nfkc: bool,
     #[structopt(long, short = "l")]
     /// list predefined encoding and filter names for ENC
     pub list_encodings: bool,
+    /// print repaired strings for findings with doubly encoded UTF-8
+    #[structopt(long, short = "m")]
+    pub mojibake: bool,
     #[structopt(long, short = "n")]
     /// minimum characters of printed strings
     pub chars_min: Option<String>,
+    /// maximum characters of printed strings, wrapped lines counted together
+    #[structopt(long, short = "N")]
+    pub chars_max: Option<String>,
+    /// print only findings looking like natural language with at least
+    /// NUM percent plausibility (0-100)
+    #[structopt(long)]
+    pub min_score: Option<String>,
+    /// print only findings with at least BITS Shannon entropy per character
+    #[structopt(long)]
+    pub min_entropy: Option<String>,
+    /// print only findings repeating no character more than NUM times in a row
+    #[structopt(long)]
+    pub max_run: Option<String>,
+    /// print only findings with at least NUM distinct characters
+    #[structopt(long)]
+    pub min_distinct: Option<String>,
+    /// word list file, one word per line (multiple allowed)
+    #[structopt(long, short = "W", parse(from_os_str))]
+    pub word_list: Vec<PathBuf>,
+    /// print only findings with at least PERCENT words of `--word-list`
+    #[structopt(long)]
+    pub min_word_ratio: Option<String>,
+    /// print the language of findings (ISO 639 code and confidence)
+    #[structopt(long)]
+    pub identify_language: bool,
+    /// print only findings in one of the `,`-separated languages
+    /// (findings too short to identify are printed)
+    #[structopt(long, short = "L")]
+    pub language: Option<String>,
+    /// add the missions of a profile defined in the configuration files
+    #[structopt(long, short = "P")]
+    pub profile: Vec<String>,
+    /// read profiles also from this configuration file
+    #[structopt(long, parse(from_os_str))]
+    pub config: Option<PathBuf>,
     #[structopt(long, short = "r")]
     /// require chars in finding to be in the same Unicode-block
     pub same_unicode_block: bool,
+    #[structopt(long, short = "R")]
+    /// allow at most NUM Unicode scripts in a finding
+    pub max_scripts: Option<String>,
     #[structopt(long, short = "p", parse(from_os_str))]
     /// print not to stdout but in file
     pub output: Option<PathBuf>,
+    /// output format: `text`, `jsonl` (JSON Lines), `csv` or `tsv`
+    #[structopt(long, default_value = "text")]
+    pub format: OutputFormat,
+    /// with `--format csv` or `tsv`: the `,`-separated columns to print
+    #[structopt(long)]
+    pub columns: Option<String>,
+    /// with `--format csv` or `tsv`: the column separator
+    #[structopt(long)]
+    pub delimiter: Option<String>,
+    /// print findings of several encodings once: `same` or `longest`
+    #[structopt(long)]
+    pub merge: Option<MergePolicy>,
     /// output line length in Unicode-codepoints
     #[structopt(long, short = "q")]
     pub output_line_len: Option<String>,
+    /// print every distinct string once with its number of occurrences,
+    /// sorted by `count` or `first` position
+    #[structopt(long)]
+    pub unique: Option<UniqueOrder>,
+    /// with `--unique`, count strings of each encoding separately
+    #[structopt(long)]
+    pub unique_per_encoding: bool,
+    /// accept only multi-byte chars of these Unicode scripts or blocks
+    #[structopt(long, short = "S")]
+    pub script_filter: Option<String>,
+    /// print scan statistics to stderr as `text` or `json`
+    #[structopt(long)]
+    pub stats: Option<StatsFormat>,
     /// start counting input bytes with NUM
     #[structopt(long, short = "s")]
     pub counter_offset: Option<String>,
@@ -88,6 +182,13 @@ pub struct Args {
     /// print version and exit
     #[structopt(long, short = "V")]
     pub version: bool,
+    /// print only findings not matching `--regex`
+    #[structopt(long, short = "v")]
+    pub invert_match: bool,
+    /// print only findings matching the regular expression (strings up to
+    /// 64 KiB are matched as a whole)
+    #[structopt(long, short = "x")]
+    pub regex: Option<String>,
 }
 
 #[derive(Debug, Hash, Clone, Eq, PartialEq, Copy)]
@@ -113,6 +214,96 @@ impl FromStr for Radix {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Copy)]
+/// format of the findings printed
+pub enum OutputFormat {
+    // human readable, tab separated
+    Text,
+    // one JSON object per line
+    Jsonl,
+    // comma separated values with header row
+    Csv,
+    // tab separated values with header row
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+    fn from_str(format: &str) -> Result<OutputFormat, Self::Err> {
+        match &*format.to_ascii_lowercase() {
+            "text" => Ok(OutputFormat::Text),
+            "jsonl" => Ok(OutputFormat::Jsonl),
+            "csv" => Ok(OutputFormat::Csv),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(String::from(
+                "can not convert format, try `text`, `jsonl`, `csv` or `tsv`",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Copy)]
+/// how `--merge` collapses findings of different missions from the same bytes
+pub enum MergePolicy {
+    // one line for identical findings, listing all their encodings
+    Same,
+    // as `Same`, and drop findings contained in a longer finding
+    Longest,
+}
+
+impl FromStr for MergePolicy {
+    type Err = String;
+    fn from_str(policy: &str) -> Result<MergePolicy, Self::Err> {
+        match &*policy.to_ascii_lowercase() {
+            "same" => Ok(MergePolicy::Same),
+            "longest" => Ok(MergePolicy::Longest),
+            _ => Err(String::from(
+                "can not convert merge policy, try `same` or `longest`",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Copy)]
+/// order of the distinct strings printed by `--unique`
+pub enum UniqueOrder {
+    // most frequent first
+    Count,
+    // in order of their first occurrence
+    First,
+}
+
+impl FromStr for UniqueOrder {
+    type Err = String;
+    fn from_str(order: &str) -> Result<UniqueOrder, Self::Err> {
+        match &*order.to_ascii_lowercase() {
+            "count" => Ok(UniqueOrder::Count),
+            "first" => Ok(UniqueOrder::First),
+            _ => Err(String::from(
+                "can not convert order, try `count` or `first`",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Copy)]
+/// format of the `--stats` report
+pub enum StatsFormat {
+    Text,
+    Json,
+}
+
+impl FromStr for StatsFormat {
+    type Err = String;
+    fn from_str(format: &str) -> Result<StatsFormat, Self::Err> {
+        match &*format.to_ascii_lowercase() {
+            "text" => Ok(StatsFormat::Text),
+            "json" => Ok(StatsFormat::Json),
+            _ => Err(String::from("can not convert format, try `text` or `json`")),
+        }
+    }
+}
+
 lazy_static! {
 /// Structure to hold the parsed command-line arguments.
 pub static ref ARGS : Args = Args::from_args();
@@ -124,7 +315,7 @@ mod tests {
     /// Are the command-line option read and processed correctly?
     #[test]
     fn test_arg_parser() {
-        use super::{Args, Radix};
+        use super::{Args, MergePolicy, OutputFormat, Radix, StatsFormat, UniqueOrder};
         use std::path::PathBuf;
         use structopt::StructOpt;
 
@@ -135,6 +326,8 @@ mod tests {
             "-d",
             "-n",
             "10",
+            "-N",
+            "40",
             "-g",
             "64",
             "-e",
@@ -152,6 +345,53 @@ mod tests {
             "-t",
             "o",
             "-r",
+            "-R",
+            "2",
+            "-m",
+            "-G",
+            "password|пароль",
+            "-x",
+            r"\d{4}",
+            "-v",
+            "-k",
+            "пароль",
+            "-i",
+            "--nfkc",
+            "--min-score",
+            "50",
+            "--min-entropy",
+            "1.5",
+            "--max-run",
+            "4",
+            "--min-distinct",
+            "3",
+            "-W",
+            "words.txt",
+            "--min-word-ratio",
+            "60",
+            "--identify-language",
+            "-L",
+            "de,en",
+            "-P",
+            "russian",
+            "--auto",
+            "--config",
+            "my.toml",
+            "-S",
+            "Han,Hiragana",
+            "--merge",
+            "longest",
+            "--unique",
+            "first",
+            "--unique-per-encoding",
+            "--stats",
+            "json",
+            "--format",
+            "csv",
+            "--columns",
+            "offset,text",
+            "--delimiter",
+            ";",
             "infile1",
             "infile2",
         ];
@@ -167,9 +407,38 @@ mod tests {
         assert!(args.version);
         assert!(args.list_encodings);
         assert_eq!(args.chars_min, Some("10".to_string()));
+        assert_eq!(args.chars_max, Some("40".to_string()));
         assert!(args.same_unicode_block);
+        assert_eq!(args.max_scripts, Some("2".to_string()));
+        assert!(args.mojibake);
         assert_eq!(args.grep_char, Some("64".to_string()));
+        assert_eq!(args.grep_pattern, Some("password|пароль".to_string()));
+        assert_eq!(args.regex, Some(r"\d{4}".to_string()));
+        assert!(args.invert_match);
+        assert_eq!(args.keyword, Some("пароль".to_string()));
+        assert!(args.ignore_case);
+        assert!(args.nfkc);
+        assert!(!args.ignore_diacritics);
+        assert_eq!(args.min_score, Some("50".to_string()));
+        assert_eq!(args.min_entropy, Some("1.5".to_string()));
+        assert_eq!(args.max_run, Some("4".to_string()));
+        assert_eq!(args.min_distinct, Some("3".to_string()));
+        assert_eq!(args.word_list, vec![PathBuf::from("words.txt")]);
+        assert_eq!(args.min_word_ratio, Some("60".to_string()));
+        assert!(args.identify_language);
+        assert_eq!(args.language, Some("de,en".to_string()));
+        assert_eq!(args.profile, vec!["russian".to_string()]);
+        assert!(args.auto);
+        assert_eq!(args.config, Some(PathBuf::from("my.toml")));
+        assert_eq!(args.script_filter, Some("Han,Hiragana".to_string()));
         assert_eq!(args.radix, Some(Radix::O));
+        assert_eq!(args.merge, Some(MergePolicy::Longest));
+        assert_eq!(args.unique, Some(UniqueOrder::First));
+        assert!(args.unique_per_encoding);
+        assert_eq!(args.stats, Some(StatsFormat::Json));
+        assert_eq!(args.format, OutputFormat::Csv);
+        assert_eq!(args.columns, Some("offset,text".to_string()));
+        assert_eq!(args.delimiter, Some(";".to_string()));
         assert_eq!(args.counter_offset, Some("1500".to_string()));
         assert_eq!(args.output, Some(PathBuf::from("outfile")));
         assert_eq!(args.output_line_len, Some("40".to_string()));
@@ -0,0 +1,128 @@
+//! Rate how much a finding looks like natural language text.
+//!
+//! About 96% of all random byte pairs decode as valid UTF-16, and random
+//! characters often pass the Unicode-block-filter. Real text, however, is
+//! made of a small set of frequent character sequences. `score()` counts how
+//! many of a finding's character bigrams - for Han and Hangul: characters -
+//! are known from the built-in tables in `plausibility_tables`.
+
+use crate::plausibility_tables::{BIGRAMS, BIGRAM_SCRIPTS, UNIGRAMS, UNIGRAM_SCRIPTS};
+use crate::script_filter::ScriptFilter;
+use lazy_static::lazy_static;
+use unicode_normalization::char::is_combining_mark;
+
+lazy_static! {
+    /// Letters of these scripts are rated by bigrams.
+    static ref BIGRAM_SCRIPT_FILTER: ScriptFilter = ScriptFilter::parse(BIGRAM_SCRIPTS).unwrap();
+    /// Letters of these scripts are rated one by one.
+    static ref UNIGRAM_SCRIPT_FILTER: ScriptFilter = ScriptFilter::parse(UNIGRAM_SCRIPTS).unwrap();
+    /// `BIGRAMS` as sorted list.
+    static ref BIGRAM_LIST: Vec<(char, char)> = {
+        let chars: Vec<char> = BIGRAMS.chars().collect();
+        chars.chunks_exact(2).map(|b| (b[0], b[1])).collect()
+    };
+    /// `UNIGRAMS` as sorted list.
+    static ref UNIGRAM_LIST: Vec<char> = UNIGRAMS.chars().collect();
+}
+
+/// Number of unknown bigrams added to every string, see `score()`.
+const SMOOTHING: usize = 2;
+
+/// A character as seen by the model.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Token {
+    /// Anything that is not a letter: whitespace, digits, punctuation...
+    Boundary,
+    /// A lowercase letter and whether its script has a bigram table.
+    Letter(char, bool),
+}
+
+/// Lowercases `c`, when this results in one character.
+#[inline]
+pub fn lower(c: char) -> char {
+    let mut l = c.to_lowercase();
+    if l.len() == 1 {
+        l.next().unwrap()
+    } else {
+        c
+    }
+}
+
+/// Returns the percentage of known bigrams and characters in `s` - slightly
+/// lowered for short strings - or `None`
+/// when `s` has nothing to rate, e.g. when it consists of digits only or of
+/// letters of scripts without table. Bigrams consist of two letters or of a
+/// letter and a word boundary. Letters of different scripts never form a
+/// known bigram.
+pub fn score(s: &str) -> Option<u8> {
+    let mut known = 0usize;
+    let mut total = 0usize;
+    let mut prev = None;
+    for c in s.chars() {
+        let t = if c.is_alphabetic() || is_combining_mark(c) {
+            let l = lower(c);
+            if UNIGRAM_SCRIPT_FILTER.pass(c) {
+                total += 1;
+                if UNIGRAM_LIST.binary_search(&l).is_ok() {
+                    known += 1;
+                }
+                prev = None;
+                continue;
+            }
+            Token::Letter(l, BIGRAM_SCRIPT_FILTER.pass(c))
+        } else {
+            Token::Boundary
+        };
+        let bigram = match (prev, t) {
+            (Some(Token::Boundary), Token::Letter(l, true)) => Some((' ', l)),
+            (Some(Token::Letter(l, true)), Token::Boundary) => Some((l, ' ')),
+            (Some(Token::Letter(a, a_has_table)), Token::Letter(b, b_has_table))
+                if a_has_table || b_has_table =>
+            {
+                Some((a, b))
+            }
+            _ => None,
+        };
+        if let Some(bigram) = bigram {
+            total += 1;
+            if BIGRAM_LIST.binary_search(&bigram).is_ok() {
+                known += 1;
+            }
+        }
+        prev = Some(t);
+    }
+    if total == 0 {
+        return None;
+    }
+    // Short strings give little evidence: two imaginary unknown bigrams
+    // keep them from scoring high by chance.
+    Some((known * 100 / (total + SMOOTHING)) as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_score() {
+        // Natural language.
+        assert!(score("The file could not be opened.").unwrap() >= 50);
+        assert!(score("Der Schlüssel wurde nicht gefunden").unwrap() >= 50);
+        assert!(score("Не удалось открыть файл").unwrap() >= 50);
+        assert!(score("Το αρχείο δεν βρέθηκε").unwrap() >= 50);
+        assert!(score("文件不存在或无法打开").unwrap() >= 50);
+        assert!(score("파일을 열 수 없습니다").unwrap() >= 50);
+        assert!(score("ファイルを開けません").unwrap() >= 50);
+
+        // Random characters, as they appear when decoding binary data as
+        // UTF-16.
+        assert!(score("ꁥ쐢䳷ᐐ綨뱕ㅏ霆ꍗ").unwrap() < 50);
+        assert!(score("ÃÐþÿÆ¢ÝÑ").unwrap() < 50);
+        assert!(score("џђЋѓЏћѝ").unwrap() < 50);
+        assert!(score("qxzjvkqw").unwrap() < 50);
+
+        // Nothing to rate.
+        assert_eq!(score("1234 5678"), None);
+        assert_eq!(score(""), None);
+    }
+}
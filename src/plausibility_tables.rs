@@ -0,0 +1,685 @@
+//! Built-in tables for `plausibility::score()`.
+//!
+//! Generated by `scripts/make-plausibility-tables` from the gettext message
+//! catalogs of a Linux system. Do not edit.
+
+/// Scripts with a table of known bigrams.
+pub const BIGRAM_SCRIPTS: &str = "Arabic,Armenian,Bengali,Cyrillic,Devanagari,Georgian,Greek,Gujarati,Gurmukhi,Hebrew,Hiragana,Kannada,Katakana,Khmer,Latin,Malayalam,Myanmar,Oriya,Sinhala,Tamil,Telugu,Thai,Tibetan";
+
+/// Scripts with a table of known characters.
+pub const UNIGRAM_SCRIPTS: &str = "Han,Hangul";
+
+/// Known bigrams, sorted, two characters each. A space stands for the
+/// boundary of a word.
+pub const BIGRAMS: &str = concat!(
+    " a b c d e f g h i j k l m n o p q r s t u v w x",
+    " y z à á ä å ç è é î ö ú ü č đ š ά έ ή α β γ δ ε",
+    " η θ ι κ λ μ ν ο π ρ σ τ υ φ χ ω ό а б в г д е ж",
+    " з и к л м н о п р с т у ф х ц ч ш щ э я є і ј ա",
+    " բ գ դ ե զ է ը թ ժ ի լ խ կ հ ձ մ ն շ ո չ պ ս վ տ",
+    " փ ք օ ֆ և ֤ א ב ג ד ה ו ז ח ט י כ ל מ נ ס ע פ ץ",
+    " צ ק ר ש ת װ ء آ أ إ ئ ا ب ت ج ح خ د ذ ر ز س ش ص",
+    " ض ط ع غ ف ق ك ل م ن ه و ي ټ پ چ ډ ښ ک گ ھ ۋ ی अ",
+    " आ इ ई उ ऊ ए ऑ ओ औ क ख ग घ च छ ज झ ट ठ ड ढ त थ द",
+    " ध न प फ ब भ म य र ल व श स ह ॖ অ আ ই উ এ ও ক খ গ",
+    " চ ছ জ ট ঠ ড ত থ দ ধ ন প ফ ব ভ ম য র ল শ স হ ৰ ਅ",
+    " ਆ ਇ ਈ ਉ ਐ ਓ ਕ ਖ ਗ ਘ ਚ ਛ ਜ ਝ ਟ ਡ ਢ ਤ ਥ ਦ ਨ ਪ ਫ ਬ",
+    " ਭ ਮ ਯ ਰ ਲ ਵ ਸ਼ ਸ ਹ ਜ਼ અ આ ઇ ઈ ઉ ઊ એ ઓ ક ખ ગ ઘ ચ છ",
+    " જ ઝ ટ ડ ત થ દ ધ ન પ ફ બ ભ મ ય ર લ વ શ સ હ ଅ ଆ ଇ",
+    " ଉ ଏ ଓ କ ଖ ଗ ଘ ଚ ଛ ଜ ଟ ଠ ଡ ତ ଥ ଦ ଧ ନ ପ ଫ ବ ଭ ମ ଯ",
+    " ର ଲ ଶ ସ ହ ୟ ୱ அ ஆ இ உ எ ஏ ஐ ஒ ஓ க ச ஜ ட த ந ப ம",
+    " ய ர ல வ ஸ ஹ అ ఆ ఇ ఈ ఉ ఊ ఎ ఏ ఐ ఒ క ఖ గ చ జ ట డ త",
+    " థ ద న ప ఫ బ భ మ య ర ల వ శ స హ ಅ ಆ ಇ ಈ ಉ ಎ ಒ ಓ ಕ",
+    " ಖ ಗ ಘ ಚ ಜ ಟ ಡ ತ ದ ನ ಪ ಫ ಬ ಭ ಮ ಯ ರ ಲ ವ ಶ ಸ ಹ അ ആ",
+    " ഇ ഈ ഉ എ ഏ ഐ ഒ ഓ ക ഗ ഘ ച ജ ട ഡ ണ ത ദ ന പ ഫ ബ ഭ മ",
+    " യ ര റ ല വ ശ ഷ സ ഹ අ ආ ඇ ඉ උ එ ඔ ක ග ජ ට ණ ත ද න",
+    " ප බ භ ම ය ර ල ව ශ ෂ ස හ ෆ ก ข ค จ ช ซ ด ต ถ ท น",
+    " บ ป ผ พ ม ย ร ล ศ ส ห อ เ แ โ ใ ไ ཀ ཁ ག ང ཅ ཆ ཇ",
+    " ཉ ཊ ཌ ཏ ཐ ད ན པ ཕ བ མ ཚ ཝ ཞ ཟ འ ཡ ར ལ ཤ ས ཧ ཨ က",
+    " ခ င စ ဆ ည တ ထ ဒ န ပ ဖ ဘ မ ယ ရ လ သ ဟ အ ဦ ა ბ გ დ",
+    " ე ვ ზ თ ი კ ლ მ ნ ო პ რ ს ტ უ ფ ქ შ ჩ ც ძ წ ხ ჯ",
+    " ក ខ គ ឃ ច ឆ ជ ឈ ដ ណ ត ថ ទ ធ ន ប ផ ព ភ ម យ រ ល វ",
+    " ស ហ ឡ អ ឥ ឬ ឯ ឲ あ お か が こ さ し す そ つ で と な に の は",
+    " へ ま も や よ を ア イ ウ エ オ カ キ ク グ ゲ コ サ シ ジ ス セ ゼ ソ",
+    " タ ダ チ テ デ ト ド ニ ネ ノ ハ バ パ ヒ ビ ピ フ ブ プ ヘ ベ ペ ホ ボ",
+    " ポ マ ミ メ モ ユ ラ リ ル レ ロ ワa aaabacadaeafagahaiajak",
+    "alamanaoapaqarasatauavawaxayazaçaíaćačağałaşašb ",
+    "babbbebhbibjblbmbnbobrbsbtbubyc cacccdcechcicjck",
+    "clcocpcrcsctcucyczcácícócăd dadbdddedgdhdidjdkdl",
+    "dmdndodpdrdsdtdudvdwdydzdádédıe eaebecedeeefegeh",
+    "eiejekelemeneoepeqereseteuevewexeyezeçečeğešf fa",
+    "fdfefffiflfofrfsftfufyföfüg gagbgdgeggghgigjgkgl",
+    "gmgngogpgrgsgtgugwgygögüh hahdhehihkhlhmhnhohrht",
+    "huhvhyháhéhôhưhểi iaibicidieifigihiiijikiliminio",
+    "ipiqirisitiuiviwixiyiziçióičięişišișiếiểiệj jaje",
+    "jijljnjojsjtjujäk kakbkckekgkhkikkklknkokpkrkskt",
+    "kukvkwkykákäkékökýl lalblcldlelflglhliljlklllmln",
+    "lolplsltlulvlxlylzlàlálälélílólülım mambmdmemhmi",
+    "mlmmmnmompmrmsmtmumymámämåmémın nanbncndnenfngnh",
+    "ninjnknlnmnnnonpnrnsntnunvnynznánãnänénínúnýnıo ",
+    "oaobocodoeofogohoiojokolomonoooporosotouovowoxoy",
+    "ozočośożožp papbpcpdpepgphpipkplpmpnpoppprpsptpu",
+    "pypåpçpépípřq qlqur rarbrcrdrerfrgrhrirjrkrlrmrn",
+    "rorprqrrrsrtrurvrwryrzrárärårérírórürıs sasbscsd",
+    "sesfsgshsisjskslsmsnsospsqsrssstsusvswsyszsásãsä",
+    "sésísúsüsısốt tatctdtetftgthtitktltmtntotptrtstt",
+    "tutvtwtxtytztàtátätètétêtítótötütătıtậu uaubucud",
+    "ueufuguhuiujukulumunuoupurusutuuuvuwuxuyuzučużuž",
+    "v vavevivlvnvovrvsvuvyvàvávävévýw wawewhwiwnwowr",
+    "wswuwyx xaxcxexixpxty yaybycydyeygyiyjykylymynyo",
+    "ypyrysytyuywyız zazbzdzezhzizlzmznzozpztzuzvzwzy",
+    "zzzázăà á ácáiájálámánárásátávázãoä älänäräsätäy",
+    "ääå ånçaçeçiçãçõè èré écéeéféléménépérésétênêtë ",
+    "í ícídíkímínísítívînó ódónórówônõeönörösövørú úb",
+    "úmülümünürüsý ă ą ącć čačečičnđưę ğiı ıkılımınır",
+    "ışłałořeříścş şlştšašešiškštť ų żnżyžažežiượșiți",
+    "ά άγάδάζάθάκάλάμάνάράσάτάφέαέγέθέκέλένέξέπέρέςέσ",
+    "έτέχή ήθήκήμήνήρήςήσήτί ίαίδίείζίηίμίνίοίπίρίσίτ",
+    "ίωα αίαβαγαδαζαθαιακαλαμαναξαπαραςασαταυαφαχβάβα",
+    "βεβιβλβοβργέγήγίγαγγγεγιγκγλγμγνγογργχγωδέδίδαδε",
+    "δηδιδοδρδυδύε είεγεδεθειεκελεμενεξεπερεςεσετευεφ",
+    "εχεωεύζεζοη ηγηθηκηλημηνηρηςησητθέθήθαθεθηθμθοθυ",
+    "ι ιάιήιαιβιγιδιειηιθικιλιμινιοιπιριςισιτιχιόιώκ ",
+    "κάκέκήκίκακδκεκηκικλκοκρκτκυκωκόκώλάλέλήλίλαλελη",
+    "λιλλλμλολυλωλόλύλώμάμέμήμίμαμβμεμημιμμμνμομπμφμό",
+    "ν νάνένήνίνανγνδνενηνθνινονσντνωνόξαξεξηξοο οίοβ",
+    "ογοδοεοηοθοιοκολομονοποροςοσοτουοφοχούπάπέπίπαπε",
+    "πηπιπλποπρπτπωπόράρέρήρίραργρερηριρμρορτρυρχρωρό",
+    "ρώς σίσασεσησισκσμσοσπσσστσυσφσχσωσόσύτάτέτήτίτα",
+    "τετητιτοτρτυτωτότύυ υαυγυθυλυμυνυπυρυςυσυτυχφάφή",
+    "φαφιφοχήχίχαχεχιχοχρχωχώψηω ωγωδωμωνωρωςωσωτό όγ",
+    "όδόλόμόνόπόρόςόσότόχύ ύθύμύνύπύρύσώμώνώρώσа аааб",
+    "авагадаеажазайакаламанаоапарасатауафахацачашащаю",
+    "аяаєајањаўб бабебибкблбнбобрбубщбъбыбів вавввдве",
+    "вивквлвмвнвовпврвсвтвувхвъвывявівљг гагегиглгнго",
+    "гргугід дадбдвдддедждидкдлдндодпдрдсдтдудъдыдіе ",
+    "ебевегедееежезеиейекелеменеоепересетеуехецечешещ",
+    "ељењећжажджежижнжуз зазбзвздзезизкзлзмзнзозпзрзу",
+    "зызязіи иаибивигидиеизииийикилиминиоипириситифих",
+    "ицичишищиюияијй йдйлйнйойсйтк каквкекиклкнкокркс",
+    "кткукцкщкъкіл лалглдлелжлзлилклнлолтлулыльлюлялі",
+    "м мамбмвмемимкмлмммнмомпмумымямін нангндненинкнн",
+    "нонснтнунфнцныньнюняніо обовогодоеожозоиойоколом",
+    "онооопоросотоцочошоюояоїојп папепиплпопрпсптпупц",
+    "пъпір рарвргрдрержриркрмрнрорсртрурхршрърырэрярі",
+    "с сасвсесискслсмснсоспсрссстсусхсъсысьсясіт татв",
+    "тетитктнтотртстттутътытьтэтіу уаубувугудуеужузук",
+    "улумунупурусутуфучушущуюуєујфафефифофрфуфіх хахе",
+    "хихохрхіц цацецицыцьцяціч чачечичкчнчтчучіш шаше",
+    "шишкшнштшущащещищоъдъзълъръты ывыеыйыкылымыныпыр",
+    "ытыхь ьзькьньоьсьтьшэлэнэтю ювютючющя явядяеязяк",
+    "ямянятёнє єті іаібівігідізійікілімінірісітічішія",
+    "іїї ј јајејуљаљељуњањећеա աբագադազաթալախածակահաղ",
+    "ամայանաշաոաչապաջառասավատարացափաֆբ բաբեբիբյբոբրգա",
+    "գեգիգլգոգվգտգրդ դադեդիդոդրե եաեգեզեթելեծեկեղեմեյ",
+    "ենեջեռեսետերեցեքզազբզիզմզրէ էջէրը ընթաթիթյթոժաժե",
+    "ժոի իաիբիլիկիմինիշիոիչիպիջիսիվիտիրիցիքլ լալելըլի",
+    "լխլնլոխախեխոծ ծեծոծվծրծքկ կակեկըկիկղկնկոկտկրկցհա",
+    "հեհիհղհմհնհոձրձևղ ղաղեղզղիղծղմղյղողվճամ մամբմեմը",
+    "միմնմոյայեյթյիյլյնյոյսյտյցն նանգնդնենընթնինկնհնմ",
+    "նյնննշնոնչնջնսնվնտնրնցնքշ շաշեշըշիշխշնշոշվշտո ոդ",
+    "ոլոխոկողոմոյոնոշոչոսովոտորոցուոփոքչ չաչեչէչիչհչո",
+    "պապեպիպոպրջ ջաջիջոռառեռնռոս սասեսիսլսխսկսոսպսվստ",
+    "սցսքվ վավեվիվյվնվովվվրտ տատետըտիտկտնտոտվտրտքր րա",
+    "րբրգրդրերըրժրիրլրծրկրձրմրնրորպրջրսրվրտրրրքց ցացե",
+    "ցիցնցոցվւ ւաւգւթւծւղւմւյւնւսւտւրւցփափոք քաքըքիքն",
+    "քոքսօբօգֆաֆեֆիֆոֆրև ևա֤ ַ ַבַזַטַכַלַמַןַנַסַפַק",
+    "ַרַשָדָזָטָלָמָנָסָפָקָרּאּיּלּעּרֿאֿוֿטֿעא אַאָ",
+    "אבאגאדאהאואזאחאטאיאלאםאמאןאנאסאפאקאראשאתאױאײב בא",
+    "בדבהבובחבטביבלבמבןבנבסבעבץבצבקברבשבתג גאגדגהגוגי",
+    "גלגנגעגרד דאדבדהדודידכדלדמדעדפדצדרדשה האהבהגהדהה",
+    "הוהחהיהלהמהנהסהעהפהצהקהרהשהתהײו ואובוגודוהוווזוח",
+    "וטויוךוכולוםומוןונוסועוףופוץוצוקורושותז זאזהזוזי",
+    "זכזמזןזעזרזײח חבחדחהחוחיחלחרחשחתט טאטבטהטוטיטלטמ",
+    "טןטנטעטפטצטרי יאיביגידיהיויזיחיטיייךיכיליםימיןינ",
+    "יסיעיפיציקירישיתך כּכדכוכטכיכלכןכנכעכפכרכשכתל לא",
+    "לבלגלדלהלולחלטלילכלללמלןלנלעלפלקלשלתלײם מאמבמדמה",
+    "מומזמחמטמימךמכמלמממןמנמסמעמפמצמקמרמשמתן נאנבנגנד",
+    "נהנונזנטנינכנלנמנסנענצנקנשנתס סאסגסהסוסטסיסלסמסן",
+    "סנסעספסקסרסתע עבעגעדעהעועטעיעךעכעלעםעמעןענעסעףעפ",
+    "עקערעשעתעװף פ פּפֿפהפופיפלפןפנפספעפקפרפשפתץ צ צא",
+    "צבצגצוצטציצעצפצרצװצײק קאקבקהקוקטקיקלקןקנקסקעקצקר",
+    "קשקײר רארברגרדרהרורחרטרירךרכרלרמרןרנרסרערפרצרקרש",
+    "רתרױרײש שאשבשגשהשושטשישלשםשמשןשנשעשפשקשרשתת תאתב",
+    "תותחתיתכתמתןתקתרװאװיװעװײױבױסײַײדײטײכײןײנײסء ءةآل",
+    "آنأ أبأثأحأخأدأرأسأعأفأقأكألأمأنأوأيؤثؤشإبإدإذإس",
+    "إضإعإفإلإنإيئائرئلئمئنئوئىئيئۆئۇئۈئیئېئەا اءائاب",
+    "اتاثاجاحاخاداذارازاساشاصاضاطاعاغافاقاكالاماناهاو",
+    "اياًاپاچاکاڭاگاںاھاۋایب بابةبتبحبدبربسبشبطبعبقبك",
+    "بلبمبنبهبوبىبيبکبڼبۆبۇبیبېبەة ت تؤتاتبتةتتتجتحتخ",
+    "تدترتستشتصتطتظتعتغتفتقتكتلتمتنتهتوتىتيتّتچتکتۇتۈ",
+    "تیتېتەث ثاثبثرثلثنثيج جاجبجةجججدجرجزجسجعجلجمجنجه",
+    "جوجىجيجۇجیجەح حاحةحتحجحدحذحرحزحسحصحلحمحهحوحيخ خا",
+    "ختخدخرخزخشخصخطخفخلخهخوخىخيخیخەد دادةدجدخدددردسدش",
+    "دعدفدلدمدندهدودىديدّدۆدۇدۋدیدېدەذ ذاذةذرذهذيذّر ",
+    "رئراربرةرترجرخردرزرسرشرصرضرغرفرقركرلرمرنرهرورىري",
+    "رّرپرچرکرگرۇرۈریرېرەز زازبزدزرزغزلزمزنزهزوزىزيزگ",
+    "زۇزۈزیزەس ساسبسةستسخسرسطسعسفسقسكسلسمسنسهسوسىسيسّ",
+    "سپسکسۆسۇسۈسیسېسەش شاشتشخشدشرشغشفشقشكشلشمشنشهشوشى",
+    "شيشکشۇشیشەص صاصحصدصرصفصلصنصوصيصّصیض ضاضبضغضيط طأ",
+    "طاطبطةطرطعطقطلطوطيظاظرظهع عاعبعةعتعثعدعذعرعلعمعن",
+    "عوعيعیغ غاغةغرغطغلغوغىغيغۇغیغەـ ف فافةفتفحفذفرفس",
+    "فشفضفظفعفغفقفلفنفهفوفىفيفیق قاقبقةقتقدقرقسقشقصقط",
+    "قعقفقلقمقنقوقىقيقۇقیقەك كاكبكةكتكركسكككلكمكنكوكى",
+    "كيكۆكۇكۈكېكەل لألإلالبلةلتلثلجلحلخلدلذلرلزلسلشلص",
+    "لطلعلغلـلفلقلكلللملنلهلولىليلّلپلکلگلۇلیلېلەم مؤ",
+    "مامبمةمتمثمجمحمخمدمرمزمسمشمصمضمطمظمعمغمفمقمكملمم",
+    "منمهمومىميمُمکمھمۇمیمېمەن نانبنةنتنجندنرنزنسنشنص",
+    "نطنظنغنفنقنكنلنمنننهنونىنينٹنځنچنڈنډنښنکنګنگنۆنۇ",
+    "نینېنەه هاهةهذهرهلهمهنهوهيهٔهیو وئواوبوتوجوحوخود",
+    "وروزوسوشوصوضوطوعوغوفوقوكولومونوهوووىويوپوړوښوکوڭ",
+    "وگوںویوېوەى ىتىجىدىرىزىسىشىغىقىكىلىمىنىيىپىچىڭىگ",
+    "ىھىۋي يايبيةيتيثيجيحيديريزيسيشيصيطيعيغيفيقيكيليم",
+    "ينيهيويىيييّيږيکيۆيۇيۈيېيەً ًاِنِکّ ّاّةّحّدّرّص",
+    "ّعّلّئ ٹاٹوټاټوپ پاپتپرپسپشپكپلپنپهپوپىپۈپیپېپە",
+    "ځوچ چاچقچنچوچىچۇچۈچیچېچەڈاڈوڈیډوړنړوړکږيښتښوک کا",
+    "کبکتکرکسکشکلکمکنکهکوکيکچکړکۆکیکۍکېګوڭ ڭشڭلڭنڭىگ ",
+    "گاگذگرگزگلگنگوگىگۈگیگەھاھلھىھۆھۇھەۆتۆجۆرۆزۆلۆمۆن",
+    "ۆچۇ ۇبۇتۇرۇزۇسۇشۇقۇلۇمۇنۇيۇپۇچۈتۈرۈزۈسۈشۈكۈلۈنۈچ",
+    "ۋاۋىۋېۋەی یایبیتیجیدیریزیسیشیصیفیقیلیمینیهیویپیچ",
+    "یکیگیہییۍ ې ېتېدېرېزېسېشېغېقېكېلېمېنېيېپېڭېگە ەت",
+    "ەخەدەرەزەسەشەغەقەكەلەمەنەيەپەکەڭەگەھەۋەیँ ँकँगँच",
+    "ँटँदं ंकंखंगंचंजंटंडंतंदंधंपंबंभंयंरंवंसः अंअकअग",
+    "अछअजअतअथअदअधअनअपअफअबअमअयअरअलअवअशअसअहआ आंआइआईआउआक",
+    "आगआढआणआदआधआनआपआबआयआरआलआवआहइ इंइकइजइटइडइतइनइपइमइर",
+    "इलइवइसई ईलउँउघउजउटउतउदउनउपउरउलउसऊ ए एँएंएउएकएचएत",
+    "एनएबएमएरएलएसऑफओ ओजओडओनओरओलओळऔजऔरक कंकएकककचकटकडकत",
+    "कनकपकबकमकरकलकळकसकाकिकीकुकूकृकॅकेकैकॉकोक्खनखरखलखव",
+    "खाखिखीखुखूखोख्ग गकगडगणगतगनगमगयगरगलगागिगीगुगूगेगो",
+    "ग्घटघडघाघुङ ङ्च चओचकचतचनचयचरचलचाचिचीचुचेचोचौच्छ ",
+    "छपछवछाछिछेछैछोज जँजएजकजनजपजबजमजरजवजसजहज़जाजिजीजु",
+    "जूजेजोज्झाझिझेझ्ञाञ्ट टकटचटणटनटपटमटरटलटवटसटाटिटी",
+    "टुटूटॅटेटैटॉटोट्ठ ठभठाठीठेठ्ड डकडणडमडरडलडसड़डाडि",
+    "डीडुडूडेडोड्ढळढ़ढाढूढ्ण णकणधणनणपणरणाणिणीणेण्त तं",
+    "तःतकतततनतपतबतमतयतरतलतवतातितीतुतेतोत्थ थनथमथवथाथि",
+    "थीद दकदछदतदनदरदलदसदादिदीदुदूदृदेदैदोद्ध धनधरधाधि",
+    "धीध्न नकनगनटनतनपनमनयनरनलनवनसनहनानिनीनुनूनेनैनोनौ",
+    "न्नॴप पंपकपटपडपढपणपतपथपदपनपपपबपमपयपरपलपशपसपहपापि",
+    "पीपुपूपृपॅपेपैपॉपोप्फ फरफलफ़फाफिफुफेफोफ्ब बंबकबट",
+    "बढबतबदबनबफबमबरबलबसबहबाबिबीबुबूबॅबेबैबोब्भ भएभनभय",
+    "भरभाभिभीभूभेम मंमकमगमजमतमदमधमनमबमममयमरमलमसमहमामि",
+    "मीमुमूमृमॅमेमैमॉमोमौम्य यंयकयचयजयतयदयनयमयरयलयवयश",
+    "यसयहयायियीयुयूयेयोर रँरंरकरखरगरङरचरजरणरतरदरनरपरफ",
+    "रबरभरमरयरररलरवरसरहरारिरीरुरूरॅरेरैरॉरोर्ल लंलकलग",
+    "लचलटलतलनलपलबलभलमलललवलसलहलालिलीलुलूलॅलेलैलॉलोल्ळ ",
+    "ळलळाळीव वडवणवतवनवयवरवलवशवसवाविवीवुवृवेवैवोव्श शक",
+    "शनशवशाशिशीशुशूशेशैशोश्ष षकषतषमषरषाषिषीषेषैष्स सँ",
+    "संसकसङसञसतसदसनसफसबसभसमसरसलसवसहसासिसीसुसूसॅसेसॉसो",
+    "स्सॴह हटहरहलहवहाहिहीहुहेहैहोह़् ़ऩा़े़ॉा ाँांाइ",
+    "ाईाउाऊाएाओाकाखागाचाजाझाटाठाडाढाणाताथादाधानापाफाब",
+    "ामायारालाळावाशाषासाहि िंिअिआिएिओिकिखिगिङिचिजिटिड",
+    "िणितिदिधिनिपिफिबिभिमियिरिलिविशिषिसिही ींीकीचीजीट",
+    "ीडीतीनीपीमीयीरीलीवीसु ुँुंुअुआुइुएुकुखुगुचुजुटुड",
+    "ुणुतुदुधुनुपुबुमुयुरुलुळुवुसुहू ूकूचूटूतूदूनूपूम",
+    "ूरूलूहृतृशृषॅंॅकॅगॅटॅनॅमे ेँेंेकेखेगेचेजेटेडेणेत",
+    "ेदेनेपेबेमेयेरेलेळेवेशेषेसेहै ैंैकैगैछैडैतैधैनैप",
+    "ैबैमैरैलैसॉकॉगॉनॉपॉरॉलो ोंोअोआोइोईोएोकोगोजोटोडोण",
+    "ोतोधोनोपोफोबोमोयोरोलोवोषोसोहौकौडौर् ्क्ग्च्छ्ज्झ",
+    "्ञ्ट्ठ्ड्ण्त्थ्द्ध्न्प्फ्ब्भ्म्य्र्ल्व्श्ष्स्हॖन",
+    "ঁ ঁকঁতং ংকংখংগংযংরংশংসংৰঅংঅকঅজঅতঅথঅনঅপঅবঅভঅসঅ্অৱ",
+    "আঁআইআকআখআগআছআনআপআফআবআমআরআলআয়আৰই ইংইউইকইজইটইডইতইন",
+    "ইভইরইলইসইয়উ উইউচউজউটউতউদউনউপউলএ এইএকএটএনএবএরএলএস",
+    "ও ওপওযওয়ক কটকতকনকবকমকরকলকশকসকাকিকীকুকৃকেকোক্কৰখ ",
+    "খতখনখাখিখুখোখ্খৰগ গটগণগতগলগাগিগুগেগোগ্ঘ্ঙ্ঙৰচ চক",
+    "চতচনচরচলচাচিচীচুচেচ্ছতছবছাছিছুছেজ জতজনজাজিজুজেজো",
+    "জ্ঞাঞ্ট টকটনটপটভটরটাটিটুটেটোট্টৰঠাঠিড ডাডিডেডোড্",
+    "ণ ণতণীণেণ্ণৰত তকতথতনতবতমতরতলতাতিতীতুতেতৈতোত্তৰথ ",
+    "থবথাথিথেথ্দ দকদনদরদলদাদিদুদৃদেদৈদ্দৰধ ধরধাধিধুধ্",
+    "ধৰন নকনগনটনতনথনপনমনযনরনলনসনহনানিনীনুনেনোন্নয়নৰপ ",
+    "পংপটপডপতপথপদপনপপপরপলপশপসপাপিপুপূপৃপেপোপ্পড়পৰফনফর",
+    "ফলফাফিফেফোফ্ব বংবকবচবজবতবধবনবমবরবলবশবসবহবাবিবীবু",
+    "বৃবেবৈবোব্বৰভ ভবভাভিভুভূভেভ্ম মকমগমডমতমধমনমবমরমস",
+    "মামিমুমূমেমোম্ময়মৰয যকযতযদযনযবযমযরয়যাযিযুযেযোয্",
+    "যৰযৱর রংরকরগরঙরচরজরণরতরদরনরপরবরভরমরযরলরসরহরারিরী",
+    "রুরূরেরোর্রয়ল লকলগলটলতলনলবলমলালিলীলুলেলৈলোল্লৰশ ",
+    "শকশনশবশাশিশীশুশূশেশ্ষ ষণষরষাষিষেষ্স সংসওসকসটসতসন",
+    "সমসরসলসহসাসিসীসুসূসেসোস্সৰহ হওহচহণহবহলহাহিহুহৃহে",
+    "হৈহোহ্হয়় ়া়ো াঁাংাইাউাওাকাখাগাঙাচাছাজাটাডাণাত",
+    "াথাদাধানাপাফাবাভামাযারালাশাষাসাহায়াৰি িংিউিওিকিখ",
+    "িগিঙিচিছিজিটিডিণিতিদিনিপিফিবিভিমিযিরিলিশিষিসিহিয়",
+    "িৰী ীকীতীনীপীমীরীয়ীৰু ুইুকুগুচুঞুটুডুণুতুদুধুনুপ",
+    "ুবুভুমুযুরুলুসুয়ুৰুৱূচূণূপূমূরূহূৰৃতৃশৃষে েইেওেক",
+    "েখেগেচেছেজেঞেটেডেণেতেদেনেপেবেভেমেরেলেশেষেসেয়েৰৈ ",
+    "ৈছৈধৈরৈশো োঁোকোগোচোজোটোডোতোদোনোবোভোমোরোলোসোয়োৰোৱ",
+    "্ ্ক্গ্ঘ্চ্ছ্জ্ঞ্ট্ঠ্ড্ণ্ত্থ্দ্ধ্ন্প্ব্ভ্ম্য্র্ল",
+    "্শ্ষ্স্ৰড়ায় য়কয়তয়নয়লয়ায়িয়ীয়েয়োৰ ৰংৰকৰজৰঞৰণৰতৰদৰন",
+    "ৰমৰসৰাৰিৰীৰুৰেৰোৰ্ৰয়ৰৰৱহৱাৱেৱৰਂ ਂਕਂਗਂਚਂਜਂਟਂਡਂਦਂਬ",
+    "ਂਸਅਕਅਗਅਚਅਟਅਡਅਣਅਤਅਦਅਧਅਨਅਪਅਫਅਬਅਮਅਯਅਰਅਲਅਵਅਸਅੰਅੱਆ ਆਂ",
+    "ਆਈਆਉਆਓਆਕਆਟਆਨਆਪਆਫਆਬਆਮਆਰਆਲਆਸਇਆਇਕਇਟਇਡਇਨਇਬਇਰਇਲਇਵਇਸਇਹ",
+    "ਇੰਇੱਈ ਈਆਈਕਈਜਈਟਈਡਈਨਈਪਈਮਈਰਈਲਈਵਈਸਉ ਉਚਉਟਉਣਉਪਉੱਊ ਊਂਊਕ",
+    "ਊਟਊਨਊਮਊਰਊਲਊਸਏ ਏਚਐਂਐਕਐਡਐਨਐਪਓ ਓਕਓਟਓਨਓਰਓਲਓਸਕ ਕਗਕਟਕਤ",
+    "ਕਦਕਨਕਪਕਬਕਮਕਯਕਰਕਲਕਵਕਸਕਾਕਿਕੀਕੁਕੂਕੇਕੈਕੋਕੌਕ੍ਕੰਖ ਖਣਖਤ",
+    "ਖਰਖਾਖਿਖੇਖੋਖੱਗ ਗਈਗਕਗਣਗਨਗਬਗਰਗਲਗਵਗਸਗਾਗਿਗੀਗੁਗੂਗੇਗੈਗੋ",
+    "ਘਾਘੀਘੱਚ ਚਨਚਲਚਾਚਿਚੀਚੁਚੂਚੇਚੋਚੌਛਮਛਾਜ ਜਦਜਨਜਰਜ਼ਜਾਜਿਜੀ",
+    "ਜੁਜੂਜੇਜੈਜੋਝ ਝਲਟ ਟਕਟਨਟਪਟਮਟਰਟਲਟਵਟਸਟਾਟਿਟੀਟੂਟੇਟੈਟੋਟਜ਼",
+    "ਠਾਠੀਡ ਡਗਡਜਡਰਡਲਡਾਡਿਡੀਡੁਡੂਡੇਡੈਡੋਢੁਢੰਣ ਣਕਣਜਣਤਣਯਣਰਣਾ",
+    "ਣਿਣੀਣੇਤ ਤਕਤਣਤਨਤਬਤਮਤਰਤਾਤਿਤੀਤੁਤੇਤੋਥ ਥਾਥਿਥੋਦ ਦਗਦਦਦਬ",
+    "ਦਰਦਲਦਸਦਾਦਿਦੀਦੁਦੂਦੇਦੋਦੌਦੱਧ ਧਾਧਿਧੀਧੂਨ ਨਕਨਗਨਜਨਟਨਡਨਯ",
+    "ਨਰਨਲਨਵਨਸਨਹਨਾਨਿਨੀਨੁਨੂਨੇਨੈਨੋਨਜ਼ਪ ਪਟਪਡਪਤਪਬਪਰਪਲਪਸਪਹਪਾ",
+    "ਪਿਪੀਪੁਪੂਪੇਪੈਪੋਪ੍ਪੜਪੱਫ ਫਰਫ਼ਫਾਫਿਫੀਫੁਫੂਫੇਫੈਫੋਬ ਬਕਬਜ",
+    "ਬਟਬਣਬਦਬਫਬਰਬਲਬਵਬਹਬਾਬਿਬੀਬੁਬੂਬੇਬੈਬੋਬੰਬੱਭਾਭੇਮ ਮਟਮਨਮਪ",
+    "ਮਬਮਯਮਰਮਲਮਵਮਸਮਾਮਿਮੀਮੁਮੂਮੇਮੈਮੋਮੌਮੰਮੱਯ ਯਾਯਿਯੀਯੂਯੇਯੋ",
+    "ਰ ਰਕਰਗਰਜਰਟਰਡਰਤਰਦਰਨਰਫਰਬਰਭਰਮਰਲਰਵਰਸਰਹਰਾਰਿਰੀਰੁਰੂਰੇਰੈ",
+    "ਰੋਰੰਰੱਲ ਲਈਲਕਲਗਲਟਲਡਲਣਲਤਲਨਲਪਲਫਲਬਲਮਲਯਲਰਲਵਲਸਲਾਲਿਲੀਲੂ",
+    "ਲੇਲੈਲੋਲੌਲ੍ਲੰਲੱਵ ਵਨਵਰਵਲਵਾਵਿਵੀਵੂਵੇਵੈਵੋਵੱਸ਼ ਸ਼ਨਸ਼ਾਸ਼ੀਸ਼ੂ",
+    "ਸ਼ੇਸ ਸਕਸਟਸਤਸਥਸਨਸਪਸਫਸਬਸਮਸਰਸਲਸਵਸਹਸ਼ਸਾਸਿਸੀਸੁਸੂਸੇਸੈਸੋ",
+    "ਸੰਸੱਹ ਹਟਹਣਹਨਹਰਹਲਹਾਹਿਹੀਹੁਹੂਹੇਹੈਹੋ਼ ਼ਨ਼ਬ਼ਰ਼ਲ਼ਾ਼ੀ਼ੁ",
+    "਼ੂ਼ੇ਼ੈਾ ਾਂਾਅਾਇਾਈਾਉਾਊਾਏਾਓਾਕਾਖਾਗਾਚਾਜਾਟਾਡਾਣਾਤਾਥਾਦਾਧ",
+    "ਾਨਾਪਾਫਾਬਾਮਾਯਾਰਾਲਾਵਾਸ਼ਾਸਾਹਾਜ਼ਿ ਿਆਿਊਿਓਿਕਿਖਿਗਿਚਿਜਿਟਿਡ",
+    "ਿਣਿਤਿਦਿਨਿਪਿਫਿਬਿਮਿਯਿਰਿਲਿਵਿਸ਼ਿਸਿਹਿੰਿੱੀ ੀਂੀਅੀਆੀਊੀਏੀਓ",
+    "ੀਕੀਗੀਟੀਡੀਤੀਦੀਨੀਪੀਬੀਮੀਯੀਰੀਲੀਵੀਸੁਆੁਕੁਝੁਡੁਣੁਤੁਨੁਮੁਰ",
+    "ੁਲੁਵੁਸੁਹੁੜੁੰੁੱੂ ੂਆੂਇੂਈੂਏੂਓੂਕੂਗੂਚੂਜੂਟੂਡੂਦੂਨੂਪੂਬੂਮ",
+    "ੂਯੂਰੂਲੂਵੂਸੂਹੂੰੇ ੇਂੇਅੇਈੇਓੇਕੇਖੇਗੇਜੇਟੇਠੇਡੇਣੇਤੇਨੇਪੇਬ",
+    "ੇਮੇਯੇਰੇਲੇਵੇਸ਼ੇਸੇਹੇਜ਼ੈ ੈਂੈਕੈਗੈਟੈਡੈਨੈਪੈਬੈਮੈਰੈਲੈਵੈਸੈੱ",
+    "ੋ ੋਂੋਆੋਇੋਈੋਊੋਕੋਗੋਚੋਜੋਟੋਡੋਣੋਤੋਧੋਨੋਪੋਫੋਬੋਮੋਯੋਰੋਲੋਵ",
+    "ੋਸੋਹੋਜ਼ੋੜੌਂੌਜੌਰੌੜ੍ਰ੍ਹਜ਼ਹਜ਼ਾਜ਼ਿਜ਼ੀਜ਼ੂਜ਼ੇਜ਼ੋੜ ੜਾੜੀੜ੍ੰ ੰਕੰਗ",
+    "ੰਘੰਚੰਜੰਟੰਡੰਤੰਦੰਨੰਪੰਬੰਭੰਮੰਸੱਕੱਖੱਗੱਚੱਛੱਜੱਟੱਡੱਤੱਥੱਦ",
+    "ੱਧੱਪੱਬੱਭੱਲੱਸં ંકંખંગંચંજંડંતંથંદંધંનંબંભંમંવંસઅં",
+    "અકઅજઅથઅદઅનઅપઅમઅયઅરઅલઅવઅસઆ આઇઆગઆડઆતઆદઆધઆનઆપઆરઆવઆસ",
+    "ઇ ઇએઇકઇચઇટઇનઇરઇલઇસઈ ઈએઈનઈલઉતઉનઉપઉમઊંઊભએ એકએનઓ ઓન",
+    "ઓફઓસક કંકડકતકનકપકમકરકલકવકસકાકિકીકુકેકોક્ખતખરખલખવ",
+    "ખસખાખીખેખોખ્ગ ગડગણગતગનગમગયગળગાગિગીગુગેગોગૌગ્ઘટઘણ",
+    "ઘાચ ચકચનચલચવચાચિચીચુચેચોચ્છાછિછીછેછોજ જગજનજમજરજા",
+    "જિજીજુજૂજેજોજ્ઝબઝાઝીઞાટ ટકટનટમટરટલટવટાટિટીટુટેટો",
+    "ટ્ઠવડ ડનડમડરડવડાડિડીડેડોડ્ણ ણકણધણનણાણીણેત તંતનતમ",
+    "તરતાતિતીતુતૃતેતોત્થ થઇથમથયથવથાથિથીથેથોદ દકદગદદદન",
+    "દમદરદલદસદાદિદીદુદૂદૃદેદોદ્ધ ધનધરધાધિધીધ્ન નંનકનગ",
+    "નતનથનનનપનમનલનવનહનાનિનીનુનેનોન્પ પછપટપડપણપતપદપનપબ",
+    "પયપરપલપવપષપસપહપાપિપીપુપૂપેપોપ્ફ ફકફરફળફાફિફેફોફ્",
+    "બ બંબટબતબદબધબનબફબરબલબહબાબિબીબુબેબૉબોબ્ભ ભનભરભાભી",
+    "ભૂમ મણમતમદમધમનમમમયમરમલમળમહમામિમીમુમૂમેમોમ્ય યકયન",
+    "યમયસયાયુયૂયેયોર રંરકરગરજરણરતરદરનરફરબરભરમરલરવરશરસ",
+    "રહરારિરીરુરૂરેરોર્લ લંલકલખલનલપલબલમલવલાલિલીલુલૅલે",
+    "લોલ્ળ ળતળનળભળવળાળીળુળોળ્વ વખવગવચવણવતવધવનવપવરવવવસ",
+    "વાવિવીવુવૃવેવૈવોવ્શ શકશનશબશરશાશિશીશુશેશૈશોશ્ષકષણ",
+    "ષરષાષિષ્સ સંસકસતસનસપસમસરસવસાસિસીસુસૂસેસોસ્હ હતહર",
+    "હસહાહિહીહેહોહ્ા ાંાઇાઈાઉાઓાકાખાગાચાજાટાડાઢાણાતાથ",
+    "ાદાધાનાપાબામાયારાલાળાવાશાષાસાહિ િંિઆિકિગિજિટિડિણ",
+    "િતિનિપિબિભિમિયિરિલિવિશિષિસિહી ીંીઅીઆીએીઓીકીચીજીટ",
+    "ીતીનીપીબીમીયીરીલીશીસુ ુંુઆુએુઓુકુગુટુણુતુધુનુપુબ",
+    "ુમુયુરુલુવુસૂ ૂકૂચૂતૂથૂનૂપૂરૂલૂળૃતૃશૅને ેઓેકેખેગ",
+    "ેજેટેડેતેથેદેનેપેબેમેયેરેલેળેવેશેસૈલો ોંોઇોઈોકોગ",
+    "ોચોજોટોઠોડોતોધોનોપોબોમોયોરોલોળોવોષોસૌણ્ક્ગ્ચ્છ્જ",
+    "્ઝ્ઞ્ટ્ડ્ણ્ત્થ્દ્ધ્ન્પ્ફ્બ્ભ્મ્ય્ર્લ્વ્શ્ષ્સ્હଁ ",
+    "ଂ ଂକଂଖଂଯଂରଂଶଂସଅକଅଗଅଙଅଛଅଜଅଟଅତଅଦଅଧଅନଅପଅବଅରଅସଆ ଆଇଆକ",
+    "ଆଗଆଙଆଡଆତଆଧଆନଆପଆଫଆବଆଭଆମଆରଆଲଆଶଆସଆୟଇ ଇଁଇଂଇକଇଛଇଜଇଟଇଣ",
+    "ଇଥଇନଇପଇବଇରଇଲଇସଈଜଉଚଉଛଉଜଉତଉଥଉପଉରଉଲଉସଋନଏ ଏକଏତଏନଏବଏମ",
+    "ଏରଏଲଏସଏହଓ ଓସକ କକକଙକଟକଣକନକମକରକଲକଳକସକାକିକୀକୁକୃକେକୋ",
+    "କୌକ୍ଖ ଖଣଖନଖାଖିଖୋଖ୍ଗ ଗକଗଣଗତଗନଗମଗରଗଲଗାଗିଗୀଗୁଗେଗୋଗ୍",
+    "ଘୁଙ୍ଚ ଚକଚତଚନଚଳଚାଚିଚୀଚୁଚେଚ୍ଚୟଛବଛାଛିଜ ଜଣଜନଜବଜରଜାଜି",
+    "ଜୀଜୁଜେଜୋଜ୍ଝୁଞ୍ଟ ଟକଟନଟରଟାଟିଟେଟୋଟ୍ଠଭଠାଠିଠ୍ଡ ଡରଡ଼ଡା",
+    "ଡିଡୁଡେଡୋଡ୍ଢ଼ଣ ଣକଣତଣଧଣନଣରଣସଣାଣିଣୀଣ୍ତ ତକତଥତନତମତରତଳ",
+    "ତାତିତୀତୁତୃତେତୋତ୍ଥ ଥମଥାଥିଥେଥ୍ଦ ଦକଦନଦରଦଲଦଳଦାଦିଦୁଦୃ",
+    "ଦେଦୋଦ୍ଧ ଧନଧରଧାଧିଧ୍ନ ନକନଗନଙନଟନଥନମନରନସନାନିନୀନୁନୂନେ",
+    "ନୋନ୍ପ ପକପଟପଢପଣପଥପଦପନପପପରପଶପସପାପିପୁପୂପୃପେପୋପ୍ଫରଫଳ",
+    "ଫାଫିଫୁଫେଫୋଫ୍ବ ବଂବଟବତବଦବନବଫବରବଲବଳବଶବସବହବାବିବୀବୁବୃ",
+    "ବେବୈବୋବ୍ଭ ଭରଭାଭିଭୁଭୂଭେଭୋମ ମକମତମଧମନମପମରମାମିମୁମୂମୃ",
+    "ମେମୋମ୍ମୟଯ ଯକଯଥଯଦଯନଯବଯଭଯରଯାଯିଯୁଯେଯୋଯ୍ର ରଏରକରଖରଗରଙ",
+    "ରଚରଣରତରଦରନରପରବରଭରମରରରଲରସରହରାରିରୀରୁରୂରେରୋର୍ରୟଲ ଲକ",
+    "ଲଗଲଟଲଫଲବଲମଲରଲାଲିଲୀଲୁଲେଲୋଲ୍ଳ ଳକଳନଳାଳିଳୀଳେଳ୍ଳୟଶ ଶନ",
+    "ଶାଶିଶୀଶୁଶୂଶେଶୈଶୋଶ୍ଷ ଷକଷଣଷରଷାଷିଷେଷ୍ଷୟସ ସଂସକସଙସଜସନ",
+    "ସଫସମସରସଲସହସାସିସୀସୁସୂସେସୋସ୍ହଁହଣହରହସହାହିହୁହୃହେହୋହ୍",
+    "଼ା଼ି଼େ଼ୋା ାଂାଇାଈାଉାଏାଓାକାଖାଗାଙାଚାଜାଞାଟାଠାଡାଣାତାଥ",
+    "ାଦାଧାନାପାବାଭାମାଯାରାଲାଳାଶାଷାସାହାୟାୱି ିଁିଅିଆିଏିଓିକ",
+    "ିଖିଗିଙିଚିଛିଜିଟିଡିଣିତିଥିଦିନିପିଫିବିଭିମିଯିରିଲିଳିଶିଷ",
+    "ିସିହିୟିୱୀ ୀକୀଜୀନୀପୀବୀମୀରୀୟୁ ୁଅୁଆୁଇୁଏୁକୁଗୁଙୁଚୁଜୁଞ",
+    "ୁଟୁଡୁଣୁତୁଥୁଦୁନୁପୁବୁମୁରୁଲୁସୁହୁୟୂଚୂତୂନୂପୂମୂରୂଲୃଟୃତ",
+    "ୃଶୃଷେ େଁେଇେଉେଋେକେଖେଗେଙେଚେଜେଟେଡେଣେତେଦେନେପେବେମେରେଲ",
+    "େଳେଶେଷେସେହେୟେୱୈଧୈଳୋ ୋଇୋକୋଗୋଙୋଜୋଟୋଡୋଣୋତୋନୋପୋବୋଭୋମ",
+    "ୋରୋଲୋଷୋସୌଣ୍ ୍କ୍ଗ୍ଚ୍ଛ୍ଜ୍ଟ୍ଠ୍ଡ୍ଣ୍ତ୍ଥ୍ଦ୍ଧ୍ନ୍ପ୍ବ୍ଭ୍ମ",
+    "୍ଯ୍ର୍ଲ୍ଶ୍ଷ୍ସ୍ୟ୍ୱୟ ୟକୟଖୟତୟନୟବୟମୟରୟାୟିୟୁୟେୟୋୱରୱାୱି",
+    "ୱୀୱେஃபஅகஅசஅடஅதஅனஅபஅமஅயஅரஅலஅளஅவஆனஇகஇடஇணஇதஇநஇனஇயஇர",
+    "இலஇஸஉகஉணஉயஉரஉளஎணஎதஎனஎலஎழஏறஐ ஒடஒதஒரஓரக கககடகணகதகன",
+    "கபகமகயகரகலகளகவகாகிகீகுகூகெகேகைகொகோக்ங்சடசனசமசரசா",
+    "சிசீசுசூசெசேசைசோச்ஜாஜிஜீஜுஜெஜோஜ்ஞ்ட டகடஙடதடனடபடம",
+    "டரடலடவடாடிடீடுடெடேடைடோட்ணிணைண்த தகதததனதபதமதரதறதல",
+    "தவதாதிதீதுதெதேதைதொதோத்நகநடநயநாநிநீநுநெநேநைநோந்ன ",
+    "னதனமனானினீனுனேனைனோன்ப பகபஙபடபணபதபனபபபமபயபரபலபழபா",
+    "பிபீபுபூபெபேபைபொபோப்மகமடமதமனமபமரமறமலமாமிமீமுமூமெ",
+    "மேமைமொமோம்ய யகயஙயடயதயனயமயரயலயவயாயியுயூயேயையொயோய்",
+    "ர ரஙரசரணரதரனரபரமரலரவராரிரீருரூரெரேரைரோர்ற றகறதறப",
+    "றமறாறிறுறைற்ல லகலஙலதலனலமலாலிலீலுலெலேலைலோல்ள ளதளம",
+    "ளரளவளிளீளுளைள்ழகழிழுழைழ்வ வகவடவணவதவனவரவறவலவாவிவு",
+    "வெவேவைவோவ்ஷாஷிஷ்ஸபஸாஸிஸோஸ்ஹனஹரஹாஹிஹுஹோஹ்ா ாகாஙாச",
+    "ாஜாடாணாதாநானாபாமாயாராறாலாளாவாஷாஸாஹி ிகிஙிசிஜிடித",
+    "ிநினிபிமியிரிறிலிளிழிவிஷிஸீ ீகீடீனீமீயீரீழீவீஸு ",
+    "ுகுஙுசுடுணுதுநுனுபுமுயுருறுலுளுழுவுஸூ ூடூனூரூலெக",
+    "ெஙெடெனெபெமெயெரெறெலெளெவெஸே ேகேசேடேணேனேபேமேயேரேறேல",
+    "ேவேஷேஸை ைகைடைதைநைனைபைமையைவொகொடொணொதொபொரொலொழோ ோகோங",
+    "ோசோடோதோனோபோமோயோரோலோவோஸோஹ் ்ஃ்க்ச்ஜ்ட்ண்த்ந்ன்ப்ம",
+    "்ய்ர்ற்ல்ள்வ்ஷ்ஸ்ஹం ంకంఖంగంచంటండంతందంధంనంపంబంభంల",
+    "ంశంసఅంఅకఅడఅతఅదఅనఅపఅభఅమఅయఅరఅలఅవఅసఆదఆపఆఫఆరఇంఇనఈ ఉం",
+    "ఉనఉపఊహఎంఎగఎడఎతఎనఏ ఐచఐలఒకక కంకచకటకనకపకమకరకలకవకశకా",
+    "కికీకుకూకెకేకొకోక్ఖాఖ్గంగతగదగమగరగలగాగిగీగుగేగొగో",
+    "గ్చకచటచడచదచనచబచరచలచవచాచిచుచూచెచేచైచ్జటజమజరజాజిజీ",
+    "జెజ్ఞాట టకటనటరటలటాటిటీటుటూటెటేటోట్ఠ ఠమఠిఠ్డ డండద",
+    "డమడరడలడవడాడిడుడెడేడైడొడోడౌడ్ణ ణంణమణాణిణ్త తంతకతగ",
+    "తనతపతమతరతలతాతితీతుతెతేతొతోత్థాథిథీద దగదతదనదమదరదల",
+    "దసదాదిదీదుదృదేదోద్ధనధమధాధిధ్న నంనఃనకనదనననపనబనమనర",
+    "నలనవనానినీనునూనెనేనైనొన్ప పంపకపటపడపతపదపనపబపమపయపర",
+    "పలపాపిపీపుపూపెపేపైపొపోప్ఫరఫలఫాఫిఫేఫైఫ్బ బంబటబడబఫ",
+    "బమబయబరబసబహబాబిబుబెబేబైబొబోబ్భంభమభాభిభ్మ మంమకమతమద",
+    "మధమనమపమమమయమరమలమామిమీముమూమెమేమైమొమోమౌమ్య యంయకయటయత",
+    "యనయపయబయమయరయలయవయాయియుయూయెయేయొయోయ్ర రంరకరచరటరణరతరద",
+    "రనరపరభరమరలరవరశరసరారిరీరురూరెరేరైరొరోరౌర్ల లంలకలగ",
+    "లదలనలపలమలలలవలసలాలిలీలులెలేలైలోల్ళ ళీళ్వ వంవకవచవణ",
+    "వదవనవరవలవసవావివీవువృవెవేవైవొవ్శ శంశకశనశమశాశిశీశు",
+    "శైశ్షంషణషనషమషరషలషిష్స సంసతసమసరసలసహసాసిసీసుసూసృసె",
+    "సేసైసోస్హంహకహదహాహిహుహ్ా ాంాకాగాచాటాఠాడాణాతాదాధాన",
+    "ాపాబామాయారాలాళావాశాషాసాహి ింికిగిచిజిటిడిణితిదిధ",
+    "ినిపిఫిబిమియిరిలివిశిషిసిహీ ీకీటీడీతీనీమీయీరీలీవ",
+    "ీసు ుంుకుగుచుటుడుణుతుదునుపుబుముయురులువుసూ ూచూటూడ",
+    "ూనూపూయూరూలూహృతృదృవృషె ెంెకెటెడెదెనెపెబెమెయెరెలెస",
+    "ైే ేకేజేటేడేతేదేనేపేబేమేయేరేలేళేవేశేషేసై ైంైటైత",
+    "ైనైపైరైలొ ొంొకొటొడొతొనొమొరొలో ోంోకోగోడోతోదోనోపోమ",
+    "ోయోరోలోవోషోసౌంౌతౌన్ ్క్గ్చ్ఛ్జ్ఞ్ట్ఠ్డ్ణ్త్థ్ద్ధ",
+    "్న్ప్ఫ్బ్మ్య్ర్ల్వ్శ్ష్సಂಕಂಖಂಗಂಚಂಜಂಝಂಟಂಡಂತಂದಂಪಂಬ",
+    "ಂಭಂವಂಶಅಂಅಕಅಗಅಟಅಡಅಥಅದಅನಅಪಅಬಅಮಅರಅಲಅವಅಶಅಸಆಂಆಗಆದಆಫಆಯ",
+    "ಆರಆವಇಂಇದಇನಇರಇಲಇಸಈ ಈಸಉದಉಪಉರಎಂಎಡಎತಎನಎರಎಲಎಸಒಂಒಕಒಟಒರ",
+    "ಒಳಓದಕ ಕಂಕಕಕಗಕಟಕಡಕತಕದಕನಕಪಕಬಕಮಕಯಕರಕಲಕವಕಷಕಸಕಾಕಿಕೀಕು",
+    "ಕೂಕೆಕೇಕೈಕೊಕೋಕ್ಖಾಖ್ಗ ಗಣಗತಗದಗನಗಬಗಮಗರಗಲಗಳಗವಗಾಗಿಗೀಗು",
+    "ಗೂಗೆಗೇಗೊಗೋಗ್ಘಟಚಕಚನಚರಚಲಚಾಚಿಚುಚೆಚೈಚೌಚ್ಜನಜರಜಾಜಿಜೀಜು",
+    "ಜೆಜೊಜೋಜ್ಝಪಝಾಝಿಟ ಟಕಟಗಟದಟನಟಮಟರಟಲಟವಟಾಟಿಟೀಟುಟೆಟೇಟೈಟೊ",
+    "ಟೋಟ್ಠ್ಡ ಡತಡದಡನಡರಡಲಡಾಡಿಡುಡೆಡೇಡೊಡೋಡ್ಢೀಣ ಣಕಣಗಣದಣರಣವ",
+    "ಣಾಣಿಣೆಣ್ತ ತಕತಗತದತನತಪತಮತರತಲತವತಾತಿತುತೆತೊತೋತ್ಥಳಥವಥಾ",
+    "ಥಿದ ದಕದಗದತದದದನದರದಲದವದಸದಾದಿದುದೃದೆದೇದೊದೋದ್ಧಿಧ್ನ ನಂ",
+    "ನಕನಗನಡನದನಮನರನಲನವನಾನಿನೀನುನೂನೆನೇನೈನೊನೋನೌನ್ಪಂಪಕಪಗಪಟ",
+    "ಪಠಪಡಪದಪನಪಪಪಬಪರಪಲಪಾಪಿಪೀಪುಪೂಪೆಪೇಪೈಪೊಪೋಪ್ಫರಫಲಫಾಫಿಫು",
+    "ಫೊಫೋಫ್ಬ ಬಂಬಗಬಣಬದಬನಬಫಬರಬಲಬಳಬಸಬಹಬಾಬಿಬೀಬುಬೆಬೇಬೈಬೊಬೋ",
+    "ಬ್ಭಾಭಿಭ್ಮ ಮಂಮಕಮಗಮಟಮತಮನಮಬಮಯಮರಮಲಮವಮಸಮಾಮಿಮೀಮುಮೂಮೆಮೇ",
+    "ಮೈಮೊಮೋಮೌಮ್ಯ ಯಂಯಕಯಗಯದಯನಯಮಯರಯಲಯವಯಾಯಿಯುಯೂಯೆಯೇಯೊಯೋಯ್",
+    "ರ ರಂರಕರಗರಚರಡರಣರತರದರನರಬರಮರಲರಳರವರಶರಸರಾರಿರೀರುರೂರೆರೇ",
+    "ರೊರೋರ್ಲ ಲಂಲಕಲಗಲತಲದಲನಲಭಲವಲಾಲಿಲೀಲುಲೂಲೆಲೇಲೈಲೊಲೋಲೌಲ್",
+    "ಳ ಳಕಳಗಳನಳಬಳಲಳಸಳಾಳಿಳುಳೆಳ್ವ ವನವಯವರವಲವಸವಾವಿವೀವುವೃವೆ",
+    "ವೇವೈವೊವೋವ್ಶ ಶಕಶಗಶದಶನಶವಶಾಶಿಶೀಶುಶೆಶೇಶೈಶೊಶೋಶ್ಷ ಷರಷಿ",
+    "ಷೆಷ್ಸ ಸಂಸಕಸಟಸದಸನಸಬಸಮಸರಸಲಸವಸಹಸಾಸಿಸುಸೂಸೆಸೇಸೊಸೋಸೌಸ್",
+    "ಹ ಹಂಹರಹಳಹಾಹಿಹುಹೆಹೈಹೊಹೋಹ್ಾ ಾಂಾಕಾಗಾಚಾಜಾಝಾಟಾಡಾಣಾತಾದ",
+    "ಾಧಾನಾಪಾಫಾಬಾಮಾಯಾರಾಲಾವಾಶಾಷಾಸಾಹಿ ಿಂಿಕಿಗಿಚಿಜಿಟಿಡಿಣಿತ",
+    "ಿದಿನಿಪಿಫಿಬಿಭಿಮಿಯಿರಿಲಿಳಿವಿಶಿಷಿಸಿಹೀಕೀಟೀಡೀನೀಪೀಬೀಮೀಯ",
+    "ೀರೀಲೀವೀಷೀಸು ುಂುಕುಖುಗುಚುಜುಟುಡುಣುತುದುನುಪುಬುಮುಯುರುಲ",
+    "ುಳುವುಶುಸುಹೂ ೂಕೂಚೂಡೂದೂನೂಪೂರೂಲೃಢೃತೆ ೆಂೆಕೆಗೆಚೆಟೆಡೆದ",
+    "ೆನೆಪೆಬೆಮೆಯೆರೆಲೆಳೆವೆಶೆಸೆಹೇ ೇಕೇಖೇಗೇಜೇಟೇಡೇತೇನೇಬೇಯೇರ",
+    "ೇಲೇವೇಶೇಸೈಜೈಟೈನೈರೈಲೊ ೊಂೊಕೊಗೊಟೊಡೊತೊನೊಪೊಬೊಮೊಯೊರೊಲೊಳ",
+    "ೊವೊಸೊಹೋ ೋಂೋಕೋಗೋಜೋಟೋಡೋನೋಪೋಮೋಯೋರೋಲೋವೋಶೋಷೋಸೌಕೌತೌರೌಲ",
+    "ೌಹ್ ್ಕ್ಗ್ಚ್ಜ್ಟ್ಡ್ಣ್ತ್ಥ್ದ್ಧ್ನ್ಪ್ಬ್ಮ್ಯ್ರ್ಲ್ಳ್ವ್ಶ್ಷ",
+    "್ಸ್ಹം ംഗംപംബംഭംവഅകഅടഅതഅനഅപഅമഅറഅലഅളഅവഅസആകആണആനആയആര",
+    "ആവഇടഇതഇനഇമഇലഇവഈ ഉണഉപഉയഉറഉളഎകഎങഎണഎതഎനഎലഎഴഎസഏതഏറഐക",
+    "ഒരഓഫക കംകങകണകതകനകപകമകയകരകറകലകളകവകസകാകികീകുകൂകെകേ",
+    "കൊകോക്ഖലഗംഗതഗാഗിഗുഗോഗ്ഘടങളങ്ച ചകചനചയചാചിചുചെചേചൈ",
+    "ച്ജമജയജരജറജാജിജീജുജോജ്ഞ ഞെഞ്ട ടകടണടതടപടയടരടറടവടാ",
+    "ടിടുടൂടെടേടോട്ഡയഡരഡറഡാഡിഡീഡെഡേഡോഡൌഡ്ണ ണംണകണങണമണയ",
+    "ണിണുണെണോണ്ത തംതകതനതമതയതരതലതവതാതിതീതുതൃതെതേതോത്ഥല",
+    "ഥാദരദാദിദൃദേദ്ധാധിധുധ്ന നംനതനമനലനവനാനിനീനുനെനേനോ",
+    "ന്പംപകപടപതപബപയപരപറപശപാപിപീപുപൂപെപേപോപ്ഫയഫിഫെഫോഫ്",
+    "ബടബനബഫബരബലബാബിബുബൂബെബൈബോബ്ഭാഭിഭ്മംമതമനമയമരമറമലമാ",
+    "മിമീമുമൂമെമേമൊമോമ്യ യംയകയങയതയനയപയമയരയറയലയവയായിയു",
+    "യൂയെയേയോയ്ര രംരകരങരഞരണരതരദരനരമരയരവരസരാരിരീരുരൂരെ",
+    "രേരൈരോര്റ റംറകറഞറതറനറപറയറരറററവറാറിറീറുറൂറെറേറൊറോ",
+    "റ്ല ലംലകലതലനലഭലമലറലവലാലിലീലുലെലേലൈലോല്ള ളംളതളരളള",
+    "ളവളിളുള്ഴിഴുഴെഴ്വ വടവതവദവധവനവമവയവരവലവളവശവസവാവിവീ",
+    "വുവെവേവോവ്ശകശദശരശിശേശ്ഷംഷകഷതഷനഷരഷിഷ്സംസജസനസമസരസല",
+    "സാസിസീസുസൂസെസോസ്ഹാഹിഹെഹോഹ്ാ ാംാകാഗാങാചാജാടാഡാണാത",
+    "ാധാനാപാബാമായാരാറാലാളാഴാവാഷാസി ിംികിങിചിഞിടിഡിതിധ",
+    "ിനിപിബിമിയിരിറിലിളിവിശിഷിസിഹീ ീകീണീതീനീപീബീമീയീര",
+    "ീറീലീളീവീഷീസു ുംുകുടുണുതുനുപുമുരുറുളുവുസു്ൂകൂചൂജ",
+    "ൂടൂണൂപൂരൂലൂളൃകൃശെ െകെങെടെഡെനെപെമെയെരെറെലേ േകേഖേജ",
+    "േഡേണേനേപേബേയേരേറേലേശേഷൈകൈനൈമൈറൈലൈളൊമൊരൊറോ ോകോഗോങ",
+    "ോടോഡോണോപോമോയോരോറോലോളോഴോവോസൌണ് ്ക്ഗ്ങ്ച്ജ്ഞ്ട്ഡ്ണ",
+    "്ത്ഥ്ദ്ധ്ന്പ്മ്യ്ര്റ്ല്ള്വ്ഷ്സංකංගංයංශඅංඅකඅගඅඟඅත",
+    "අනඅපඅයඅරඅවඅසආකආරඇතඇනඇලඉකඉඩඉතඉනඉරඉවඉසඋතඋපඋසඑකඑයඔබ",
+    "ක කටකඩකතකනකයකරකලකසකළක්කාකැකිකීකුකෘකෙකේකොකෝග ගණගත",
+    "ගනගබගයග්ගාගැගිගුගේගොගෝඟිච්චීජ ජධජනජයජවජ්ජාජිජීට ",
+    "ටයටහට්ටාටැටිටීටුටෙටේටොටෝඩ ඩකඩනඩරඩසඩ්ඩාඩිඩෝණ ණයණව",
+    "ණසණාණිණුත තකතතතනතමතයතරතලතහත්තාතැතිතීතුතේතොතෝථාද ",
+    "දකදතදනදයදරදවදහද්දාදැදිදීදුදූදෙදේදෝධතධාන නකනටනපනම",
+    "නයනරනවනසන්නානැනිනීනුනෙනේනොනෝඳහප පතපදපනපයපරපසපහපළ",
+    "ප්පාපැපිපීපුපූපෙපේපොබටබඩබයබරබලබවබහබ්බාබැබිබීබුබෙ",
+    "බොබෝභ භාම මකමටමතමනමයමරමහම්මාමැමිමීමුමූමෘමෙමේමොමෝ",
+    "ඹිය යකයටයතයනයමයයයලයවයළයායියුයෙයේයොයෝර රංරකරගරජරණ",
+    "රතරදරනරමරයරලරවරසරහර්රාරිරීරුරූරේරොරෝල ලංලකලතලදලන",
+    "ලපලබලමලයල්ලාලැලිලීලුලෙලේලෝව වකවටවතවදවනවමවයවරවලවශ",
+    "වසව්වාවැවිවීවුවූවෘවෙවේවෝශ ශනශයශ්ශාශිශීශුශේශෛෂ ෂක",
+    "ෂණෂයෂරෂිස සංසකසජසටසතසනසඳසමසයසරසලසහසළස්සාසැසිසීසු",
+    "සෙසේසොහ හජහතහදහනහමහයහරහලහවහසහළහාහැහිහුහෙහොහෝළ ළක",
+    "ළලළිළුෆේ ්ක්ග්ජ්ට්ඩ්ණ්ත්ථ්ද්ධ්න්ප්බ්භ්ම්ය්ල්ව්ශ",
+    "්ෂ්සා ාංාකාගාජාටාණාතාදානාපාබාමායාරාලාවාශාසැකැගැඩ",
+    "ැතැදැනැඳැපැබැමැයැරැලැවැසි ිකිගිචිජිටිඩිණිතිදිධින",
+    "ිපිබිමිඹියිරිලිවිශිසිහිළී ීකීඩීනීමීයීරීලීවීසු ුක",
+    "ුටුඩුණුතුදුනුපුබුමුයුරුලුවුසුහුළූ ූපූරූලෘතෘදෙ ෙක",
+    "ෙටෙඩෙතෙදෙනෙබෙමෙයෙරෙලෙවෙසෙහෙළේ ේඛේජේතේදේනේබේරේශේෂ",
+    "ෛලොංොකොටොතොදොනොබොමොයොරොලොවොසොහෝ ෝකෝගෝනෝරෝලෝවෝෂෝස",
+    "ก กกกขกคกจกชกซกดกตกทกนกปกผกมกรกฤกลกวกษกสกหกอกะกั",
+    "กากำกิกีกุกูกเกแกโกใกไก็ก่ก้ก์ขณขตขนขยขรขอขัขาขี",
+    "ขึข้ค คกคคคงคชคตคนคมครคลควคสคอคัคาคำคิคีคืคุคูคเ",
+    "คโค่ค้ค์ง งกงขงคงจงชงซงดงตงถงทงนงบงปงพงมงยงรงลงว",
+    "งสงหงองางเงแงโงใงไงๆจ จกจงจจจตจทจนจบจรจสจอจะจัจา",
+    "จำจิจีจุจเจไจ็จ้ฉบฉีชตชนชลชวชอชัชาชิชีชืชุชเชโช่",
+    "ช้ช์ซ ซตซนซมซสซอซัซาซิซีซึซุซูซเซโซ็ซ้ซ์ญญญหญาฐป",
+    "ฐอฐาฐเฐโณ ณรณสณะณาณีณ์ด ดกดขดคดงดจดดดตดถดทดนดปดพ",
+    "ดภดมดยดรดลดวดสดหดอดัดาดำดิดีดูดเดแดโดใดได้ด์ต ตก",
+    "ตขตตตทตนตมตยตรตสตอตะตัตาตำติตีตุตูตเตแตโตใตไต็ต่",
+    "ต้ต์ถ ถตถลถสถอถาถึถูถเถแถใถ้ทธทนทยทรทศทอทัทาทำทิ",
+    "ทีทึทุท็ท่ท้ท์ธ ธาธิธีธ์น นกนขนคนจนชนซนดนตนทนธนน",
+    "นบนปนพนมนยนรนลนวนสนหนอนะนันานำนินีนึนืนุนูนเนแนโ",
+    "นในไน่น้น์บ บกบขบคบจบฉบชบตบทบนบบบปบผบรบลบวบสบอบั",
+    "บาบิบีบุบูบเบแบโบใบไป ปกปตปนปปปภปยปรปลปอปัปาปิปี",
+    "ปุปเปแปไป็ป้ป์ผลผสผิผูผ่ฝรพ พกพชพตพบพยพรพลพอพัพา",
+    "พิพืพูพ์ฟรฟลฟอฟาฟิฟเฟ้ภาม มกมขมคมจมชมซมดมตมทมนมบ",
+    "มปมผมพมภมมมยมรมลมวมสมหมอมะมัมามิมีมืมูมเมแมโมใมไ",
+    "ม่ย ยกยขยคยงยชยดยตยทยนยบยปยมยรยลยวยสยหยอยะยัยายิ",
+    "ยืยุยูยเยแยโยใยไย่ย้ย์ร รกรครงรจรฐรณรดรตรถรทรนรบ",
+    "รปรมรยรรรลรวรสรหรอระรัราริรีรืรุรูรเรแรโรใรไร็ร่",
+    "ร้ร์ฤษล ลกลขลงลจลซลดลตลทลนลบลผลมลยลรลลลวลสลอละลั",
+    "ลาลำลิลีลืลุลูลเลแลโลไล็ล่ล้ล์ว วกวขวควงวจวตวนวบ",
+    "วมวยวรวลวสวอวะวัวาวิวีวเวแวโวไว่ว้ศ ศรศสศูษรษลษา",
+    "ส สกสคสซสดสตสถสนสมสรสลสวสสสหสอสะสัสาสำสิสีสุสูสเ",
+    "สแสโส่ส์หตหนหมหรหลหวหัหาห้ห์อ อกอขอคองอจอชอซอดอต",
+    "อทอนอบอปอผอฟอมอยอรอลอวอสอหอออะอัอาอำอิอีอือุอูอเ",
+    "อแอโอใอไอ็อ่อ้ฮัฮาฮิฮีะ ะกะขะคะจะชะดะตะถะทะนะบะป",
+    "ะพะมะยะระละวะสะหะอะเะแะโะใะไักังัจัชัญัฐัดัตันับ",
+    "ัพัฟัมัยัลัวัสั่ั้า ากาขาคางาจาชาซาณาดาตาทาธานาบ",
+    "าปาพามายาราลาวาษาสาหาอาฮาะาเาแาโาใาไำ ำกำคำงำดำน",
+    "ำรำลำสำหำเำแำใำไิ ิกิงิจิชิดิติทิธินิบิปิฟิมิยิร",
+    "ิลิวิสิเิแิโิ่ิ้ี ีกีขีคีชีซีดีตีทีนีบีปีมียีรีล",
+    "ีวีสีอีเีแีโีใี่ี้ึกึงึ่ึ้ืนือื่ืุ้ ุกุคุชุซุญุณ",
+    "ุดุตุนุบุมุยุรุลุสุเุู่ ูกูงูซูดูตูนูบูปูมูรูลูว",
+    "ูเูโูู่้เ เกเขเคเจเฉเชเซเดเตเทเนเบเปเพเฟเมเยเรเล",
+    "เวเศเสเหเอเฮแกแคแจแซแดแตแถแทแนแบแปแผแพแฟแมแยแลแว",
+    "แสแหแอแฮโกโคโซโดโตโทโนโบโปโพโฟโมโยโรโลโวโหโอโฮใช",
+    "ใดใตในใหไขไดไตไทไนไบไปไมไรไลไวไอๆ ็ก็จ็ต็น็บ็ม็อ",
+    "่ ่ก่ข่ค่ง่จ่ช่ต่ถ่ท่น่บ่ป่พ่ม่ย่ร่ล่ว่ส่ห่อ่า่เ",
+    "่แ่ใ่ไ้ ้ก้ข้ค้ง้จ้ด้ต้ท้น้ป้ม้ร้ว้ส้ห้อ้า้ำ้เ้แ",
+    "้ใ้ไ์ ์ก์ข์จ์ซ์ด์ต์ท์น์บ์ป์ผ์ฟ์ม์ย์ร์ล์ส์ห์อ์เ์แ",
+    "์โ์ใ์ไཀ ཀརཀིཀུཀེཀོཀྱཀྲཁ ཁབཁརཁོཁྱཁྲག གཅགཏགདགནགཞགཟ",
+    "གཡགསགིགུགེགོགྱགྲང ངམངསངེངོཅནཅིཅོཆ ཆགཆེཆོཇིཇུཉིཉེ",
+    "ཊིཊེཌིཌོཏངཏུཐབཐའཐིཐུཐོད དཀདགདངདཔདབདམདཝདིདུདེདོདྲ",
+    "ན ནངནཌནདནམནའནསནིནུནོཔ པའཔརཔསཔིཔུཔེཔོཕ ཕནཕརཕྱཕྲབ ",
+    "བཀབཅབཏབདབཙབཞབཟབའབརབཤབསབཱབིབུབེབོབྱབྲམ མགམངམཇམཐམཚ",
+    "མཛམའམསམིམེམོཙུཚདཚནཚབཚམཚིཚུཚོཛིཛོཝ ཝིཞགཞིཞུཟུཟོའ ",
+    "འཁའགའཐའདའཕའབའཛའིའུའོཡ ཡངཡནཡིཡུཡེཡོར རངརཔརིརེརྐརྒ",
+    "རྗརྙརྟརྡརྣརྩལ ལགལངལམལསལིལུལེལོལྟལྡལྷཤིཤུཤེཤོས སར",
+    "སལསིསེསོསྐསྒསྔསྟསྡསྣསྤསྦསྲཨངཨའཨརཨིཨེཨཱོནཱལཱསཱོི ",
+    "ིཀིགིངིཊིཌིདིནིབིམིའིརིལིསུ ུགུངུཊུདུནུབུམུའུརུལ",
+    "ུསེ ེགེཊེདེནེཕེབེམེའེརེལེསོ ོགོངོདོནོཔོབོམོའོརོལ",
+    "ོསྐབྐོྐྱྐྲྒོྒྱྒྲྔོྙནྟ ྟགྟེྟོྡ ྡུྡེྣམྣོྤྱྤྲྦེྦྱྩ ",
+    "ྱ ྱངྱདྱབྱརྱལྱིྱུྱེྱོྲ ྲངྲདྲནྲམྲལྲིྲུྲེྲོྷགက ကနကလ",
+    "ကာကိကုကူကေက္က်ကျကြကွခငခနခရခလခါခိခုခေခံချခြခွဂရဂါ",
+    "ဂျငနငယငံင်စကစဉစညစတစနစပစမစရစာစိစီစုစေစံစ္စ်စွဆကဆင",
+    "ဆညဆာဆိဆီဆုဆေဆွဉာဉ်ညာညီည်ညွညှဏ်တ တကတခတငတညတနတမတယတရ",
+    "တာတိတီတုတူတေတဲတံတ္တ်တွထကထညထာထိထုထေထဲထွဒါဒိဒီဒုဒေ",
+    "ဒ်နစနညနယနာနိနီနေနဲန်နှပငပစပမပယပရပါပိပုပူပေပံပ်ပျ",
+    "ပြပွဖကဖနဖိဖေဖျဖြဖွဗဟဘယဘာဘူဘေမခမညမတမထမပမဖမမမယမရမလ",
+    "မသမအမာမိမူမေမဲမ္မ်မျမြမွမှယအယာယူယ်ယွရငရနရပရမရာရိ",
+    "ရီရုရေရဲရွရှလကလငလညလပလာလိလီလုလေလဲလံလျလွလှဝေသ သကသင",
+    "သတသမသာသိသုသူသေသွဟာဟိဟုအ အကအခအငအစအဆအညအတအထအနအပအဖအမ",
+    "အရအလအဝအသအာအိအုအေဦးါ ါငါဆါနါရါ်ာ ာကာငာဏာတာနာပာဖာမ",
+    "ာယာရာလာသာအာ့ားာ်ိ ိခိတိနိပိဘိမိယိရိသိုီ ီကီစီနီမ",
+    "ီယီရီလီးု ုကုခုငုတုထုဒုနုပုမုရုလုအုံု့ုးူ ူခူနူရ",
+    "ူအူးေ ေတေနေမေရေအေါောေ့ေးဲ ဲကဲခဲတဲပဲမဲဝဲသဲ့ံ ံကံင",
+    "ံစံဆံထံနံပံမံရံလံသံ့ံး့ ့က့ခ့စ့ဆ့တ့ထ့န့ပ့ဖ့မ့ယ့ရ",
+    "့သ့အး းကးခးစးဆးညးတးထးဒးနးပးဖးမးယးရးလးသးဟးအ္က္ခ္စ",
+    "္တ် ်က်ခ်ဂ်င်စ်ဆ်ဉ်ည်တ်ထ်ဒ်န်ပ်ဖ်ဘ်မ်ရ်လ်သ်အ့််း",
+    "်္ျကျငျဉျမျယျာျိျီျေျဲျှြငြစြညြတြနြပြမြသြာြိြီြု",
+    "ြေြွြှွကွငွတွနွယွာွေွဲွှှ ှငှစှညှတှနှာှိှုှေა აა",
+    "აბაგადავაზათაიაკალამანაოაპარასატაუაფაქაღაყაშაჩაც",
+    "აწაჭახბ ბაბებიბლბობრბსბუბშგაგეგვგიგოგრგუდ დადგდე",
+    "დვდიდოდრდუე ებეგედეეევეზეთეიეკელემენეოეპერესეტეუ",
+    "ექეშეცეხვავდვევთვივლვნვრვსზაზეზიზოზღთ თათეთვთითო",
+    "თუთხი იაიბიგიდიეივიზითიიიკილიმინიოიპირისიტიუიფიქ",
+    "იღიყიშიციძკაკეკვკიკლკოკრკულ ლალდლელილოლსლტლულშმ ",
+    "მამბმდმემთმიმნმომუმშმწმხნ ნანგნდნენზნინონსნტნუნფ",
+    "ნქო ოაობოგოდოეოვოთოიოკოლომონოპოროსოტოუოფოყოცოწპა",
+    "პეპიპლპოპრპუჟირ რარგრდრერვრთრირმრნრორსრტრურქრჩრც",
+    "რძს სასესვსთსისკსლსნსოსპსრსტსუსწსხტატეტვტიტოტრტუ",
+    "ტყუ უაუბუდუთუკულუმუნურუსუტუფუშუცუძფაფეფიფლფოფრფუ",
+    "ქაქვქმქოქსქტქცღაღეღიღწყეყვყიყოშაშეშვშიშლჩაჩეჩვც ",
+    "ცაცდცეცვციცნცხძაძეძლწაწეწვწიწმწოწყჭეჭიხახეხვხიხლ",
+    "ხმხოხრხსხუჯაჯგក កចកញកដកថកបកមករកសកាកុកូកើកេកែកោកំ",
+    "ក់ក្ខ ខាខូខេខ្គ គងគមគាគិគីគឺគុគូគោគ្ឃើឃ្ង ងកងចងជ",
+    "ងទងផងពងរងសងហងាង់ង្ច ចកចងចនចបចមចាចិចុចូចេចំច្ឆេជម",
+    "ជាជិជីជុជូជួជើជំជ័ជ្ឈ្ញ ញាញ្ដ ដងដលដាដីដូដើដេដែដោ",
+    "ដំដ្ឋ ឋមឋាឌីឌូណ ណតណនណរណសណាណឹណូណើណេណែណោណ៌ណ្ត តងតភ",
+    "តមតាតិតុតូតួតេតែតំត់ត៌ត្ថតថបថាថុថែថ្ទ ទងទទទាទិទី",
+    "ទឹទុទួទៀទេទៅទំទះទ្ធ ធាធិធីធ្ន នកនដនតនទនធនននបនផនភ",
+    "នមនយនលនសនឡនអនានិនីនឹនុនួនេនែនៃនៅន់ន័ន្ប បងបចបញបឋ",
+    "បណបតបទបនបភបមបរបសបាបិបីបុបូបើបៀបេបែបំប៉ប៊ប់ប័ប្ផុ",
+    "ផ្ព ពណពនពសពអពាពិពីពឹពុពួពេព័ព្ភពភាភេភ្ម មកមតមទមន",
+    "មបមវមាមិមីមុមូមួមេមែមោម៉ម័ម្យ យកយណយបយមយរយលយសយអយា",
+    "យ៉យ្រ រករងរចរជរដរណរតរទរនរបរផរពរភរមរយរវរសរហរឡរអរា",
+    "រិរីរឹរុរូរួរើរៀរោរំរះរ៉រ័ល លងលដលនលបលមលាលិលីលុលូ",
+    "លើលេលែលៃលោលំល់ល្វ វកវតវបវាវិវីវើវៀវែវ៉ស សកសងសញសន",
+    "សមសរសហសាសិសូសើសេសែសោសៅសំស៊ស់ស្ហពហរហាហុហើហែហោហំហ្",
+    "ឡងឡាឡុឡូឡើឡេអ អកអងអតអនអបអសអាអូអេអែអំអ៊អ្ឥណឬ ឯកឲ្",
+    "ា ាកាគាងាចាជាញាដាឌាណាតាទាធានាបាពាមាយារាលាវាសាហាឡ",
+    "ាអាំិ ិកិងិចិញិតិទិធិនិបិមិយិសី ីកីគីជីឌីណីតីទីន",
+    "ីបីពីមីយីរីលីវីសីហឹងឹមឺ ឺនឺមុ ុកុងុចុញុណុតុនុបុម",
+    "ុយុលុសុំូងូចូដូឌូណូតូទូនូបូមូរូលូវូសូហូឡូអួកួចួន",
+    "ួមួយួរួលួសើ ើកើងើញើតើនើបើមើយើរើសើឲៀងៀតៀបេ េកេខេង",
+    "េចេញេណេតេទេនេបេមេយេរេលេសេហេឡេះែ ែកែងែតែនែបែមែរែល",
+    "ៃ ោ ោចោមោយោលោះៅ ៅកំ ំងំដំណំទំនំបំពំហំឡះ ះបះរះស៉ង",
+    "៉ា៉ឺ៉ុ៉ូ៉េ៉ែ៊ិ៊ី៊ុ៊ូ៊ែ់ ់ក់គ់ច់ដ់ត់ទ់ប់ផ់ព់ភ់ម់រ",
+    "់វ់ស់ហ់អ៌ន៌ម៍ ័ណ័ត័ន័យ័រ្ក្ខ្គ្ង្ច្ជ្ញ្ដ្ឋ្ឌ្ណ្ត",
+    "្ថ្ទ្ធ្ន្ប្ព្ម្យ្រ្ល្វ្ស្ហ្អảnầnậpếnể ị ố ỗiợcあっ",
+    "あなありあるあれい いういかいくいけいずいたいていでいないのいはいまいよいるう うかうこうちうで",
+    "うとうなうにえてえなえまえらえるおいおけおそおよか かしかっかつかでかどかのかもからかりかをが ",
+    "があがでがなき きいきさきしきすきできなきにきのきまきるぎまく くあくこくだくつくとくなくはけて",
+    "けでけまけらけるけれここことこのこれごとさ さいさがさせさなされさをし しいしかしくしたしてしで",
+    "しなしのしましょしよしれじ じてす すかすがすぎすこすですべするず ずにずれせ せがせずせてせの",
+    "せませるせをせんそうそのそらそれた たいたかたがたこたたたとたのたはためたらたりだがだけださちま",
+    "ちらったってつ ついつかつけつこつのて ていておてくてのてはてもで であでいできでしですでなでに",
+    "でのではでもと といとがときとしとすとでとなとにとのとはともとをどうどのな ないなかなくなけなし",
+    "なすなたなっなどならなりなるに にあにおにしにすにつになにのにはにもによの のあのいのうのがのた",
+    "のでのとのなのにのはのまのみのものよは はあはいはこはすはずはそはではなはまば ばいばなび への",
+    "べきべてましますませまたまだまでまままりまるまれみ みがみでみにみのみまみをむ むこめ めてめに",
+    "めのめまめらめるも もうもしものや やすょうようよっよびよりよるら らくらならのられり りでりと",
+    "りにりのりまりもる るいるかるこるたるとるにるのるべるまるもるよれ れかれたれてれでれなれはれば",
+    "れまれられるれをわせわなわりわれを をすん んでァ ァイァベァミァリァルァンァーア アアアイアウ",
+    "アカアクアグアサアシアジアスアセアゼアタアダアチアッアトアドアナアニアパアビアフアプアマアムアメ",
+    "アラアリアルアレアロアンアヴアーィ ィアィオィカィグィコィシィジィスィタィッィナィブィポィラィリ",
+    "ィルィレィンィーイ イアイエイオイカイクイコイサイジイスイズイセイタイダイチイッイツイテイトイド",
+    "イナイヌイバイフイブイプイベイマイムイメイモイヤイライリイルイレイロインイーゥアゥラゥルゥンゥー",
+    "ウ ウィウイウェウエウォウガウクウサウスウズウトウドウルウンウーェアェイェクェコェシェスェッェニ",
+    "ェリェルェロェンェーエイエウエキエクエコエジエスエデエミエラエリエルエレエンエーォアォトォルォン",
+    "ォーオ オグオスオフオブオプオペオラオリオルオロオンオーカ カイカウカザカシカスカタカチカッカテ",
+    "カドカナカバカムカラカリカルカレカンカーガ ガイガシガスガニガラガリガルガンガーキアキシキスキッ",
+    "キテキャキュキリキルキンキーギスギニギリギーク クァクアクイクエクォクグクサクシクスクセクタクチ",
+    "クックテクデクトクナクバクフクプクポクマクメクモクラクリクルクレクロクワクンクーグ グアグイグエ",
+    "グシグセグナグネグフグメグモグラグリグルグレグログーケッケドケニケルケンケーゲッゲーコ コアコス",
+    "コタコッコトコネコピコマコミコメコモコリコルコレコロコンコーゴスゴミゴラゴリゴルゴンゴーサ サイ",
+    "サウサカサスサトサブサポサマサムサモサラサリサルサロサンサーザ ザクザニザンザーシ シアシェシエ",
+    "シカシグシコシスシッシナシピシャシュショシリシルシンシージ ジアジェジオジカジキジスジタジッジト",
+    "ジバジフジブジプジヘジャジュジョジリジルジンジース スアスイスウスエスオスカスキスクスケスコスサ",
+    "スシスススタスチステスデストスナスニスバスパスピスフスプスペスポスマスメスユスラスリスルスレスロ",
+    "スワスンスーズ ズエズベズムズルズーセキセクセグセスセッセパセマセラセルセレセンセーゼルゼロゼン",
+    "ソケソッソトソフソルソロソンソーゾルゾンゾータ タイタウタカタガタクタグタサタジタスタタタチタッ",
+    "タデタナタニタフタブタプタベタマタミタムタラタリタルタレタロタンターダ ダイダウダッダブダマダム",
+    "ダヤダラダリダルダンダーチ チアチェチキチスチッチトチバチャチュチョチリチンチーッカッキックッグ",
+    "ッケッサッシッジッセッタッダッチッツットッドッパッピッフップツァツィツェツォツリツーティテイテキ",
+    "テクテゴテステッテナテネテムテラテリテルテンテーディデオデコデシデスデッデバデフデュデルデンデー",
+    "ト トアトイトゥトウトエトオトカトガトキトクトグトケトコトサトシトジトストセトタトダトットテトデ",
+    "トトトナトニトバトパトビトフトブトポトマトメトモトラトリトルトレトロトワトントード ドアドイドゥ",
+    "ドウドエドオドカドキドサドシドスドッドニドバドパドフドプドメドモドラドリドルドレドロドワドンドー",
+    "ナ ナイナウナカナガナコナスナダナッナビナミナムナラナリナルナンナーニアニウニカニコニスニッニメ",
+    "ニャニュニョニンニーヌクヌーネイネグネシネスネチネッネルネンネーノルノンノーハイハウハジハッハマ",
+    "ハラハルハンハーバ バイバウバキバクバグバスバッバトバドバニバネバハバブバラバリバルバレバンバー",
+    "パイパクパケパスパタパッパテパデパブパラパリパルパレパンパーヒスヒリヒンヒービアビエビジビスビッ",
+    "ビデビュビルビンビーピアピクピノピリピンピーファフィフェフォフガフセフッフトフラフリフルフレフロ",
+    "フンブ ブエブギブコブサブシブジブスブタブチブッブデブトブパブフブプブポブメブラブリブルブレブロ",
+    "ブンブープ プアプエプグプシプセプタプテプデプトプフプラプリプルプレプロプンプーヘッヘブヘルベク",
+    "ベスベッベトベニベラベリベルベレベンベーペアペイペクペシペラペルペレペンペーホスホッホーボ ボア",
+    "ボスボタボッボデボラボリボルボンボーポイポジポッポリポルポンポーマ マイマウマカマクマサマシマジ",
+    "マスマタマッマドマナマニマネマハマフマラマリマルマンマーミアミコミシミッミナミニミュミラミリミル",
+    "ミンミーム ムアムエムカムコムサムシムスムゾムデムバムフムヘムペムラムリムルムーメイメジメソメタ",
+    "メッメデメニメモメラメリメルメンメーモアモジモデモナモニモリモルモロモンモーャ ャイャスャッャド",
+    "ャナャネャラャリャルャワャンャーヤクヤレヤンヤーュ ュアュクュコュタュテュニュパュフュメュラュリ",
+    "ュルュレューユニユーョッョナョブョンョーヨルヨーラ ライラウラエラオラガラクラグラゴラシラジラス",
+    "ラタラチラッラテラデラトラナラニラバラパラビラフラブラベラマラムラメラララリラルラレラワランラー",
+    "リ リアリエリオリカリガリキリクリケリコリサリシリジリスリズリセリソリゾリタリダリッリテリデリト",
+    "リナリニリバリヒリビリピリフリブリプリベリポリマリミリメリモリャリヤリュリラリリリレリロリンリー",
+    "ル ルアルイルウルエルオルカルガルキルギルクルグルケルコルゴルサルシルジルスルズルセルタルダルチ",
+    "ルツルテルデルトルドルナルニルネルノルハルバルパルビルピルフルブルプルヘルベルボルマルミルメルモ",
+    "ルユルラルリルワルンルーレアレイレオレクレコレシレジレスレゼレッレナレバレビレフレプレベレポレリ",
+    "レルレンレーロ ロアロカロキログロケロゲロシロジロスロセロッロトロバロパロフロベロマロモロラロン",
+    "ロヴローワイワンワーン ンアンインウンオンカンガンキンギンクングンゲンコンゴンサンザンシンジンス",
+    "ンズンセンソンタンダンチンツンテンデントンドンナンネンハンバンパンビンピンフンブンプンヘンベンペ",
+    "ンボンポンマンメンモンユンランリンルンロンワヴァヴィヴェヴォーかーがーしーすーでーとーにーのーは",
+    "ーをーアーイーエーカーガーキークーグーケーゲーコーサーザーシージースーズーセーソーターダーチーテ",
+    "ーデートードーナーニーネーノーバーパービーピーフーブープーベーボーマーミームーメーモーユーラーリ",
+    "ールーレーローワーン",
+);
+
+/// Known characters, sorted.
+pub const UNIGRAMS: &str = concat!(
+    "一丁三上下不与且世丘东両丢两严並个中串临丹为主举久么义之乌乎乔乘也书了予事二于互",
+    "五亚些亞交亦产享京亮人什仅今介仍从仓他付代令以们仮件价任份伊伐优会传伦伪伯伸伺似",
+    "但佈位低住佐体何余佛作你佩佳併使來例供依価侧便係俄保信修個倍們倒候値倫值假偏做停",
+    "側偵偶偽備储傳傾僅像優儲允元充先光克免入內全兩八公六兰共关其具典兹兼内冊册再冒冗",
+    "写冰冲决况准减几処凯出击函分切划列则刚创初删判別利刪别到制刷刻則削前剛剩剪副割力",
+    "功加务动助努劳効勒動務包匈化北匙匯匹区匿區十升半协協单南単博占卡卢印危即却卷卸卻",
+    "厄历压原去县参參及双反发取受变叠口古句另只叫可台史右号各合吉吊同名后向吗否含启告",
+    "员周味呼命和品哇哈响員哥哪唯商問啟喀喬單嗎嘉嘗器嚴四回因囲围固国图圈國圍圖土圣圧",
+    "在圭地圾址均坎坏块坡坦垂垃型埃埋城域埠執基堆堡報場塊塔塞填境増增墨壊壓壞士声处备",
+    "変复夏外多够夠大天太夫央失头夹夾奇奈套奥奧奨好如姆始姓威媒子字存孟学學它守安完宏",
+    "宗定宛实実客宣害家容宽寄密富察實寫寬寸对寻导対封専射将將專尋對導小少尔尚尝尤就尺",
+    "尼尽尾局层屏展属層屬山岛岡岸島嵌州工左差己已巴市布希帐帕带帮帰帳帶常幅幕干平年并",
+    "幾序库应底府废度庫康廢延廷建开异弃式引弗弧弱張強强彈归当录形彩影径待很律後徑従得",
+    "從御復循微德心必志応快忽态急性怪总恢恩息恰您情惠想意愛感態慮憑憶應戈成我或截戳戴",
+    "戶户戻所手才扎打托执扩扫扬扱批找承技把抑抓折択护报押抽拆拉拒拖拜拟拡拣拥择括拷拿",
+    "持挂指按挪挿捕损换捨据捲捷授掉排掛採探接控推掩揀描提插換援損搜摘摩撒撤播擁擇擊操",
+    "據擬擴支收改放政故效敏敗敘教散敦数整數文料斜斥断斯新斷方於施旁族旗无既日旧早时旺",
+    "昂昆昇明易星映昧是显時普景智暂暗暫曖曜曲更書曼替最會月有服朗望朝期木未末本术机杀",
+    "权杜束杠条来杰東松板构析林枚果架柄柏某查査标栈栏树校样核根格桁框案桌桑档梅條检棄",
+    "森植検楚業極概構様槽標模樣権横樹機橫檔檢欄權次欧款歐止正此步歧歷死殊残段毀毁母毎",
+    "每比毛毫民水永求汇汉汗江汶決沃沒沙没河治況法波注泰泽洋洛洲活派流测济浏浮海消涉深",
+    "混添清済減渡測港游湊湾源準溢滑滚满滤滿漏演漢澳激濾瀏点為烏热無焦然照爍爱父爾片版",
+    "牌牙物特状狀独獨獲率王环现玻班現理瑞瑟瑪環瓜瓦甘生產用由申电甸画界留略番畫異當疊",
+    "疏疑発登發白百的皆皮益监盒盖盘盡監盤盧目直相省看真眠着矢知短码破硬确碎碟確碼磁礎",
+    "礙示社祖禁福离私种科秒秘称移稀程種稱稼稿穆積空突窗立竖站章端競笔符第等筒答策签简",
+    "算管箭箱節範築簇簡簽籤米类精糊系紀約紋納紐純紙級素索紧累細紹終組経結絕絡給統絶經",
+    "継続維網綴緒線緣編緩緬縮總繪繰繼續约级纯纳纹线组细终绍经绑结绘给络绝统继续维缀缓",
+    "编缩缺网罗罩置署羅美羣群義翁翻老考者而耗耳耶联聖聚聯聲肯背胡能脈脚脫脱腊臘臨自臭",
+    "至致臺與舊般良色艾节芬花苏若英范茨茲荷莫莱获菜菲萄萨萬落著葡蒂蒙蓋薩藏蘇蘭虑處虚",
+    "虛號螢蟲行術衝补表被装補裝裡製複西要覆見規視覧親覽觀见规视览角解触觸言訂計訊討記",
+    "設許訳診註証評詞詢試詰話該詳誌認語誤說説読調請論諸諾證識警譯議護讀變讓计订认议记",
+    "许设访证识诊词译试话询该详语误说请诸诺读调谢象貝負貯貼資賓質賴贝负败质贮贾资赋赖",
+    "赤赫起超越足距跟跡跨路跳踪蹤身軟転較載輔輪輯輸轉车转轮软载较辅辑输辛辞辨边込达辿",
+    "过运近返还这进远连迟迪述迴追退送适逆选透逐递途逗這通速造連週進逻逾遅遇運遍過道達",
+    "違遞遠適遭遮遲遵遷選遺避還邊那邦邮郎郡部郵都配采釈释釋里重量金鈕銷錄錯録鍵鎖鏈鐘",
+    "鑰针钟钥钮链销锁错锡键長长門閃閉開間関閱闊關门闭问闲间阅阱防阻阿附际降限陣除陰陶",
+    "陷陽隆階随隐隔際障隠隨隱隸雅集雙雜離零雷電需霍静靜非靠面韓韦音響頁頂項順須預頓領",
+    "頭題額顏類顯页顶项顺须顿预频题颜额风飾餘饰首香馬驗马验體高魯鲁麥麦麼黎黑默黙點鼠",
+    "齊齐가각간갈감갑값강같개객갯거건검것게겟격견결경계고골공과관괄교구국권귀규그근글",
+    "금급기긴길김깁깃깅까께꼴꾸꿀꿈꿉뀌끊끝나난날남납났내낼냅너널넘넣네년노논높놓누뉴",
+    "는능니닌님닙다단닫달답당대더덤덮데덱덴델도독돌동되된될됨됩두둘뒤듈드든들듭등디딩",
+    "따때떤또뜀라락란람랍랑래랜램랫략량러럭런럼렇레렉려력련렬렸령로록론롤롬롭롯료루룰",
+    "룹류르른를름릅리릭린릴림립릿링마막만많말맞매맨맵머먼멀멈메멤며면명모목못무묶문물",
+    "므미민밀밋밍및바반받발방배백버번범법벗베벤벨변별병보복본볼부북분불붙뷰브블비빈빌",
+    "빠빼사삭산살상새색생서석선설섬성세섹셀셋션셸소속손송쇄수순술숨숫쉼슈스슬습시식신",
+    "실심십싱썬쓰쓸씁아안않알암압았앞애액야약양어언업없었에엔엘여역연열영예옛오온올옮",
+    "옴옵와완외왼요용우운울움웁웃워원월웨위윈유율으은을음응의이인일읽임입있자작잘잠잡",
+    "장재잭저적전절점접정제젝젯져졌조족존종주준줄줌줍중쥯즈증지직진질짐집징짜짝짧째쪽",
+    "차찬참창찾채책처첫청체초총최추축출춤충취치칙칠침카칸칼캐캘커컨컬컴케켓코콘콜쿠쿼",
+    "퀀크큰클큼큽키타탄탈탐태택탬탭터턴테텍템토통투튜트특틀티틴팅파판패퍼페편평포폭폴",
+    "폼표푸풀프플피픽핀필핑하한할함합항해핸했행향허헤현형호혹혼홈화확환활황회획횟효후",
+    "히힌",
+);
@@ -0,0 +1,192 @@
+//! Search the raw input bytes for a keyword before decoding.
+//!
+//! Decoding every byte of a large image just to find one keyword is slow.
+//! Instead, the keyword is encoded in the scanner's encoding and searched as
+//! byte sequence. `FindingCollection::from()` then decodes only the decoder
+//! input windows covering the string around a hit.
+
+use crate::mission::CustomEncoding;
+use crate::mojibake;
+use anyhow::{anyhow, Result};
+use encoding_rs::Encoding;
+use encoding_rs::ISO_2022_JP;
+use encoding_rs::UTF_16BE;
+use encoding_rs::UTF_16LE;
+use memchr::memmem::Finder;
+use std::fmt;
+
+/// A keyword and its byte representation in one encoding.
+#[derive(Clone)]
+pub struct PreSearch {
+    /// The keyword as given by the user.
+    pub keyword: String,
+    /// `keyword` encoded in the scanner's encoding.
+    pub needle: Vec<u8>,
+    /// Searches `needle`.
+    finder: Finder<'static>,
+}
+
+impl PreSearch {
+    /// Constructor. Fails when `keyword` is empty or can not be represented
+    /// in the encoding.
+    pub fn new(
+        keyword: &str,
+        encoding: &'static Encoding,
+        custom_encoding: Option<&CustomEncoding>,
+        print_encoding_as_ascii: bool,
+    ) -> Result<Self> {
+        if keyword.is_empty() {
+            return Err(anyhow!("the keyword is empty"));
+        }
+        let needle = encode(keyword, encoding, custom_encoding, print_encoding_as_ascii)
+            .ok_or_else(|| anyhow!("can not encode keyword `{}`", keyword))?;
+        let finder = Finder::new(&needle).into_owned();
+        Ok(Self {
+            keyword: keyword.to_string(),
+            needle,
+            finder,
+        })
+    }
+
+    /// Returns the positions of all hits in `buf` in ascending order, and
+    /// the number of bytes of a hit cut by the end of `buf`. Feed this
+    /// number as `partial_len_before` when searching the following buffer:
+    /// a hit completed at its beginning is reported at position `0`. A cut
+    /// hit at the end of `buf` is reported at the position where it starts.
+    pub fn find_hits(&self, buf: &[u8], partial_len_before: usize) -> (Vec<usize>, usize) {
+        let mut hits = Vec::new();
+        if partial_len_before > 0 && buf.starts_with(&self.needle[partial_len_before..]) {
+            hits.push(0);
+        }
+        for h in self.finder.find_iter(buf) {
+            if hits.last() != Some(&h) {
+                hits.push(h);
+            }
+        }
+        let partial_len = (1..self.needle.len())
+            .rev()
+            .find(|k| buf.ends_with(&self.needle[..*k]))
+            .unwrap_or(0);
+        if partial_len > 0 {
+            hits.push(buf.len() - partial_len);
+        }
+        (hits, partial_len)
+    }
+}
+
+/// We do not print the `Finder`'s internals.
+impl fmt::Debug for PreSearch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "PreSearch {{ keyword: {:?}, needle: {:02x?} }}",
+            self.keyword, self.needle
+        )
+    }
+}
+
+/// Encodes `keyword`. Returns `None` when a character can not be
+/// represented. Note, `encoding_rs` encodes UTF-16 as UTF-8, so we do it
+/// here.
+fn encode(
+    keyword: &str,
+    encoding: &'static Encoding,
+    custom_encoding: Option<&CustomEncoding>,
+    print_encoding_as_ascii: bool,
+) -> Option<Vec<u8>> {
+    match custom_encoding {
+        Some(CustomEncoding::CharMap(char_map)) => keyword
+            .chars()
+            .map(|c| {
+                char_map
+                    .table
+                    .iter()
+                    .position(|t| *t == Some(c))
+                    .map(|b| b as u8)
+            })
+            .collect(),
+        Some(CustomEncoding::DoubleUtf8) => Some(
+            keyword
+                .bytes()
+                .map(mojibake::byte_to_char)
+                .collect::<String>()
+                .into_bytes(),
+        ),
+        None if print_encoding_as_ascii => {
+            if keyword.is_ascii() {
+                Some(keyword.as_bytes().to_vec())
+            } else {
+                None
+            }
+        }
+        None if encoding == UTF_16LE => {
+            Some(keyword.encode_utf16().flat_map(u16::to_le_bytes).collect())
+        }
+        None if encoding == UTF_16BE => {
+            Some(keyword.encode_utf16().flat_map(u16::to_be_bytes).collect())
+        }
+        None => {
+            let (bytes, _, had_unmappable) = encoding.encode(keyword);
+            if had_unmappable {
+                None
+            } else if encoding == ISO_2022_JP {
+                Some(strip_escape_sequences(&bytes).to_vec())
+            } else {
+                Some(bytes.into_owned())
+            }
+        }
+    }
+}
+
+/// ISO-2022-JP switches between character sets with escape sequences:
+/// `日本` is encoded as `ESC $ B F|K\ ESC ( B`. In the input, the keyword is
+/// mostly part of a longer run of the same character set. We therefore
+/// search without the escape sequence at the beginning and the one
+/// returning to ASCII at the end.
+fn strip_escape_sequences(bytes: &[u8]) -> &[u8] {
+    let mut bytes = bytes;
+    if bytes.first() == Some(&0x1b) && bytes.len() >= 3 {
+        bytes = &bytes[3..];
+    }
+    bytes.strip_suffix(b"\x1b(B").unwrap_or(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use encoding_rs::KOI8_R;
+    use encoding_rs::SHIFT_JIS;
+    use encoding_rs::UTF_8;
+
+    #[test]
+    fn test_encode() {
+        let ps = PreSearch::new("пароль", KOI8_R, None, false).unwrap();
+        assert_eq!(ps.needle, b"\xd0\xc1\xd2\xcf\xcc\xd8");
+        let ps = PreSearch::new("ab", UTF_16LE, None, false).unwrap();
+        assert_eq!(ps.needle, b"a\0b\0");
+        let ps = PreSearch::new("ab", UTF_16BE, None, false).unwrap();
+        assert_eq!(ps.needle, b"\0a\0b");
+        let ps = PreSearch::new("日本", SHIFT_JIS, None, false).unwrap();
+        assert_eq!(ps.needle, b"\x93\xfa\x96\x7b");
+        // Without the escape sequences of ISO-2022-JP.
+        let ps = PreSearch::new("日本", ISO_2022_JP, None, false).unwrap();
+        assert_eq!(ps.needle, b"F|K\\");
+        let ps = PreSearch::new("a日本", ISO_2022_JP, None, false).unwrap();
+        assert_eq!(ps.needle, b"a\x1b$BF|K\\");
+        let ps = PreSearch::new("é", UTF_8, Some(&CustomEncoding::DoubleUtf8), false).unwrap();
+        assert_eq!(ps.needle, "Ã©".as_bytes());
+
+        assert!(PreSearch::new("日本", KOI8_R, None, false).is_err());
+        assert!(PreSearch::new("é", UTF_8, None, true).is_err());
+        assert!(PreSearch::new("", UTF_8, None, false).is_err());
+    }
+
+    #[test]
+    fn test_find_hits() {
+        let ps = PreSearch::new("abc", UTF_8, None, false).unwrap();
+        assert_eq!(ps.find_hits(b"xabcxxabcxab", 0), (vec![1, 6, 10], 2));
+        assert_eq!(ps.find_hits(b"cxxxx", 2), (vec![0], 0));
+        assert_eq!(ps.find_hits(b"bcxxx", 2), (vec![], 0));
+        assert_eq!(ps.find_hits(b"abcxa", 0), (vec![0, 4], 1));
+    }
+}
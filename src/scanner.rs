@@ -3,11 +3,23 @@
 
 extern crate encoding_rs;
 
+use crate::charmap::CharMapDecoder;
+use crate::finding::Precision;
+use crate::finding::OUTPUT_BUF_LEN;
 use crate::input::ByteCounter;
+use crate::mission::CustomEncoding;
 use crate::mission::Mission;
 use crate::mission::MISSIONS;
+use crate::mojibake::DoubleUtf8Decoder;
+use crate::script_filter::ScriptSet;
 use encoding_rs::Decoder;
+use encoding_rs::DecoderResult;
+use encoding_rs::ISO_2022_JP;
+use encoding_rs::UTF_16BE;
+use encoding_rs::UTF_16LE;
+use encoding_rs::UTF_8;
 use std::ops::Deref;
+use std::str;
 
 /// A vector of `ScannerState` s.
 pub struct ScannerStates {
@@ -45,7 +57,7 @@ pub struct ScannerState {
     /// The decoder may hold in its internal state, among other
     /// things, some bytes of output, when a multibyte encoder was cut at the end
     /// of a buffer.
-    pub decoder: Decoder,
+    pub decoder: ScannerDecoder,
 
     /// For short strings (`< chars_min_nb`) at the very end of the buffer, we
     /// can not decide immediately, if they have to be printed or not, because we
@@ -62,6 +74,36 @@ pub struct ScannerState {
     /// whatever length it has.
     pub last_run_str_was_printed_and_is_maybe_cut_str: bool,
 
+    /// The last string did not pass `Mission::pass_str_filter()`. When it
+    /// touched the right boundary of the buffer, it is still followed with
+    /// `last_run_str_was_printed_and_is_maybe_cut_str`: the first string of
+    /// the next run completing it is dropped too.
+    pub last_run_str_is_rejected: bool,
+
+    /// The Scripts of the last string, when `Mission::max_scripts` is set.
+    /// A string of the next run completing it may not add more Scripts.
+    pub last_run_scripts: ScriptSet,
+
+    /// When `Mission::holds_whole_str()`, the chunks of the current string,
+    /// held back until the string ends and can be filtered and repaired as a
+    /// whole. At most `HELD_STR_LEN_MAX` bytes are held.
+    pub held_str: Option<HeldStr>,
+
+    /// In pre-search mode: the number of bytes of the encoded keyword found
+    /// at the very end of the last buffer. See `PreSearch::find_hits()`.
+    pub pre_search_partial_len: usize,
+
+    /// The input bytes every character in `FindingCollection::output_buffer`
+    /// was decoded from, indexed by the position of its first byte in the
+    /// output buffer. Entries for the characters of `last_scan_run_leftover`
+    /// are kept at the beginning for the next run. Empty, unless
+    /// `Mission::record_char_spans`.
+    pub char_spans: Vec<CharSpan>,
+
+    /// Absolute position of the first input byte, the next character the
+    /// decoder outputs is decoded from.
+    pub next_char_position: ByteCounter,
+
     /// This an absolute byte counter counting bytes of the input stream. The
     /// value will be update after a `FindingCollection::scan()` run to point to the first not
     /// scanned byte in the input stream.
@@ -73,7 +115,7 @@ impl ScannerState {
     pub fn new(mission: &'static Mission) -> Self {
         Self {
             mission,
-            decoder: mission.encoding.new_decoder_without_bom_handling(),
+            decoder: ScannerDecoder::new(mission),
             //
             // We keep only short substrings for the next run, because about all
             // longer ones we can decide immediately.
@@ -83,21 +125,233 @@ impl ScannerState {
             // maximum 4 bytes in UTF-8.
             last_scan_run_leftover: String::with_capacity(mission.output_line_char_nb_max),
             last_run_str_was_printed_and_is_maybe_cut_str: false,
+            last_run_str_is_rejected: false,
+            last_run_scripts: ScriptSet::default(),
+            held_str: None,
+            pre_search_partial_len: 0,
+            char_spans: if mission.record_char_spans {
+                vec![CharSpan::default(); OUTPUT_BUF_LEN]
+            } else {
+                Vec::new()
+            },
+            next_char_position: mission.counter_offset,
             consumed_bytes: mission.counter_offset,
         }
     }
 }
 
+/// The input bytes `start..end` a decoded character was read from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct CharSpan {
+    pub start: ByteCounter,
+    pub end: ByteCounter,
+}
+
+/// The maximum length in bytes of the text a `HeldStr` holds. Longer
+/// strings are filtered and released part by part with a warning, see
+/// `FindingCollection::push_held_str_part()`. While a string is held, the
+/// merger keeps back the findings of all missions, see
+/// `finding_collection::PendingFindings`: this bounds their number too.
+#[cfg(not(test))]
+pub const HELD_STR_LEN_MAX: usize = 0x10000;
+
+#[cfg(test)]
+pub const HELD_STR_LEN_MAX: usize = 0x200;
+
+/// A string, decoded so far, whose chunks were not printed yet, see
+/// `ScannerState::held_str`.
+#[derive(Debug, Default)]
+pub struct HeldStr {
+    /// The chunks joined, after `context_len` bytes of context.
+    pub s: String,
+    /// The length of the end of the string's last part, kept at the
+    /// beginning of `s`, so that the string filters find matches spanning
+    /// the parts. The context is not printed again.
+    pub context_len: usize,
+    /// Number of characters of the whole string, counted chunk by chunk for
+    /// `Mission::chars_max_nb`.
+    pub char_nb: usize,
+    /// Everything `Finding` needs to know about the chunks, but their text.
+    pub chunks: Vec<HeldChunk>,
+    /// Set when a part of the string passed the string filters already. The
+    /// rest of it is printed too.
+    pub is_accepted: bool,
+}
+
+/// A chunk of a `HeldStr`, the future `Finding`.
+#[derive(Debug)]
+pub struct HeldChunk {
+    pub input_file_id: Option<u8>,
+    pub position: ByteCounter,
+    pub position_precision: Precision,
+    pub start: ByteCounter,
+    pub byte_len: usize,
+    /// Length of the chunk's text in `HeldStr::s`.
+    pub len: usize,
+    pub s_completes_previous_s: bool,
+}
+
+/// Most encodings are decoded by `encoding_rs`. Encodings it does not know
+/// about come with their own decoder.
+pub enum ScannerDecoder {
+    /// Decoder for all encodings listed with `--list-encodings`.
+    EncodingRs(Decoder),
+    /// Decoder for custom single-byte encodings defined in a mapping file.
+    CharMap(CharMapDecoder),
+    /// Decoder for the synthetic `double-UTF-8` encoding.
+    DoubleUtf8(DoubleUtf8Decoder),
+}
+
+impl ScannerDecoder {
+    /// Constructor. Returns a decoder in neutral state suitable for
+    /// `mission`.
+    pub fn new(mission: &'static Mission) -> Self {
+        match mission.custom_encoding {
+            Some(CustomEncoding::CharMap(ref char_map)) => {
+                Self::CharMap(CharMapDecoder::new(char_map))
+            }
+            Some(CustomEncoding::DoubleUtf8) => Self::DoubleUtf8(DoubleUtf8Decoder::new()),
+            None => Self::EncodingRs(mission.encoding.new_decoder_without_bom_handling()),
+        }
+    }
+
+    /// Forwards to the decoder's `decode_to_str_without_replacement()`.
+    #[inline]
+    pub fn decode_to_str_without_replacement(
+        &mut self,
+        src: &[u8],
+        dst: &mut str,
+        last: bool,
+    ) -> (DecoderResult, usize, usize) {
+        match self {
+            Self::EncodingRs(d) => d.decode_to_str_without_replacement(src, dst, last),
+            Self::CharMap(d) => d.decode_to_str_without_replacement(src, dst, last),
+            Self::DoubleUtf8(d) => d.decode_to_str_without_replacement(src, dst, last),
+        }
+    }
+
+    /// Prepares the decoder to continue after skipping `src`. Most decoders
+    /// are reset. A decoder whose state depends on escape sequences, e.g.
+    /// ISO-2022-JP's, decodes `src` and drops the output instead, so that it
+    /// knows the character set in use after `src`.
+    pub fn skip(&mut self, mission: &'static Mission, src: &[u8]) {
+        match self {
+            Self::EncodingRs(d) if d.encoding() == ISO_2022_JP => {
+                let mut output_buffer_bytes = [0u8; 0x400];
+                let output_buffer = str::from_utf8_mut(&mut output_buffer_bytes).unwrap();
+                let mut read = 0;
+                while read < src.len() {
+                    let (_, r, _) =
+                        d.decode_to_str_without_replacement(&src[read..], output_buffer, false);
+                    read += r;
+                }
+            }
+            _ => *self = Self::new(mission),
+        }
+    }
+
+    /// The number of input bytes a character `c` is encoded with, if it
+    /// does not depend on the decoder's state.
+    fn char_width(&self) -> Option<fn(char) -> usize> {
+        match self {
+            Self::EncodingRs(d) if d.encoding() == UTF_8 => Some(char::len_utf8),
+            Self::EncodingRs(d) if d.encoding() == UTF_16LE || d.encoding() == UTF_16BE => {
+                Some(|c| 2 * c.len_utf16())
+            }
+            Self::EncodingRs(d) if d.encoding().is_single_byte() => Some(|_| 1),
+            Self::CharMap(_) => Some(|_| 1),
+            _ => None,
+        }
+    }
+
+    /// Like `decode_to_str_without_replacement()`, but also records in
+    /// `spans` the input bytes of every character written to `dst`. `spans`
+    /// is indexed like `dst`, `src_position` is the absolute position of
+    /// `src` in the input stream. `next_char_position` is where the next
+    /// character starts; it is updated as characters are decoded.
+    ///
+    /// When the width of a character depends on the decoder's state, e.g.
+    /// with escape sequences, we feed the decoder byte by byte: a character
+    /// then spans all bytes since the previous output.
+    pub fn decode_with_spans(
+        &mut self,
+        src: &[u8],
+        dst: &mut str,
+        last: bool,
+        src_position: ByteCounter,
+        next_char_position: &mut ByteCounter,
+        spans: &mut [CharSpan],
+    ) -> (DecoderResult, usize, usize) {
+        if let Some(char_width) = self.char_width() {
+            let (result, read, written) = self.decode_to_str_without_replacement(src, dst, last);
+            for (i, c) in dst[..written].char_indices() {
+                let start = *next_char_position;
+                *next_char_position += char_width(c) as ByteCounter;
+                spans[i] = CharSpan {
+                    start,
+                    end: *next_char_position,
+                };
+            }
+            if let DecoderResult::Malformed(_, extra) = result {
+                *next_char_position = src_position + (read - extra as usize) as ByteCounter;
+            }
+            return (result, read, written);
+        }
+
+        let mut read = 0;
+        let mut written = 0;
+        // The first round reads nothing: it flushes a character the decoder
+        // kept from the last call, which ends at `src_position`.
+        let mut step = 0;
+        loop {
+            let src_end = (read + step).min(src.len());
+            step = 1;
+            let (result, r, w) = self.decode_to_str_without_replacement(
+                &src[read..src_end],
+                &mut dst[written..],
+                last && src_end == src.len(),
+            );
+            read += r;
+            let end = src_position + read as ByteCounter;
+            for (i, _) in dst[written..written + w].char_indices() {
+                spans[written + i] = CharSpan {
+                    start: *next_char_position,
+                    end,
+                };
+            }
+            written += w;
+            if w > 0 {
+                *next_char_position = end;
+            }
+            match result {
+                DecoderResult::Malformed(_, extra) => {
+                    *next_char_position = end - extra as ByteCounter;
+                    return (result, read, written);
+                }
+                DecoderResult::OutputFull => return (result, read, written),
+                DecoderResult::InputEmpty if read == src.len() => return (result, read, written),
+                DecoderResult::InputEmpty => {}
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use crate::finding::Precision;
     use crate::finding_collection::FindingCollection;
+    use crate::grep::GrepPatterns;
+    use crate::grep::MatchMode;
+    use crate::mission::CustomEncoding;
     use crate::mission::Mission;
     use crate::mission::{Utf8Filter, AF_ALL, AF_CTRL, AF_WHITESPACE, UBF_LATIN, UBF_NONE};
     use crate::mission::{UTF8_FILTER_ALL_VALID, UTF8_FILTER_LATIN};
+    use crate::pre_search::PreSearch;
+    use crate::str_stats::StrStatsFilter;
     use encoding_rs::Encoding;
     use lazy_static::lazy_static;
+    use regex::Regex;
 
     // To see println!() output in test run, launch
     // cargo test   -- --nocapture
@@ -105,90 +359,403 @@ pub mod tests {
     lazy_static! {
         pub static ref MISSION_ALL_UTF8: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
             chars_min_nb: 3,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             // this is a pass all filter
             filter: UTF8_FILTER_ALL_VALID,
             output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_MAX_SCRIPTS: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: Some(1),
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
         };
     }
     lazy_static! {
         pub static ref MISSION_LATIN_UTF8: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
             chars_min_nb: 3,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             // this is a pass all filter
             filter: UTF8_FILTER_LATIN,
             output_line_char_nb_max: 10,
+            record_char_spans: false,
         };
     }
 
     lazy_static! {
         pub static ref MISSION_LATIN_UTF8_GREP42: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
             chars_min_nb: 3,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             // this is a pass all filter
             filter: Utf8Filter {
                 af: AF_ALL & !AF_CTRL | AF_WHITESPACE,
                 ubf: UBF_LATIN,
-                grep_char: Some(42),
+                grep_char: Some('*'),
+                script_filter: None,
+                char_ranges: None,
             },
             output_line_char_nb_max: 10,
+            record_char_spans: false,
         };
     }
 
     lazy_static! {
         pub static ref MISSION_ALL_X_USER_DEFINED: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("x-user-defined").as_bytes()).unwrap(),
             chars_min_nb: 3,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             filter: UTF8_FILTER_ALL_VALID,
             output_line_char_nb_max: 10,
+            record_char_spans: false,
         };
     }
     lazy_static! {
         pub static ref MISSION_ASCII: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("x-user-defined").as_bytes()).unwrap(),
             chars_min_nb: 3,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             // this is a pass all filter
             filter: Utf8Filter {
                 af: AF_ALL & !AF_CTRL | AF_WHITESPACE,
                 ubf: UBF_NONE,
                 grep_char: None,
+                script_filter: None,
+                char_ranges: None,
             },
             output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_UTF16LE: Mission = Mission {
+            mission_id: 1,
+            def_id: 1,
+            label: "b".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: UTF_16LE,
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            filter: UTF8_FILTER_LATIN,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_ISO_2022_JP: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: Encoding::for_label(("iso-2022-jp").as_bytes()).unwrap(),
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            // this is a pass all filter
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_DOUBLE_UTF8: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: Some(CustomEncoding::DoubleUtf8),
+            detect_mojibake: true,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: Encoding::for_label(("x-user-defined").as_bytes()).unwrap(),
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_GREP_PATTERNS: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: Some(
+                GrepPatterns::new(
+                    vec!["secret".to_string(), "пароль".to_string()],
+                    MatchMode::default()
+                )
+                .unwrap()
+            ),
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_REGEX: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: Some(Regex::new(r"\d{4}-\d{2}").unwrap()),
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
+        };
+    }
+    lazy_static! {
+        pub static ref MISSION_PRE_SEARCH: Mission = Mission {
+            mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
+            counter_offset: 10_000,
+            print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: Some(PreSearch::new("key", UTF_16LE, None, false).unwrap()),
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
+            encoding: UTF_16LE,
+            chars_min_nb: 3,
+            chars_max_nb: None,
+            require_same_unicode_block: false,
+            max_scripts: None,
+            filter: UTF8_FILTER_ALL_VALID,
+            output_line_char_nb_max: 10,
+            record_char_spans: false,
         };
     }
     lazy_static! {
         pub static ref MISSION_REAL_DATA_SCAN: Mission = Mission {
             mission_id: 0,
+            def_id: 0,
+            label: "a".to_string(),
             counter_offset: 10_000,
             print_encoding_as_ascii: false,
+            custom_encoding: None,
+            detect_mojibake: false,
+            grep_patterns: None,
+            regex: None,
+            invert_match: false,
+            pre_search: None,
+            min_score: None,
+            str_stats_filter: StrStatsFilter::default(),
+            word_list: None,
+            identify_language: false,
+            languages: None,
             encoding: Encoding::for_label(("utf-8").as_bytes()).unwrap(),
             chars_min_nb: 4,
+            chars_max_nb: None,
             require_same_unicode_block: false,
+            max_scripts: None,
             // this is a pass all filter
             filter: UTF8_FILTER_LATIN,
             output_line_char_nb_max: 60,
+            record_char_spans: false,
         };
     }
+
+    /// `m` recording the input bytes of every character, as with `--merge`
+    /// and the machine-readable output formats.
+    pub fn with_char_spans(m: &Mission) -> &'static Mission {
+        Box::leak(Box::new(Mission {
+            record_char_spans: true,
+            ..m.clone()
+        }))
+    }
+
     #[test]
     fn test_scan_input_buffer_chunks() {
         // This test uses INP_BUF_LEN=0x20 and
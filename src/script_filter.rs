@@ -0,0 +1,255 @@
+//! Filter characters by their Unicode Script and Block properties.
+//!
+//! The Unicode-block-filter `Utf8Filter::ubf` looks only at the UTF-8 leading
+//! byte of a character, which is a coarse approximation of Unicode blocks:
+//! e.g. Chinese and Korean share leading bytes and all 4-byte characters
+//! look alike. `ScriptFilter` decodes the code point and looks up its Script
+//! and Block property instead. As this is more expensive, `ubf` is checked
+//! first.
+
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+use std::fmt;
+use unicode_blocks::{find_unicode_block, UnicodeBlock};
+use unicode_script::{Script, UnicodeScript};
+
+/// Separates the names in a script filter expression, e.g. `Han,Hiragana`.
+pub const SCRIPT_FILTER_SEPARATOR: char = ',';
+
+/// Names with this prefix always denote a Unicode Block, e.g. `block:Thai`.
+/// Without, a name denoting a Script and a Block at the same time is
+/// interpreted as Script.
+pub const BLOCK_PREFIX: &str = "block:";
+
+/// A pseudo name selecting all blocks in `EMOJI_BLOCKS`.
+pub const EMOJI_ALIAS: &str = "Emoji";
+
+/// Blocks containing emoji and pictographs.
+const EMOJI_BLOCKS: [&str; 7] = [
+    "Miscellaneous Symbols",
+    "Dingbats",
+    "Miscellaneous Symbols and Pictographs",
+    "Emoticons",
+    "Transport and Map Symbols",
+    "Supplemental Symbols and Pictographs",
+    "Symbols and Pictographs Extended-A",
+];
+
+/// Number of `u64` needed to hold one bit per `Script` value.
+const SCRIPT_WORDS: usize = 4;
+/// Number of `u64` needed to hold one bit per Unicode block.
+const BLOCK_WORDS: usize = 6;
+
+lazy_static! {
+    /// All Unicode blocks ordered by their first code point. A block is
+    /// identified by its index in this list.
+    static ref UNICODE_BLOCKS: Vec<UnicodeBlock> = {
+        let mut v = Vec::new();
+        let mut c = 0u32;
+        while c <= char::MAX as u32 {
+            match char::from_u32(c).and_then(find_unicode_block) {
+                Some(b) => {
+                    v.push(b);
+                    c = b.end() + 1;
+                }
+                // Blocks start at multiples of 16.
+                None => c += 0x10,
+            }
+        }
+        assert!(v.len() <= BLOCK_WORDS * 64);
+        v
+    };
+}
+
+/// A set of Unicode Scripts and Blocks. A character passes the filter when
+/// it belongs to one of the Scripts or one of the Blocks.
+#[derive(Clone, Copy, Eq, PartialEq)]
+pub struct ScriptFilter {
+    /// Bit `n` is set, when the `Script` with value `n` is in the set.
+    scripts: [u64; SCRIPT_WORDS],
+    /// Bit `n` is set, when `UNICODE_BLOCKS[n]` is in the set.
+    blocks: [u64; BLOCK_WORDS],
+}
+
+impl ScriptFilter {
+    /// Parses a `SCRIPT_FILTER_SEPARATOR` separated list of Script names
+    /// (e.g. `Han` or `Hani`), Block names (e.g. `Hangul Syllables`) and
+    /// `EMOJI_ALIAS`. Block names are compared ignoring case, spaces,
+    /// hyphens and underscores.
+    pub fn parse(s: &str) -> Result<Self> {
+        let mut f = Self {
+            scripts: [0; SCRIPT_WORDS],
+            blocks: [0; BLOCK_WORDS],
+        };
+        for name in s.split(SCRIPT_FILTER_SEPARATOR).map(str::trim) {
+            if name.is_empty() {
+                return Err(anyhow!("empty name in script filter `{}`", s));
+            }
+            if name == EMOJI_ALIAS {
+                for b in EMOJI_BLOCKS.iter() {
+                    // The names are from the block list, they are found.
+                    f.add_block(block_index(b).unwrap());
+                }
+                continue;
+            }
+            if let Some(block) = name.strip_prefix(BLOCK_PREFIX) {
+                let i = block_index(block)
+                    .ok_or_else(|| anyhow!("unknown Unicode block `{}`", block))?;
+                f.add_block(i);
+                continue;
+            }
+            if let Some(script) = parse_script(name) {
+                let i = script as u8 as usize;
+                f.scripts[i / 64] |= 1 << (i % 64);
+                continue;
+            }
+            let i = block_index(name)
+                .ok_or_else(|| anyhow!("unknown Unicode script or block `{}`", name))?;
+            f.add_block(i);
+        }
+        Ok(f)
+    }
+
+    #[inline]
+    fn add_block(&mut self, i: usize) {
+        self.blocks[i / 64] |= 1 << (i % 64);
+    }
+
+    /// True when `c` belongs to one of the Scripts or Blocks of this
+    /// filter.
+    #[inline]
+    pub fn pass(&self, c: char) -> bool {
+        let i = c.script() as u8 as usize;
+        if self.scripts[i / 64] & (1 << (i % 64)) != 0 {
+            return true;
+        }
+        if self.blocks == [0; BLOCK_WORDS] {
+            return false;
+        }
+        let b = match find_unicode_block(c) {
+            Some(b) => b,
+            None => return false,
+        };
+        // `UNICODE_BLOCKS` is sorted and contains `b`.
+        let i = UNICODE_BLOCKS
+            .binary_search_by_key(&b.start(), |b| b.start())
+            .unwrap();
+        self.blocks[i / 64] & (1 << (i % 64)) != 0
+    }
+}
+
+/// Looks up the Script `name`, given as full name (`Old_Italic` or
+/// `Old Italic`) or as four-character short name (`Ital`).
+fn parse_script(name: &str) -> Option<Script> {
+    let name = name.replace(' ', "_");
+    Script::from_full_name(&name).or_else(|| Script::from_short_name(&name))
+}
+
+/// Returns the index in `UNICODE_BLOCKS` of the block `name`.
+fn block_index(name: &str) -> Option<usize> {
+    let loose = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_'))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let name = loose(name);
+    UNICODE_BLOCKS.iter().position(|b| loose(b.name()) == name)
+}
+
+/// The distinct Scripts of the characters in a string. `Common` and
+/// `Inherited` characters, e.g. digits, punctuation or combining marks, are
+/// used together with many Scripts and are never counted.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ScriptSet {
+    /// Bit `n` is set, when the `Script` with value `n` is in the set.
+    scripts: [u64; SCRIPT_WORDS],
+    /// Number of bits set in `scripts`.
+    nb: u8,
+}
+
+impl ScriptSet {
+    /// Adds the Script of `c` to the set, unless the set already holds
+    /// `nb_max` Scripts. Returns `false` when the Script could not be added.
+    #[inline]
+    pub fn insert(&mut self, c: char, nb_max: u8) -> bool {
+        let script = c.script();
+        if script == Script::Common || script == Script::Inherited {
+            return true;
+        }
+        let i = script as u8 as usize;
+        if self.scripts[i / 64] & (1 << (i % 64)) != 0 {
+            return true;
+        }
+        if self.nb >= nb_max {
+            return false;
+        }
+        self.scripts[i / 64] |= 1 << (i % 64);
+        self.nb += 1;
+        true
+    }
+
+    /// Empties the set.
+    #[inline]
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+impl fmt::Debug for ScriptFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "scripts: {:x?}, blocks: {:x?}",
+            self.scripts, self.blocks
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_pass() {
+        let f = ScriptFilter::parse("Han, Hira,block:hangul syllables,Emoji").unwrap();
+        assert!(f.pass('漢'));
+        assert!(f.pass('ひ'));
+        assert!(f.pass('한'));
+        assert!(f.pass('😀'));
+        // Katakana and Hangul Jamo are not selected.
+        assert!(!f.pass('カ'));
+        assert!(!f.pass('ᄀ'));
+        assert!(!f.pass('a'));
+        // A 4-byte Han character.
+        assert!(f.pass('𠀀'));
+
+        // `Thai` is a Script and a Block.
+        let f = ScriptFilter::parse("Thai").unwrap();
+        assert!(f.pass('ก'));
+        let f = ScriptFilter::parse("Devanagari Extended").unwrap();
+        assert!(f.pass('\u{a8e0}'));
+        assert!(!f.pass('क'));
+
+        assert!(ScriptFilter::parse("Klingon").is_err());
+        assert!(ScriptFilter::parse("Han,").is_err());
+        assert!(ScriptFilter::parse("block:Han").is_err());
+    }
+
+    #[test]
+    fn test_script_set() {
+        let mut set = ScriptSet::default();
+        assert!(set.insert('д', 1));
+        // Digits, punctuation and combining marks are `Common` or
+        // `Inherited`.
+        assert!(set.insert('7', 1));
+        assert!(set.insert('«', 1));
+        assert!(set.insert('\u{301}', 1));
+        assert!(set.insert('я', 1));
+        assert!(!set.insert('λ', 1));
+        assert!(set.insert('λ', 2));
+        assert!(!set.insert('a', 2));
+        set.clear();
+        assert!(set.insert('a', 1));
+    }
+}
@@ -0,0 +1,371 @@
+//! Summary statistics of a scan (`--stats`).
+//!
+//! The merger thread feeds every batch of `FindingCollection`s into `Stats`
+//! before printing the findings. At the end, the summary is printed to
+//! `stderr`, as text or as JSON document.
+
+use crate::finding::Finding;
+use crate::finding_collection::FindingCollection;
+use crate::input::{input_name, ByteCounter, UNREADABLE_REGIONS};
+use crate::mission::MISSIONS;
+use crate::options::StatsFormat;
+use serde_derive::Serialize;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::time::{Duration, Instant};
+use unicode_script::UnicodeScript;
+
+/// Number of longest strings reported.
+pub const LONGEST_STRINGS_NB: usize = 5;
+
+/// Number of characters of the longest strings printed in text format.
+const LONGEST_STRINGS_TEXT_LEN: usize = 60;
+
+/// A whole string, joined from the `Finding`s it was wrapped to.
+#[derive(Debug)]
+struct WholeStr {
+    chars: usize,
+    position: ByteCounter,
+    mission_id: usize,
+    text: String,
+}
+
+/// Statistics collected during a scan.
+#[derive(Debug)]
+pub struct Stats {
+    start: Instant,
+    /// Scanned bytes for every input, see `Finding::input_file_id`.
+    input_bytes: BTreeMap<Option<u8>, ByteCounter>,
+    /// Number of strings found by every mission.
+    strings: Vec<u64>,
+    /// Number of characters in findings for every Unicode script.
+    scripts: BTreeMap<&'static str, u64>,
+    /// The `LONGEST_STRINGS_NB` longest strings, longest first.
+    longest: Vec<WholeStr>,
+    /// For every mission the string its last `Finding` started.
+    pending: Vec<Option<WholeStr>>,
+    /// Number of `FindingCollection::str_buf_overflow` events.
+    buf_overflows: u64,
+}
+
+impl Stats {
+    pub fn new(mission_nb: usize, input_file_ids: impl Iterator<Item = Option<u8>>) -> Self {
+        Stats {
+            start: Instant::now(),
+            input_bytes: input_file_ids.map(|i| (i, 0)).collect(),
+            strings: vec![0; mission_nb],
+            scripts: BTreeMap::new(),
+            longest: Vec::with_capacity(LONGEST_STRINGS_NB + 1),
+            pending: (0..mission_nb).map(|_| None).collect(),
+            buf_overflows: 0,
+        }
+    }
+
+    /// Adds the results of all missions for one input slice.
+    pub fn add_batch(&mut self, results: &[Pin<Box<FindingCollection>>]) {
+        // All missions scan the same input.
+        if let Some(fc) = results.first() {
+            *self.input_bytes.entry(fc.input_file_id).or_insert(0) += fc.input_len as ByteCounter;
+        }
+        for fc in results {
+            if fc.str_buf_overflow {
+                self.buf_overflows += 1;
+            }
+            for f in fc.v.iter() {
+                self.add(f);
+            }
+        }
+    }
+
+    fn add(&mut self, f: &Finding) {
+        let mission_id = f.mission.mission_id;
+        let mut chars = 0;
+        for c in f.s.chars() {
+            chars += 1;
+            *self.scripts.entry(c.script().full_name()).or_insert(0) += 1;
+        }
+
+        if f.s_completes_previous_s {
+            if let Some(p) = &mut self.pending[mission_id] {
+                p.chars += chars;
+                p.text.push_str(f.s);
+                return;
+            }
+        }
+        self.strings[mission_id] += 1;
+        let p = self.pending[mission_id].replace(WholeStr {
+            chars,
+            position: f.position,
+            mission_id,
+            text: f.s.to_string(),
+        });
+        if let Some(p) = p {
+            self.add_whole_str(p);
+        }
+    }
+
+    /// Keeps `s`, if it is one of the longest strings.
+    fn add_whole_str(&mut self, s: WholeStr) {
+        if self.longest.len() == LONGEST_STRINGS_NB
+            && self.longest.last().is_some_and(|l| l.chars >= s.chars)
+        {
+            return;
+        }
+        let i = self.longest.partition_point(|l| l.chars >= s.chars);
+        self.longest.insert(i, s);
+        self.longest.truncate(LONGEST_STRINGS_NB);
+    }
+
+    /// Completes the pending strings and stops the clock.
+    pub fn finish(&mut self) -> Duration {
+        for mission_id in 0..self.pending.len() {
+            if let Some(p) = self.pending[mission_id].take() {
+                self.add_whole_str(p);
+            }
+        }
+        self.start.elapsed()
+    }
+
+    /// Prints the statistics in `format`.
+    pub fn print(
+        &mut self,
+        out: &mut dyn Write,
+        format: StatsFormat,
+    ) -> Result<(), Box<std::io::Error>> {
+        let duration = self.finish();
+        match format {
+            StatsFormat::Text => self.print_text(out, duration),
+            StatsFormat::Json => self.print_json(out, duration),
+        }
+    }
+
+    fn print_text(
+        &self,
+        out: &mut dyn Write,
+        duration: Duration,
+    ) -> Result<(), Box<std::io::Error>> {
+        let bytes: ByteCounter = self.input_bytes.values().sum();
+        let secs = duration.as_secs_f64();
+        writeln!(out, "\nSCAN STATISTICS\n")?;
+        writeln!(
+            out,
+            "Scanned bytes: {} in {:.3} s ({:.2} MB/s)",
+            bytes,
+            secs,
+            throughput(bytes, secs) / 1e6
+        )?;
+        for (i, b) in &self.input_bytes {
+            writeln!(out, "\t{}: {}", input_name(*i), b)?;
+        }
+
+        writeln!(out, "Strings per scanner:")?;
+        for (m, n) in MISSIONS.v.iter().zip(&self.strings) {
+            writeln!(out, "\t({} {}): {}", m.label, m.encoding_name(), n)?;
+        }
+
+        writeln!(out, "Characters per script:")?;
+        for (s, n) in &self.scripts {
+            writeln!(out, "\t{}: {}", s, n)?;
+        }
+
+        writeln!(out, "Longest strings:")?;
+        for s in &self.longest {
+            let text: String = s.text.chars().take(LONGEST_STRINGS_TEXT_LEN).collect();
+            writeln!(
+                out,
+                "\t{} chars at {:#x} ({}): {}{}",
+                s.chars,
+                s.position,
+                MISSIONS[s.mission_id].label,
+                text,
+                if s.chars > LONGEST_STRINGS_TEXT_LEN {
+                    "…"
+                } else {
+                    ""
+                }
+            )?;
+        }
+
+        writeln!(out, "Output buffer overflows: {}", self.buf_overflows)?;
+
+        let unreadable = UNREADABLE_REGIONS.lock().unwrap();
+        writeln!(out, "Unreadable regions: {}", unreadable.len())?;
+        for r in unreadable.iter() {
+            writeln!(
+                out,
+                "\t{} from byte {}: {}",
+                input_name(r.input_file_id),
+                r.offset,
+                r.error
+            )?;
+        }
+        Ok(())
+    }
+
+    fn print_json(
+        &self,
+        out: &mut dyn Write,
+        duration: Duration,
+    ) -> Result<(), Box<std::io::Error>> {
+        let bytes: ByteCounter = self.input_bytes.values().sum();
+        let secs = duration.as_secs_f64();
+        let unreadable = UNREADABLE_REGIONS.lock().unwrap();
+        let report = StatsReport {
+            scanned_bytes: bytes,
+            // Milliseconds are precise enough.
+            seconds: (secs * 1000.0).round() / 1000.0,
+            bytes_per_second: throughput(bytes, secs).round() as u64,
+            inputs: self
+                .input_bytes
+                .iter()
+                .map(|(i, b)| InputReport {
+                    input: input_name(*i),
+                    bytes: *b,
+                })
+                .collect(),
+            missions: MISSIONS
+                .v
+                .iter()
+                .zip(&self.strings)
+                .map(|(m, n)| MissionReport {
+                    label: &m.label,
+                    encoding: m.encoding_name(),
+                    strings: *n,
+                })
+                .collect(),
+            scripts: &self.scripts,
+            longest_strings: self
+                .longest
+                .iter()
+                .map(|s| LongestStrReport {
+                    chars: s.chars,
+                    position: s.position,
+                    label: &MISSIONS[s.mission_id].label,
+                    text: &s.text,
+                })
+                .collect(),
+            output_buffer_overflows: self.buf_overflows,
+            unreadable_regions: unreadable
+                .iter()
+                .map(|r| UnreadableReport {
+                    input: input_name(r.input_file_id),
+                    offset: r.offset,
+                    error: &r.error,
+                })
+                .collect(),
+        };
+        serde_json::to_writer(&mut *out, &report).map_err(io::Error::from)?;
+        writeln!(out)?;
+        Ok(())
+    }
+}
+
+/// The statistics printed with `--stats json`.
+#[derive(Serialize)]
+struct StatsReport<'a> {
+    scanned_bytes: ByteCounter,
+    seconds: f64,
+    bytes_per_second: u64,
+    inputs: Vec<InputReport>,
+    missions: Vec<MissionReport<'a>>,
+    /// Number of characters for every Unicode script.
+    scripts: &'a BTreeMap<&'static str, u64>,
+    longest_strings: Vec<LongestStrReport<'a>>,
+    output_buffer_overflows: u64,
+    unreadable_regions: Vec<UnreadableReport<'a>>,
+}
+
+/// Scanned bytes of one input.
+#[derive(Serialize)]
+struct InputReport {
+    /// Path of the input file, `-` for `stdin`.
+    input: String,
+    bytes: ByteCounter,
+}
+
+/// Number of strings one mission found.
+#[derive(Serialize)]
+struct MissionReport<'a> {
+    label: &'a str,
+    encoding: &'a str,
+    strings: u64,
+}
+
+/// One of the longest strings, see `WholeStr`.
+#[derive(Serialize)]
+struct LongestStrReport<'a> {
+    chars: usize,
+    position: ByteCounter,
+    label: &'a str,
+    text: &'a str,
+}
+
+/// See `UnreadableRegion`.
+#[derive(Serialize)]
+struct UnreadableReport<'a> {
+    input: String,
+    offset: ByteCounter,
+    error: &'a str,
+}
+
+/// Bytes per second.
+fn throughput(bytes: ByteCounter, secs: f64) -> f64 {
+    if secs > 0.0 {
+        bytes as f64 / secs
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scanner::tests::MISSION_ALL_UTF8;
+    use crate::scanner::ScannerState;
+
+    #[test]
+    fn test_add_batch() {
+        let mut ss = ScannerState::new(&MISSION_ALL_UTF8);
+        let fc = FindingCollection::from(
+            &mut ss,
+            Some(1),
+            "Привет мир, hello!\u{0}ab\u{0}xyz".as_bytes(),
+            true,
+        );
+        let mut stats = Stats::new(1, [Some(1), Some(2)].into_iter());
+        stats.add_batch(&[fc]);
+        stats.finish();
+
+        assert_eq!(stats.input_bytes[&Some(1)], 34);
+        assert_eq!(stats.input_bytes[&Some(2)], 0);
+        assert_eq!(stats.strings, vec![2]);
+        assert_eq!(stats.scripts["Cyrillic"], 9);
+        assert_eq!(stats.scripts["Latin"], 8);
+        assert_eq!(stats.longest.len(), 2);
+        assert_eq!(stats.longest[0].text, "Привет мир, hello!");
+        assert_eq!(stats.longest[0].chars, 18);
+        assert_eq!(stats.longest[1].text, "xyz");
+        assert_eq!(stats.buf_overflows, 0);
+    }
+
+    #[test]
+    fn test_print_json() {
+        let mut ss = ScannerState::new(&MISSION_ALL_UTF8);
+        let fc = FindingCollection::from(&mut ss, None, r#"a "b" \c"#.as_bytes(), true);
+        let mut stats = Stats::new(1, [None].into_iter());
+        stats.add_batch(&[fc]);
+        let mut out = Vec::new();
+        stats.print(&mut out, StatsFormat::Json).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["scanned_bytes"], 8);
+        assert_eq!(value["inputs"][0]["input"], "-");
+        assert_eq!(value["inputs"][0]["bytes"], 8);
+        assert_eq!(value["missions"][0]["strings"], 1);
+        assert_eq!(value["scripts"]["Latin"], 3);
+        assert_eq!(value["longest_strings"][0]["text"], r#"a "b" \c"#);
+        assert_eq!(value["output_buffer_overflows"], 0);
+        assert!(value["unreadable_regions"].as_array().unwrap().is_empty());
+    }
+}
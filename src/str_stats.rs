@@ -0,0 +1,128 @@
+//! Reject findings that are padding or fill patterns.
+//!
+//! Runs like `AAAAAAAA`, `@@@@@@` or `ABABABAB` consist of valid characters
+//! and pass all character filters. Looking at the whole string, they have
+//! little information: few distinct characters, long runs of the same
+//! character or a low Shannon entropy.
+
+/// Filter criteria on statistics of whole strings. Criteria set to `None`
+/// are not checked.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct StrStatsFilter {
+    /// Minimum Shannon entropy in bits per character.
+    pub min_entropy: Option<f32>,
+    /// Maximum number of repetitions of the same character in a row.
+    pub max_run_len: Option<usize>,
+    /// Minimum number of distinct characters.
+    pub min_distinct_chars: Option<usize>,
+}
+
+impl StrStatsFilter {
+    /// True when at least one criterion is set.
+    #[inline]
+    pub fn is_set(&self) -> bool {
+        self.min_entropy.is_some()
+            || self.max_run_len.is_some()
+            || self.min_distinct_chars.is_some()
+    }
+
+    /// True when `s` satisfies all criteria.
+    pub fn pass(&self, s: &str) -> bool {
+        if !self.is_set() {
+            return true;
+        }
+        if let Some(max) = self.max_run_len {
+            if longest_run(s) > max {
+                return false;
+            }
+        }
+        let mut chars: Vec<char> = s.chars().collect();
+        let len = chars.len();
+        chars.sort_unstable();
+        // The number of occurrences of each distinct character.
+        let mut counts = Vec::new();
+        for (i, c) in chars.iter().enumerate() {
+            if i == 0 || chars[i - 1] != *c {
+                counts.push(1usize);
+            } else {
+                *counts.last_mut().unwrap() += 1;
+            }
+        }
+        if let Some(min) = self.min_distinct_chars {
+            if counts.len() < min {
+                return false;
+            }
+        }
+        if let Some(min) = self.min_entropy {
+            if entropy(&counts, len) < min {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Length of the longest sequence of the same character in `s`.
+fn longest_run(s: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut last = None;
+    for c in s.chars() {
+        if Some(c) == last {
+            run += 1;
+        } else {
+            run = 1;
+            last = Some(c);
+        }
+        longest = longest.max(run);
+    }
+    longest
+}
+
+/// Shannon entropy in bits per character, given the number of occurrences of
+/// each distinct character and the total number of characters `len`.
+fn entropy(counts: &[usize], len: usize) -> f32 {
+    counts
+        .iter()
+        .map(|n| {
+            let p = *n as f32 / len as f32;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pass() {
+        let f = StrStatsFilter {
+            min_entropy: Some(1.5),
+            ..StrStatsFilter::default()
+        };
+        assert!(!f.pass("AAAAAAAA"));
+        assert!(!f.pass("ABABABAB"));
+        assert!(f.pass("ABCDABCD"));
+        assert!(f.pass("Hello world"));
+
+        let f = StrStatsFilter {
+            max_run_len: Some(3),
+            ..StrStatsFilter::default()
+        };
+        assert!(!f.pass("x@@@@"));
+        assert!(f.pass("x@@@x@@@"));
+        assert!(f.pass("aaa"));
+
+        let f = StrStatsFilter {
+            min_distinct_chars: Some(3),
+            ..StrStatsFilter::default()
+        };
+        assert!(!f.pass("ABABABAB"));
+        assert!(f.pass("ABC"));
+        assert!(f.pass("ÄÖÜ"));
+
+        assert!(StrStatsFilter::default().pass("AAAA"));
+        assert!((entropy(&[1, 1, 1, 1], 4) - 2.0).abs() < 1e-6);
+    }
+}
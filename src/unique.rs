@@ -0,0 +1,626 @@
+//! Aggregate findings by their decoded text (`--unique`).
+//!
+//! Like `sort | uniq -c`, every distinct string is reported once with the
+//! number of its occurrences, its first and last position and the input
+//! files it occurs in. Strings wrapped into several `Finding`s are joined
+//! first. The aggregation table is kept in memory until it grows beyond
+//! `UNIQUE_TABLE_LEN_MAX` bytes. Then it is sorted by key and spilled into a
+//! temporary file, a so called run. At the end, all runs are merged, which
+//! sums up the records with the same key, and sorted again by the requested
+//! order, spilling into runs the same way.
+
+use crate::finding::Finding;
+use crate::format::DELIMITED;
+use crate::input::{input_name, ByteCounter};
+use crate::mission::MISSIONS;
+use crate::options::{OutputFormat, Radix, UniqueOrder, ARGS};
+use anyhow::{Context, Result};
+use serde_derive::Serialize;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::mem;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+/// Estimated size in bytes of the aggregation table, above which it is
+/// spilled to disk.
+#[cfg(not(test))]
+pub const UNIQUE_TABLE_LEN_MAX: usize = 0x1000_0000;
+#[cfg(test)]
+pub const UNIQUE_TABLE_LEN_MAX: usize = 0x100;
+
+/// Estimated memory in bytes used by one record besides its text.
+const RECORD_OVERHEAD_LEN: usize = 96;
+
+/// Number of the next temporary run file.
+static RUN_FILE_NB: AtomicUsize = AtomicUsize::new(0);
+
+/// One distinct string and its occurrences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The decoded string.
+    pub text: String,
+    /// With `--unique-per-encoding` the `Mission::mission_id` having found
+    /// the string, otherwise `None`.
+    pub mission_id: Option<usize>,
+    /// Number of occurrences.
+    pub count: u64,
+    /// Position of the first occurrence.
+    pub first: ByteCounter,
+    /// Position of the last occurrence.
+    pub last: ByteCounter,
+    /// Sorted `Finding::input_file_id`s of all occurrences in files.
+    pub files: Vec<u8>,
+}
+
+impl Record {
+    /// Orders by the aggregation key.
+    fn cmp_key(&self, other: &Self) -> Ordering {
+        self.text
+            .cmp(&other.text)
+            .then(self.mission_id.cmp(&other.mission_id))
+    }
+
+    /// Orders by `order`, then by key.
+    fn cmp_order(&self, other: &Self, order: UniqueOrder) -> Ordering {
+        match order {
+            UniqueOrder::Count => other
+                .count
+                .cmp(&self.count)
+                .then(self.first.cmp(&other.first)),
+            UniqueOrder::First => self.first.cmp(&other.first),
+        }
+        .then_with(|| self.cmp_key(other))
+    }
+
+    /// Adds the occurrences of `other`, which has the same key.
+    fn combine(&mut self, other: Record) {
+        self.count += other.count;
+        self.first = self.first.min(other.first);
+        self.last = self.last.max(other.last);
+        for f in other.files {
+            add_file(&mut self.files, f);
+        }
+    }
+
+    /// Prints the record: the number of occurrences, the input files, the
+    /// first and last position, the encoding and the string.
+    pub fn print(&self, out: &mut dyn Write) -> Result<(), Box<std::io::Error>> {
+        if ARGS.format != OutputFormat::Text {
+            let m = self.mission_id.map(|m| &MISSIONS[m]);
+            let record = RecordFields {
+                count: self.count,
+                first: self.first,
+                last: self.last,
+                files: self.files.iter().map(|i| input_name(Some(*i))).collect(),
+                label: m.map(|m| m.label.as_str()),
+                encoding: m.map(|m| m.encoding_name()),
+                text: &self.text,
+            };
+            if ARGS.format != OutputFormat::Jsonl {
+                return DELIMITED.write_row(&record, out);
+            }
+            serde_json::to_writer(&mut *out, &record).map_err(io::Error::from)?;
+            out.write_all(b"\n")?;
+            return Ok(());
+        }
+        out.write_all(b"\n")?;
+        if !ARGS.no_metadata {
+            out.write_fmt(format_args!("{:>7}\t", self.count))?;
+            if ARGS.inputs.len() > 1 && !self.files.is_empty() {
+                for (n, i) in self.files.iter().enumerate() {
+                    if n > 0 {
+                        out.write_all(b",")?;
+                    }
+                    // map 1 -> 'A', 2 -> 'B', 3 -> 'C'
+                    out.write_all(&[i + 64_u8])?;
+                }
+                out.write_all(b" ")?;
+            }
+            match ARGS.radix.unwrap_or(Radix::X) {
+                Radix::X => out.write_fmt(format_args!("{:x}-{:x}\t", self.first, self.last))?,
+                Radix::D => out.write_fmt(format_args!("{}-{}\t", self.first, self.last))?,
+                Radix::O => out.write_fmt(format_args!("{:o}-{:o}\t", self.first, self.last))?,
+            };
+            if let Some(m) = self.mission_id {
+                if MISSIONS.len() > 1 {
+                    let m = &MISSIONS[m];
+                    out.write_fmt(format_args!("({} {})\t", m.label, m.encoding_name()))?;
+                }
+            }
+        }
+        out.write_all(self.text.as_bytes())?;
+        Ok(())
+    }
+
+    /// Estimated memory used by this record.
+    fn mem_len(&self) -> usize {
+        self.text.len() + self.files.len() + RECORD_OVERHEAD_LEN
+    }
+
+    fn write(&self, w: &mut impl Write) -> io::Result<()> {
+        w.write_all(&(self.text.len() as u64).to_le_bytes())?;
+        w.write_all(self.text.as_bytes())?;
+        w.write_all(&self.mission_id.map_or(u64::MAX, |m| m as u64).to_le_bytes())?;
+        w.write_all(&self.count.to_le_bytes())?;
+        w.write_all(&self.first.to_le_bytes())?;
+        w.write_all(&self.last.to_le_bytes())?;
+        w.write_all(&[self.files.len() as u8])?;
+        w.write_all(&self.files)
+    }
+
+    /// Reads the next record, `None` at the end of the run.
+    fn read(r: &mut impl Read) -> io::Result<Option<Self>> {
+        fn read_u64(r: &mut impl Read) -> io::Result<u64> {
+            let mut b = [0; 8];
+            r.read_exact(&mut b)?;
+            Ok(u64::from_le_bytes(b))
+        }
+        let text_len = match read_u64(r) {
+            Ok(n) => n as usize,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        };
+        let mut text = vec![0; text_len];
+        r.read_exact(&mut text)?;
+        let text =
+            String::from_utf8(text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        let mission_id = match read_u64(r)? {
+            u64::MAX => None,
+            m => Some(m as usize),
+        };
+        let count = read_u64(r)?;
+        let first = read_u64(r)?;
+        let last = read_u64(r)?;
+        let mut files_len = [0; 1];
+        r.read_exact(&mut files_len)?;
+        let mut files = vec![0; files_len[0] as usize];
+        r.read_exact(&mut files)?;
+        Ok(Some(Record {
+            text,
+            mission_id,
+            count,
+            first,
+            last,
+            files,
+        }))
+    }
+}
+
+/// The names of the fields of `RecordFields`, in the order they are
+/// declared.
+pub const RECORD_COLUMNS: &[&str] = &[
+    "count", "first", "last", "files", "label", "encoding", "text",
+];
+
+/// The fields of a `Record` in output formats other than `text`, see
+/// `format::FindingFields`.
+#[derive(Serialize)]
+struct RecordFields<'a> {
+    count: u64,
+    first: ByteCounter,
+    last: ByteCounter,
+    /// Paths of the input files.
+    files: Vec<String>,
+    label: Option<&'a str>,
+    encoding: Option<&'a str>,
+    text: &'a str,
+}
+
+/// Inserts `file` into the sorted `files`, if not there yet.
+fn add_file(files: &mut Vec<u8>, file: u8) {
+    if let Err(i) = files.binary_search(&file) {
+        files.insert(i, file);
+    }
+}
+
+/// A temporary file holding sorted records. The file has no name in the file
+/// system (or, on Windows, loses it when closed): it is gone when the process
+/// exits, however it does.
+struct Run(File);
+
+impl Run {
+    /// Writes `records` into a new run.
+    fn write(records: impl Iterator<Item = Record>) -> Result<Self> {
+        let file = create_temp_file()?;
+        let mut w = BufWriter::new(&file);
+        for r in records {
+            r.write(&mut w)?;
+        }
+        w.flush()?;
+        drop(w);
+        Ok(Run(file))
+    }
+}
+
+/// Creates a new temporary file, only accessible by the current user, that
+/// is deleted when closed.
+fn create_temp_file() -> Result<File> {
+    loop {
+        let path = env::temp_dir().join(format!(
+            "stringsext-{}-{}.run",
+            process::id(),
+            RUN_FILE_NB.fetch_add(1, AtomicOrdering::Relaxed)
+        ));
+        let mut options = OpenOptions::new();
+        // `create_new` never follows a symbolic link placed under this name.
+        options.read(true).write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::OpenOptionsExt;
+            // `FILE_FLAG_DELETE_ON_CLOSE`
+            options.custom_flags(0x0400_0000);
+        }
+        match options.open(&path) {
+            Ok(file) => {
+                // The open file stays readable and writable.
+                #[cfg(unix)]
+                std::fs::remove_file(&path)
+                    .with_context(|| format!("can not remove temporary file {:?}", path))?;
+                return Ok(file);
+            }
+            // Someone else took this name: try the next.
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                return Err(e).with_context(|| format!("can not create temporary file {:?}", path))
+            }
+        }
+    }
+}
+
+/// Merges the records of `runs`, each sorted by `cmp`, and passes them to
+/// `f` in `cmp` order.
+fn merge_runs(
+    runs: Vec<Run>,
+    cmp: impl Fn(&Record, &Record) -> Ordering,
+    mut f: impl FnMut(Record) -> Result<()>,
+) -> Result<()> {
+    let mut readers = Vec::with_capacity(runs.len());
+    let mut heads = Vec::with_capacity(runs.len());
+    for run in &runs {
+        let mut file = &run.0;
+        file.seek(SeekFrom::Start(0))
+            .context("can not read temporary file")?;
+        let mut r = BufReader::new(file);
+        heads.push(Record::read(&mut r)?);
+        readers.push(r);
+    }
+    // The number of runs is small: a linear search is fast enough.
+    while let Some(i) = heads
+        .iter()
+        .enumerate()
+        .filter_map(|(i, h)| h.as_ref().map(|h| (i, h)))
+        .min_by(|(_, a), (_, b)| cmp(a, b))
+        .map(|(i, _)| i)
+    {
+        let next = Record::read(&mut readers[i])?;
+        f(mem::replace(&mut heads[i], next).unwrap())?;
+    }
+    Ok(())
+}
+
+/// Sorts records by `UniqueOrder`, spilling into runs when needed.
+struct Sorter {
+    order: UniqueOrder,
+    v: Vec<Record>,
+    len: usize,
+    runs: Vec<Run>,
+}
+
+impl Sorter {
+    fn new(order: UniqueOrder) -> Self {
+        Sorter {
+            order,
+            v: Vec::new(),
+            len: 0,
+            runs: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, r: Record) -> Result<()> {
+        self.len += r.mem_len();
+        self.v.push(r);
+        if self.len > UNIQUE_TABLE_LEN_MAX {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        let order = self.order;
+        self.v.sort_unstable_by(|a, b| a.cmp_order(b, order));
+        self.runs.push(Run::write(self.v.drain(..))?);
+        self.len = 0;
+        Ok(())
+    }
+
+    fn finish(mut self, mut f: impl FnMut(Record) -> Result<()>) -> Result<()> {
+        let order = self.order;
+        if self.runs.is_empty() {
+            self.v.sort_unstable_by(|a, b| a.cmp_order(b, order));
+            return self.v.into_iter().try_for_each(f);
+        }
+        self.spill()?;
+        merge_runs(self.runs, |a, b| a.cmp_order(b, order), &mut f)
+    }
+}
+
+/// The occurrences of a string, the value of the aggregation table.
+#[derive(Debug)]
+struct Occurrences {
+    count: u64,
+    first: ByteCounter,
+    last: ByteCounter,
+    files: Vec<u8>,
+}
+
+/// A string that may be completed by the next `Finding` of its mission.
+#[derive(Debug)]
+struct Pending {
+    text: String,
+    position: ByteCounter,
+    input_file_id: Option<u8>,
+}
+
+/// Aggregates findings by their decoded text.
+pub struct Unique {
+    order: UniqueOrder,
+    per_encoding: bool,
+    /// Key: the string and, with `per_encoding`, the mission.
+    table: HashMap<(String, Option<usize>), Occurrences>,
+    /// Estimated memory used by `table`.
+    table_len: usize,
+    /// Spilled `table`s, each sorted by key.
+    runs: Vec<Run>,
+    /// For each mission the string its last `Finding` started.
+    pending: Vec<Option<Pending>>,
+}
+
+impl Unique {
+    pub fn new(mission_nb: usize, order: UniqueOrder, per_encoding: bool) -> Self {
+        Unique {
+            order,
+            per_encoding,
+            table: HashMap::new(),
+            table_len: 0,
+            runs: Vec::new(),
+            pending: (0..mission_nb).map(|_| None).collect(),
+        }
+    }
+
+    /// Adds a finding. Findings completing a previous string are appended to
+    /// it.
+    pub fn add(&mut self, f: &Finding) -> Result<()> {
+        let mission_id = f.mission.mission_id;
+        if f.s_completes_previous_s {
+            if let Some(p) = &mut self.pending[mission_id] {
+                p.text.push_str(f.s);
+                return Ok(());
+            }
+        }
+        let p = self.pending[mission_id].replace(Pending {
+            text: f.s.to_string(),
+            position: f.position,
+            input_file_id: f.input_file_id,
+        });
+        match p {
+            Some(p) => self.insert(p, mission_id),
+            None => Ok(()),
+        }
+    }
+
+    fn insert(&mut self, p: Pending, mission_id: usize) -> Result<()> {
+        let mission_id = if self.per_encoding {
+            Some(mission_id)
+        } else {
+            None
+        };
+        let len = p.text.len() + RECORD_OVERHEAD_LEN;
+        let o = self
+            .table
+            .entry((p.text, mission_id))
+            .or_insert_with(|| Occurrences {
+                count: 0,
+                first: p.position,
+                last: p.position,
+                files: Vec::new(),
+            });
+        if o.count == 0 {
+            self.table_len += len;
+        }
+        o.count += 1;
+        o.first = o.first.min(p.position);
+        o.last = o.last.max(p.position);
+        if let Some(f) = p.input_file_id {
+            add_file(&mut o.files, f);
+        }
+        if self.table_len > UNIQUE_TABLE_LEN_MAX {
+            self.spill()?;
+        }
+        Ok(())
+    }
+
+    /// Empties `table` into a `Vec` of records.
+    fn drain_table(&mut self) -> Vec<Record> {
+        self.table_len = 0;
+        self.table
+            .drain()
+            .map(|((text, mission_id), o)| Record {
+                text,
+                mission_id,
+                count: o.count,
+                first: o.first,
+                last: o.last,
+                files: o.files,
+            })
+            .collect()
+    }
+
+    fn spill(&mut self) -> Result<()> {
+        let mut v = self.drain_table();
+        v.sort_unstable_by(Record::cmp_key);
+        self.runs.push(Run::write(v.into_iter())?);
+        Ok(())
+    }
+
+    /// Passes all distinct strings in the requested order to `f`.
+    pub fn finish(mut self, f: impl FnMut(Record) -> Result<()>) -> Result<()> {
+        for mission_id in 0..self.pending.len() {
+            if let Some(p) = self.pending[mission_id].take() {
+                self.insert(p, mission_id)?;
+            }
+        }
+
+        let mut sorter = Sorter::new(self.order);
+        if self.runs.is_empty() {
+            sorter.v = self.drain_table();
+            return sorter.finish(f);
+        }
+
+        self.spill()?;
+        // Sum up records with the same key, which are adjacent now.
+        let mut current: Option<Record> = None;
+        merge_runs(mem::take(&mut self.runs), Record::cmp_key, |r| {
+            match &mut current {
+                Some(c) if c.cmp_key(&r) == Ordering::Equal => c.combine(r),
+                _ => {
+                    if let Some(c) = current.replace(r) {
+                        sorter.push(c)?;
+                    }
+                }
+            };
+            Ok(())
+        })?;
+        if let Some(c) = current {
+            sorter.push(c)?;
+        }
+        sorter.finish(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Adds `text` found at `position` in file `file`.
+    fn insert(u: &mut Unique, text: &str, mission_id: usize, position: ByteCounter, file: u8) {
+        u.insert(
+            Pending {
+                text: text.to_string(),
+                position,
+                input_file_id: Some(file),
+            },
+            mission_id,
+        )
+        .unwrap();
+    }
+
+    fn finish(u: Unique) -> Vec<Record> {
+        let mut v = Vec::new();
+        u.finish(|r| {
+            v.push(r);
+            Ok(())
+        })
+        .unwrap();
+        v
+    }
+
+    #[test]
+    fn test_unique() {
+        let mut u = Unique::new(2, UniqueOrder::Count, false);
+        insert(&mut u, "beta", 0, 10, 1);
+        insert(&mut u, "alpha", 0, 20, 1);
+        insert(&mut u, "beta", 1, 30, 2);
+        insert(&mut u, "beta", 0, 5, 1);
+        let v = finish(u);
+        assert_eq!(v.len(), 2);
+        assert_eq!(
+            v[0],
+            Record {
+                text: "beta".to_string(),
+                mission_id: None,
+                count: 3,
+                first: 5,
+                last: 30,
+                files: vec![1, 2],
+            }
+        );
+        assert_eq!(v[1].text, "alpha");
+
+        let mut u = Unique::new(2, UniqueOrder::First, true);
+        insert(&mut u, "beta", 0, 10, 1);
+        insert(&mut u, "alpha", 0, 20, 1);
+        insert(&mut u, "beta", 1, 30, 2);
+        let v = finish(u);
+        assert_eq!(v.len(), 3);
+        assert_eq!(v[0].mission_id, Some(0));
+        assert_eq!(v[1].text, "alpha");
+        assert_eq!(v[2].mission_id, Some(1));
+    }
+
+    #[test]
+    fn test_record_columns() {
+        let record = RecordFields {
+            count: 1,
+            first: 0,
+            last: 0,
+            files: vec![],
+            label: None,
+            encoding: None,
+            text: "",
+        };
+        let value = serde_json::to_value(record).unwrap();
+        let mut keys: Vec<_> = value.as_object().unwrap().keys().collect();
+        let mut columns = RECORD_COLUMNS.to_vec();
+        keys.sort();
+        columns.sort();
+        assert_eq!(keys, columns);
+    }
+
+    /// Nothing is left behind in the temporary directory.
+    #[cfg(unix)]
+    #[test]
+    fn test_create_temp_file() {
+        use std::os::unix::fs::MetadataExt;
+        use std::os::unix::fs::PermissionsExt;
+        let metadata = create_temp_file().unwrap().metadata().unwrap();
+        assert_eq!(metadata.nlink(), 0);
+        assert_eq!(metadata.permissions().mode() & 0o777, 0o600);
+    }
+
+    /// More strings than fit in `UNIQUE_TABLE_LEN_MAX`.
+    #[test]
+    fn test_unique_spill() {
+        let mut u = Unique::new(1, UniqueOrder::Count, false);
+        for i in 0..100 {
+            insert(
+                &mut u,
+                &format!("string {}", i % 30),
+                0,
+                i,
+                (i / 30) as u8 + 1,
+            );
+        }
+        assert!(u.runs.len() > 1);
+        let v = finish(u);
+        assert_eq!(v.len(), 30);
+        // 100 = 10 * 4 + 20 * 3
+        assert!(v[..10].iter().all(|r| r.count == 4));
+        assert!(v[10..].iter().all(|r| r.count == 3));
+        assert_eq!(v[0].text, "string 0");
+        assert_eq!((v[0].first, v[0].last), (0, 90));
+        assert_eq!(v[0].files, vec![1, 2, 3, 4]);
+        assert!(v
+            .windows(2)
+            .all(|w| w[0].count > w[1].count
+                || (w[0].count == w[1].count && w[0].first < w[1].first)));
+    }
+}
@@ -0,0 +1,177 @@
+//! Rate how many words of a finding appear in user supplied word lists.
+//!
+//! Word lists are plain text files with one word per line, in any language.
+//! Words of scripts using spaces between words are looked up as a whole.
+//! Scripts written without spaces, e.g. Chinese or Thai, can not be split
+//! into words without a dictionary based segmenter. Here, runs of their
+//! characters are cut into overlapping character bigrams instead, on both
+//! sides: the word list and the finding.
+
+use crate::script_filter::ScriptFilter;
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use unicode_normalization::char::is_combining_mark;
+
+/// Default for `--min-word-ratio` in percent.
+pub const WORD_RATIO_DEFAULT: u8 = 50;
+
+lazy_static! {
+    /// Scripts written without spaces between words.
+    static ref NO_SPACE_SCRIPTS: ScriptFilter =
+        ScriptFilter::parse("Han,Hiragana,Katakana,Thai,Lao,Khmer,Myanmar").unwrap();
+}
+
+/// The tokens of one or more word lists and the required share of known
+/// tokens.
+#[derive(Clone)]
+pub struct WordList {
+    /// Lowercase tokens, see `tokenize()`.
+    words: HashSet<String>,
+    /// Minimum percentage of tokens of a finding, that must be in `words`.
+    pub min_ratio: u8,
+}
+
+/// We do not print all words, just a summary.
+impl fmt::Debug for WordList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "WordList {{ tokens: {}, min_ratio: {}% }}",
+            self.words.len(),
+            self.min_ratio
+        )
+    }
+}
+
+impl WordList {
+    /// Reads the word lists `paths`.
+    pub fn from_files(paths: &[PathBuf], min_ratio: u8) -> Result<Self> {
+        let mut content = String::new();
+        for path in paths {
+            content.push_str(
+                &fs::read_to_string(path)
+                    .with_context(|| format!("can not read word list `{}`", path.display()))?,
+            );
+            content.push('\n');
+        }
+        Ok(Self::parse(&content, min_ratio))
+    }
+
+    /// Parses the content of word lists, one word per line.
+    pub fn parse(content: &str, min_ratio: u8) -> Self {
+        let mut words = HashSet::new();
+        for line in content.lines() {
+            tokenize(line, |t| {
+                words.insert(t.to_string());
+            });
+        }
+        Self { words, min_ratio }
+    }
+
+    /// Returns the percentage of tokens of `s` found in the word list, or
+    /// `None` when `s` has no tokens, e.g. when it consists of digits only.
+    pub fn ratio(&self, s: &str) -> Option<u8> {
+        let mut known = 0usize;
+        let mut total = 0usize;
+        tokenize(s, |t| {
+            total += 1;
+            if self.words.contains(t) {
+                known += 1;
+            }
+        });
+        if total == 0 {
+            return None;
+        }
+        Some((known * 100 / total) as u8)
+    }
+
+    /// True when enough tokens of `s` are known. Strings without tokens
+    /// always pass.
+    #[inline]
+    pub fn pass(&self, s: &str) -> bool {
+        self.ratio(s).map_or(true, |r| r >= self.min_ratio)
+    }
+}
+
+/// Calls `f` with every lowercase token of `s`. A token is a word: a run of
+/// alphanumeric characters and combining marks with at least one letter.
+/// Runs of characters of `NO_SPACE_SCRIPTS` are cut into overlapping
+/// bigrams instead. A run of one such character is a token itself.
+fn tokenize(s: &str, mut f: impl FnMut(&str)) {
+    let mut run: Vec<char> = Vec::new();
+    let mut run_has_letter = false;
+    let mut run_is_no_space = false;
+    let mut token = String::new();
+
+    let mut flush = |run: &mut Vec<char>, has_letter: bool, is_no_space: bool| {
+        if has_letter {
+            if !is_no_space || run.len() == 1 {
+                token.clear();
+                token.extend(run.iter().flat_map(|c| c.to_lowercase()));
+                f(&token);
+            } else {
+                for pair in run.windows(2) {
+                    token.clear();
+                    token.extend(pair.iter().flat_map(|c| c.to_lowercase()));
+                    f(&token);
+                }
+            }
+        }
+        run.clear();
+    };
+
+    for c in s.chars() {
+        if c.is_alphanumeric() || is_combining_mark(c) {
+            // Combining marks belong to the preceding character.
+            let is_no_space = if is_combining_mark(c) && !run.is_empty() {
+                run_is_no_space
+            } else {
+                NO_SPACE_SCRIPTS.pass(c)
+            };
+            if !run.is_empty() && is_no_space != run_is_no_space {
+                flush(&mut run, run_has_letter, run_is_no_space);
+            }
+            if run.is_empty() {
+                run_has_letter = false;
+                run_is_no_space = is_no_space;
+            }
+            run_has_letter |= c.is_alphabetic();
+            run.push(c);
+        } else if !run.is_empty() {
+            flush(&mut run, run_has_letter, run_is_no_space);
+        }
+    }
+    if !run.is_empty() {
+        flush(&mut run, run_has_letter, run_is_no_space);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio() {
+        let wl = WordList::parse(
+            "the\nfile\nnot\nfound\nDatei\nnicht\nфайл\n東京都\nไม่\n\n",
+            50,
+        );
+
+        assert_eq!(wl.ratio("The file was not found."), Some(80));
+        assert_eq!(wl.ratio("Datei NICHT gefunden"), Some(66));
+        assert_eq!(wl.ratio("Файл: 1234"), Some(100));
+        assert_eq!(wl.ratio("xqzv jkwp"), Some(0));
+        assert_eq!(wl.ratio("1234 5678"), None);
+        // Bigrams `東京`, `京都` are known, `都に` is not.
+        assert_eq!(wl.ratio("東京都に"), Some(66));
+        assert_eq!(wl.ratio("東"), Some(0));
+
+        assert!(wl.pass("file not found"));
+        assert!(!wl.pass("ꁥ쐢䳷 綨뱕"));
+        assert!(wl.pass("1234"));
+    }
+}